@@ -87,7 +87,9 @@ fn bench_scan_content(c: &mut Criterion) {
             BenchmarkId::new("clean_js", size),
             &content,
             |b, content| {
-                b.iter(|| analyzer.scan_content(black_box(content), black_box(path_js), None));
+                b.iter(|| {
+                    analyzer.scan_content(black_box(content), black_box(path_js), None, None)
+                });
             },
         );
     }
@@ -99,7 +101,9 @@ fn bench_scan_content(c: &mut Criterion) {
             BenchmarkId::new("dirty_js", size),
             &content,
             |b, content| {
-                b.iter(|| analyzer.scan_content(black_box(content), black_box(path_js), None));
+                b.iter(|| {
+                    analyzer.scan_content(black_box(content), black_box(path_js), None, None)
+                });
             },
         );
     }
@@ -107,12 +111,12 @@ fn bench_scan_content(c: &mut Criterion) {
     // Different file types at 1000 lines
     let py_content = clean_py(1000);
     group.bench_function("clean_py_1000", |b| {
-        b.iter(|| analyzer.scan_content(black_box(&py_content), black_box(path_py), None));
+        b.iter(|| analyzer.scan_content(black_box(&py_content), black_box(path_py), None, None));
     });
 
     let md_content = clean_md(1000);
     group.bench_function("clean_md_1000", |b| {
-        b.iter(|| analyzer.scan_content(black_box(&md_content), black_box(path_md), None));
+        b.iter(|| analyzer.scan_content(black_box(&md_content), black_box(path_md), None, None));
     });
 
     group.finish();
@@ -236,7 +240,7 @@ fn bench_real_samples(c: &mut Criterion) {
                     .and_then(|e| e.to_str())
                     .unwrap_or("");
                 let path = samples_dir.join(name);
-                let _ = analyzer.scan_content(black_box(content), black_box(&path), None);
+                let _ = analyzer.scan_content(black_box(content), black_box(&path), None, None);
             }
         });
     });
@@ -251,7 +255,7 @@ fn bench_real_samples(c: &mut Criterion) {
         let label = name.replace('/', "_").replace('\\', "_");
 
         group.bench_function(&label, |b| {
-            b.iter(|| analyzer.scan_content(black_box(content), black_box(&path), None));
+            b.iter(|| analyzer.scan_content(black_box(content), black_box(&path), None, None));
         });
     }
 
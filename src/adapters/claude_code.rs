@@ -13,7 +13,10 @@ pub struct ClaudeCodeAdapter {
 
 impl ClaudeCodeAdapter {
     pub fn new() -> Self {
+        #[cfg(feature = "native")]
         let home_dir = dirs::home_dir().unwrap_or_else(|| PathBuf::from("."));
+        #[cfg(not(feature = "native"))]
+        let home_dir = PathBuf::from(".");
         Self { home_dir }
     }
 
@@ -303,11 +306,10 @@ impl PlatformAdapter for ClaudeCodeAdapter {
         let mut components = Vec::new();
 
         if path.is_file() {
-            // Skip binary files
-            if super::is_binary_file(path) {
-                return Ok(components);
-            }
-
+            // Binary files (by extension or content sniffing) are still
+            // discovered, just classified generically — the scanner routes
+            // them through a lightweight embedded-strings pass instead of
+            // regex/AST analysis (see `binary::extract_strings`).
             let ext = path.extension().and_then(|e| e.to_str()).unwrap_or("");
             let component_type = match ext {
                 "js" | "ts" | "mjs" | "cjs" | "py" => ComponentType::Plugin,
@@ -336,11 +338,6 @@ impl PlatformAdapter for ClaudeCodeAdapter {
             {
                 let entry_path = entry.path();
                 if entry_path.is_file() {
-                    // Skip binary files
-                    if super::is_binary_file(entry_path) {
-                        continue;
-                    }
-
                     let ext = entry_path
                         .extension()
                         .and_then(|e| e.to_str())
@@ -352,7 +349,11 @@ impl PlatformAdapter for ClaudeCodeAdapter {
                         "sh" | "bash" | "zsh" | "ps1" | "psm1" | "psd1" | "bat" | "cmd" => {
                             ComponentType::Hook
                         }
-                        _ => continue, // Skip unknown file types
+                        // Known binary extensions still get scanned — the
+                        // scanner routes them through the embedded-strings
+                        // pass instead of skipping them outright.
+                        _ if super::is_binary_file(entry_path) => ComponentType::Other,
+                        _ => continue, // Skip unknown, non-binary file types
                     };
 
                     components.push(DiscoveredComponent {
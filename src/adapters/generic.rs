@@ -16,8 +16,10 @@ impl GenericAdapter {
     pub fn new() -> Self {
         Self {
             extensions: vec![
-                "js", "ts", "mjs", "cjs", // JavaScript/TypeScript
+                "js", "ts", "mjs", "cjs", "jsx", "tsx", // JavaScript/TypeScript
                 "py",  // Python
+                "rs",  // Rust
+                "php", "phtml", // PHP
                 "json", "yaml", "yml", "toml", // Config
                 "md", "txt", // Documentation/prompts
                 "sh", "bash", "zsh", // Shell scripts
@@ -29,7 +31,9 @@ impl GenericAdapter {
 
     fn get_component_type(ext: &str) -> ComponentType {
         match ext {
-            "js" | "ts" | "mjs" | "cjs" | "py" => ComponentType::Plugin,
+            "js" | "ts" | "mjs" | "cjs" | "jsx" | "tsx" | "py" | "rs" | "php" | "phtml" => {
+                ComponentType::Plugin
+            }
             "json" | "yaml" | "yml" | "toml" => ComponentType::Config,
             "md" | "txt" => ComponentType::Prompt,
             "sh" | "bash" | "zsh" | "ps1" | "psm1" | "psd1" | "bat" | "cmd" => ComponentType::Hook,
@@ -65,13 +69,11 @@ impl PlatformAdapter for GenericAdapter {
         let mut components = Vec::new();
 
         if path.is_file() {
-            // Skip binary files
-            if super::is_binary_file(path) {
-                return Ok(components);
-            }
-
             let ext = path.extension().and_then(|e| e.to_str()).unwrap_or("");
-            if self.extensions.contains(&ext) {
+            // Known binary extensions are still discovered — the scanner
+            // routes them through a lightweight embedded-strings pass
+            // instead of regex/AST analysis (see `binary::extract_strings`).
+            if self.extensions.contains(&ext) || super::is_binary_file(path) {
                 components.push(DiscoveredComponent {
                     path: path.to_path_buf(),
                     component_type: Self::get_component_type(ext),
@@ -109,16 +111,11 @@ impl PlatformAdapter for GenericAdapter {
         {
             let entry_path = entry.path();
             if entry_path.is_file() {
-                // Skip binary files
-                if super::is_binary_file(entry_path) {
-                    continue;
-                }
-
                 let ext = entry_path
                     .extension()
                     .and_then(|e| e.to_str())
                     .unwrap_or("");
-                if self.extensions.contains(&ext) {
+                if self.extensions.contains(&ext) || super::is_binary_file(entry_path) {
                     components.push(DiscoveredComponent {
                         path: entry_path.to_path_buf(),
                         component_type: Self::get_component_type(ext),
@@ -5,6 +5,7 @@ pub mod generic;
 
 use crate::types::Platform;
 use anyhow::Result;
+use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 use std::path::{Path, PathBuf};
 use std::sync::LazyLock;
@@ -21,7 +22,8 @@ pub struct DiscoveredComponent {
 }
 
 /// Types of components that can be discovered.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub enum ComponentType {
     /// Plugin/skill code file.
     Plugin,
@@ -96,8 +98,10 @@ pub fn detect_platform() -> Option<Platform> {
     None
 }
 
-/// Binary file extensions that should be skipped during scanning.
-/// These files are not text and cannot be meaningfully regex-scanned.
+/// Binary file extensions that are not meaningfully regex/AST-scannable as
+/// text. Files with these extensions are still routed through the scanner,
+/// just via the embedded-strings pass (see `binary::extract_strings`)
+/// instead of being read as UTF-8 text.
 static BINARY_EXTENSIONS: LazyLock<HashSet<&'static str>> = LazyLock::new(|| {
     HashSet::from([
         "png", "jpg", "jpeg", "gif", "bmp", "ico", "svg", "webp", "tiff", "tif", "psd", "raw",
@@ -105,12 +109,36 @@ static BINARY_EXTENSIONS: LazyLock<HashSet<&'static str>> = LazyLock::new(|| {
         "wmv", "flv", "wav", "ogg", "webm", "m4a", "aac", "flac", "mkv", "mpeg", "mpg", "zip",
         "tar", "gz", "bz2", "7z", "rar", "jar", "war", "ear", "xz", "lz", "lzma", "tgz", "tbz2",
         "pdf", "doc", "docx", "xls", "xlsx", "ppt", "pptx", "odt", "ods", "odp", "exe", "dll",
-        "so", "dylib", "o", "obj", "a", "lib", "class", "pyc", "pyo", "wasm", "bin", "dat", "dex",
-        "db", "sqlite", "sqlite3", "mdb", "lock", "cache",
+        "so", "dylib", "o", "obj", "a", "lib", "class", "pyc", "pyo", "wasm", "node", "bin", "dat",
+        "dex", "db", "sqlite", "sqlite3", "mdb", "lock", "cache",
     ])
 });
 
-/// Returns true if the file should be skipped (is a known binary file type).
+/// The custom ignore file vexscan honors in addition to `.gitignore`.
+const CUSTOM_IGNORE_FILENAME: &str = ".vexscanignore";
+
+/// Every file under `root` that a `.gitignore`/`.vexscanignore`-aware walk
+/// would keep, honoring ignore files at any depth the same way `git` does.
+/// Used to post-filter an adapter's already-discovered components rather
+/// than re-implementing adapter-specific walks, so build artifacts and
+/// vendored junk excluded by the project's own ignore files don't need
+/// bespoke directory-name denylists. Empty if `root` isn't a directory.
+pub fn ignore_aware_files(root: &Path) -> HashSet<PathBuf> {
+    if !root.is_dir() {
+        return HashSet::new();
+    }
+    ignore::WalkBuilder::new(root)
+        .follow_links(true)
+        .require_git(false)
+        .add_custom_ignore_filename(CUSTOM_IGNORE_FILENAME)
+        .build()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().map(|t| t.is_file()).unwrap_or(false))
+        .map(|e| e.into_path())
+        .collect()
+}
+
+/// Returns true if the file is a known binary file type by extension.
 pub fn is_binary_file(path: &Path) -> bool {
     if let Some(ext) = path.extension() {
         if let Some(ext_str) = ext.to_str() {
@@ -119,3 +147,50 @@ pub fn is_binary_file(path: &Path) -> bool {
     }
     false
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn write(path: &Path, content: &str) {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).unwrap();
+        }
+        fs::write(path, content).unwrap();
+    }
+
+    #[test]
+    fn test_ignore_aware_files_respects_gitignore() {
+        let dir = TempDir::new().unwrap();
+        write(&dir.path().join(".gitignore"), "dist/\n");
+        write(&dir.path().join("src/main.js"), "console.log(1);");
+        write(&dir.path().join("dist/bundle.js"), "console.log(1);");
+
+        let files = ignore_aware_files(dir.path());
+        assert!(files.contains(&dir.path().join("src/main.js")));
+        assert!(!files.contains(&dir.path().join("dist/bundle.js")));
+    }
+
+    #[test]
+    fn test_ignore_aware_files_respects_custom_ignore_file() {
+        let dir = TempDir::new().unwrap();
+        write(&dir.path().join(".vexscanignore"), "vendor/\n");
+        write(&dir.path().join("src/main.js"), "console.log(1);");
+        write(&dir.path().join("vendor/lib.js"), "console.log(1);");
+
+        let files = ignore_aware_files(dir.path());
+        assert!(files.contains(&dir.path().join("src/main.js")));
+        assert!(!files.contains(&dir.path().join("vendor/lib.js")));
+    }
+
+    #[test]
+    fn test_ignore_aware_files_non_directory_is_empty() {
+        let dir = TempDir::new().unwrap();
+        let file = dir.path().join("solo.js");
+        write(&file, "console.log(1);");
+
+        assert!(ignore_aware_files(&file).is_empty());
+    }
+}
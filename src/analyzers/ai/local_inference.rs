@@ -0,0 +1,82 @@
+//! CPU inference against a local GGUF model file via `candle`.
+//!
+//! This backs `AiBackend::Local`: no network egress, just a quantized
+//! llama-family model loaded from disk. Only compiled in with the
+//! `local-inference` feature, since candle and its transitive dependencies
+//! are a heavy addition most builds don't need.
+
+use super::pricing::TokenUsage;
+use anyhow::{Context, Result};
+use candle_core::quantized::gguf_file;
+use candle_core::{Device, Tensor};
+use candle_transformers::generation::LogitsProcessor;
+use candle_transformers::models::quantized_llama::ModelWeights;
+use std::path::Path;
+
+/// Run a single-shot completion against the GGUF model at `model_path`.
+///
+/// Expects a `tokenizer.json` (Hugging Face tokenizers format) next to the
+/// model file, matching how quantized llama.cpp-style model directories are
+/// typically distributed. Returns the generated text and the number of
+/// prompt/generated tokens, so the caller can report usage even though
+/// local inference has no per-token bill.
+pub fn generate(
+    model_path: &Path,
+    prompt: &str,
+    max_tokens: usize,
+    temperature: f32,
+    context_size: usize,
+) -> Result<(String, TokenUsage)> {
+    let tokenizer_path = model_path.with_file_name("tokenizer.json");
+    let tokenizer = tokenizers::Tokenizer::from_file(&tokenizer_path).map_err(|e| {
+        anyhow::anyhow!(
+            "failed to load tokenizer at {}: {e}",
+            tokenizer_path.display()
+        )
+    })?;
+
+    let device = Device::Cpu;
+    let mut file =
+        std::fs::File::open(model_path).context("failed to open local GGUF model file")?;
+    let content = gguf_file::Content::read(&mut file).context("failed to parse GGUF file")?;
+    let mut model = ModelWeights::from_gguf(content, &mut file, &device)
+        .context("failed to load quantized model weights")?;
+
+    let mut tokens = tokenizer
+        .encode(prompt, true)
+        .map_err(|e| anyhow::anyhow!("failed to tokenize prompt: {e}"))?
+        .get_ids()
+        .to_vec();
+    tokens.truncate(context_size);
+
+    let mut logits_processor = LogitsProcessor::new(0, Some(temperature as f64), None);
+    let eos_token = tokenizer
+        .token_to_id("</s>")
+        .or_else(|| tokenizer.token_to_id("<|end_of_text|>"));
+
+    let mut generated = Vec::new();
+    let mut index_pos = 0;
+    let mut next_input = tokens.clone();
+    for _ in 0..max_tokens {
+        let input = Tensor::new(next_input.as_slice(), &device)?.unsqueeze(0)?;
+        let logits = model.forward(&input, index_pos)?;
+        let logits = logits.squeeze(0)?;
+        let next_token = logits_processor.sample(&logits)?;
+        index_pos += next_input.len();
+
+        if Some(next_token) == eos_token {
+            break;
+        }
+        generated.push(next_token);
+        next_input = vec![next_token];
+    }
+
+    let usage = TokenUsage {
+        input_tokens: tokens.len() as u64,
+        output_tokens: generated.len() as u64,
+    };
+    let text = tokenizer
+        .decode(&generated, true)
+        .map_err(|e| anyhow::anyhow!("failed to decode generated tokens: {e}"))?;
+    Ok((text, usage))
+}
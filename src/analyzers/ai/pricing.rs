@@ -0,0 +1,93 @@
+//! Rough per-token USD pricing for AI backends, used to estimate the cost
+//! of a scan against `ScanConfig::max_ai_cost_usd`. These rates are
+//! necessarily approximate snapshots of published list pricing — check the
+//! provider's current pricing page for billing-accurate numbers.
+
+use super::AiBackend;
+
+/// USD cost per 1,000,000 input/output tokens for a given model.
+#[derive(Debug, Clone, Copy)]
+pub struct Rate {
+    pub input_per_million: f64,
+    pub output_per_million: f64,
+}
+
+impl Rate {
+    /// Free — for backends that run on the caller's own hardware.
+    const FREE: Rate = Rate {
+        input_per_million: 0.0,
+        output_per_million: 0.0,
+    };
+
+    pub fn estimate_cost_usd(&self, usage: TokenUsage) -> f64 {
+        (usage.input_tokens as f64 / 1_000_000.0) * self.input_per_million
+            + (usage.output_tokens as f64 / 1_000_000.0) * self.output_per_million
+    }
+}
+
+/// Input/output token counts reported by (or, for `Local`, measured from) a
+/// single AI backend call.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TokenUsage {
+    pub input_tokens: u64,
+    pub output_tokens: u64,
+}
+
+/// Look up the rate for `backend`/`model`. Ollama and Local run on the
+/// caller's own hardware, so they're always free regardless of model.
+pub fn rate_for(backend: AiBackend, model: &str) -> Rate {
+    match backend {
+        AiBackend::Claude => claude_rate(model),
+        AiBackend::OpenAi => openai_rate(model),
+        AiBackend::Gemini => gemini_rate(model),
+        AiBackend::Ollama | AiBackend::Local => Rate::FREE,
+    }
+}
+
+fn claude_rate(model: &str) -> Rate {
+    if model.contains("haiku") {
+        Rate {
+            input_per_million: 0.80,
+            output_per_million: 4.00,
+        }
+    } else if model.contains("opus") {
+        Rate {
+            input_per_million: 15.00,
+            output_per_million: 75.00,
+        }
+    } else {
+        // Sonnet and anything unrecognized default to the mid-tier rate.
+        Rate {
+            input_per_million: 3.00,
+            output_per_million: 15.00,
+        }
+    }
+}
+
+fn openai_rate(model: &str) -> Rate {
+    if model.contains("mini") || model.contains("nano") {
+        Rate {
+            input_per_million: 0.15,
+            output_per_million: 0.60,
+        }
+    } else {
+        Rate {
+            input_per_million: 2.50,
+            output_per_million: 10.00,
+        }
+    }
+}
+
+fn gemini_rate(model: &str) -> Rate {
+    if model.contains("flash") {
+        Rate {
+            input_per_million: 0.075,
+            output_per_million: 0.30,
+        }
+    } else {
+        Rate {
+            input_per_million: 1.25,
+            output_per_million: 5.00,
+        }
+    }
+}
@@ -109,6 +109,9 @@ impl Detector for CommaOperatorDetector {
                     snippet,
                 )
                 .with_remediation(&self.rule.remediation)
+                .with_cwe(self.rule.cwe())
+                .with_owasp_llm(self.rule.owasp_llm())
+                .with_attack_technique(self.rule.attack_technique())
                 .with_metadata("technique", "comma_operator_indirect_call")
                 .with_metadata("function", target_name.to_string())
                 .with_metadata("ast_analyzed", "true"),
@@ -4,15 +4,18 @@
 //! - `window['eval'](code)`
 //! - `globalThis["eval"](code)`
 //! - `global['Function'](code)`
+//! - `window[`ev${'al'}`](code)` (template literals with constant substitutions)
 
 use super::Detector;
 use crate::analyzers::ast::rules::{AstRuleEntry, DangerousLists};
-use crate::analyzers::ast::scope::ScopeTracker;
+use crate::analyzers::ast::scope::{ResolvedValue, ScopeTracker};
 use crate::types::{Finding, Location};
 use std::path::Path;
 use std::sync::Arc;
 use tree_sitter::Node;
 
+const MAX_RESOLVE_DEPTH: usize = 10;
+
 pub struct ComputedAccessDetector {
     rule: AstRuleEntry,
     lists: Arc<DangerousLists>,
@@ -34,6 +37,99 @@ impl ComputedAccessDetector {
             None
         }
     }
+
+    /// Statically resolve a template literal (backtick string) whose
+    /// substitutions are all themselves constant, e.g. `` `ev${'al'}` `` or
+    /// `` `${'e' + 'v'}al` ``.
+    fn resolve_template_string(node: Node, source: &str, depth: usize) -> Option<String> {
+        let mut result = String::new();
+        let mut cursor = node.walk();
+        for child in node.named_children(&mut cursor) {
+            match child.kind() {
+                "string_fragment" => {
+                    result.push_str(child.utf8_text(source.as_bytes()).ok()?);
+                }
+                "template_substitution" => {
+                    let expr = child.named_child(0)?;
+                    result.push_str(&Self::resolve_constant_string(expr, source, depth + 1)?);
+                }
+                "escape_sequence" => {
+                    result.push_str(child.utf8_text(source.as_bytes()).ok()?);
+                }
+                _ => return None,
+            }
+        }
+        Some(result)
+    }
+
+    /// Statically resolve an expression to a constant string: a plain
+    /// string literal, a template literal with constant substitutions, or
+    /// (possibly nested) `+` concatenation of either. Used both for
+    /// top-level subscript indices and for nested template substitutions.
+    fn resolve_constant_string(node: Node, source: &str, depth: usize) -> Option<String> {
+        if depth > MAX_RESOLVE_DEPTH {
+            return None;
+        }
+
+        match node.kind() {
+            "string" => Self::get_string_value(node, source),
+            "template_string" => Self::resolve_template_string(node, source, depth),
+            "binary_expression" => {
+                let operator = node.child_by_field_name("operator")?;
+                if operator.utf8_text(source.as_bytes()).ok()? != "+" {
+                    return None;
+                }
+                let left = Self::resolve_constant_string(
+                    node.child_by_field_name("left")?,
+                    source,
+                    depth,
+                )?;
+                let right = Self::resolve_constant_string(
+                    node.child_by_field_name("right")?,
+                    source,
+                    depth,
+                )?;
+                Some(format!("{left}{right}"))
+            }
+            "parenthesized_expression" => {
+                Self::resolve_constant_string(node.named_child(0)?, source, depth + 1)
+            }
+            _ => None,
+        }
+    }
+
+    /// Statically resolve a subscript index expression, deliberately
+    /// excluding bare `+` concatenation at the top level: that shape is
+    /// `StringConcatDetector`'s responsibility, so resolving it here too
+    /// would produce a duplicate finding for the same access.
+    fn resolve_index_string(node: Node, source: &str, depth: usize) -> Option<String> {
+        match node.kind() {
+            "string" => Self::get_string_value(node, source),
+            "template_string" => Self::resolve_template_string(node, source, depth),
+            "parenthesized_expression" => {
+                Self::resolve_index_string(node.named_child(0)?, source, depth + 1)
+            }
+            _ => None,
+        }
+    }
+
+    /// Resolve a subscript index that is a plain identifier pointing at a
+    /// string built up (possibly across multiple statements, e.g.
+    /// `let s = 'ev'; s += 'al';`) via the scope tracker.
+    fn resolve_tracked_identifier(
+        node: Node,
+        source: &str,
+        scope_tracker: &ScopeTracker,
+    ) -> Option<String> {
+        if node.kind() != "identifier" {
+            return None;
+        }
+        let name = node.utf8_text(source.as_bytes()).ok()?;
+        match scope_tracker.resolve(name) {
+            ResolvedValue::StringLiteral(s) => Some(s),
+            _ => None,
+        }
+    }
 }
 
 impl Detector for ComputedAccessDetector {
@@ -54,21 +150,21 @@ impl Detector for ComputedAccessDetector {
         node: Node,
         source: &str,
         path: &Path,
-        _scope_tracker: &ScopeTracker,
+        scope_tracker: &ScopeTracker,
     ) -> Vec<Finding> {
         let mut findings = Vec::new();
 
         if node.kind() == "call_expression" {
             if let Some(callee) = node.child_by_field_name("function") {
                 if callee.kind() == "subscript_expression" {
-                    findings.extend(self.check_subscript(callee, source, path));
+                    findings.extend(self.check_subscript(callee, source, path, scope_tracker));
                 }
             }
             return findings;
         }
 
         if node.kind() == "subscript_expression" {
-            findings.extend(self.check_subscript(node, source, path));
+            findings.extend(self.check_subscript(node, source, path, scope_tracker));
         }
 
         findings
@@ -76,7 +172,13 @@ impl Detector for ComputedAccessDetector {
 }
 
 impl ComputedAccessDetector {
-    fn check_subscript(&self, node: Node, source: &str, path: &Path) -> Vec<Finding> {
+    fn check_subscript(
+        &self,
+        node: Node,
+        source: &str,
+        path: &Path,
+        scope_tracker: &ScopeTracker,
+    ) -> Vec<Finding> {
         let mut findings = Vec::new();
 
         let object = match node.child_by_field_name("object") {
@@ -98,13 +200,16 @@ impl ComputedAccessDetector {
             None => return findings,
         };
 
-        if index.kind() != "string" {
-            return findings;
-        }
-
-        let property = match Self::get_string_value(index, source) {
+        let mut resolved_via_scope = false;
+        let property = match Self::resolve_index_string(index, source, 0) {
             Some(s) => s,
-            None => return findings,
+            None => match Self::resolve_tracked_identifier(index, source, scope_tracker) {
+                Some(s) => {
+                    resolved_via_scope = true;
+                    s
+                }
+                None => return findings,
+            },
         };
 
         if self.lists.is_dangerous_function(&property) {
@@ -113,28 +218,35 @@ impl ComputedAccessDetector {
             let start_line = node.start_position().row + 1;
             let end_line = node.end_position().row + 1;
 
-            findings.push(
-                Finding::new(
-                    self.rule_id(),
-                    self.title(),
-                    format!(
-                        "Computed property access to '{}' on '{}' can execute arbitrary code. \
-                        This pattern is often used to evade regex-based detection.",
-                        property, object_text
-                    ),
-                    self.rule.severity(),
-                    self.rule.category(),
-                    Location::new(path.to_path_buf(), start_line, end_line).with_columns(
-                        node.start_position().column + 1,
-                        node.end_position().column + 1,
-                    ),
-                    snippet,
-                )
-                .with_remediation(&self.rule.remediation)
-                .with_metadata("technique", "computed_property_access")
-                .with_metadata("function", property)
-                .with_metadata("ast_analyzed", "true"),
-            );
+            let mut finding = Finding::new(
+                self.rule_id(),
+                self.title(),
+                format!(
+                    "Computed property access to '{}' on '{}' can execute arbitrary code. \
+                    This pattern is often used to evade regex-based detection.",
+                    property, object_text
+                ),
+                self.rule.severity(),
+                self.rule.category(),
+                Location::new(path.to_path_buf(), start_line, end_line).with_columns(
+                    node.start_position().column + 1,
+                    node.end_position().column + 1,
+                ),
+                snippet,
+            )
+            .with_remediation(&self.rule.remediation)
+            .with_cwe(self.rule.cwe())
+            .with_owasp_llm(self.rule.owasp_llm())
+            .with_attack_technique(self.rule.attack_technique())
+            .with_metadata("technique", "computed_property_access")
+            .with_metadata("function", property)
+            .with_metadata("ast_analyzed", "true");
+
+            if resolved_via_scope {
+                finding = finding.with_metadata("resolution", "cross_statement_string_tracking");
+            }
+
+            findings.push(finding);
         }
 
         findings
@@ -0,0 +1,110 @@
+//! Detector for dangerous functions re-exported from one file and used in
+//! another (`export const run = exec` in `plugin-a.js`, then
+//! `const { run } = require('./plugin-a'); run(cmd)` in `plugin-b.js`).
+//!
+//! Neither file is suspicious in isolation — `VariableAliasingDetector`
+//! only sees a single-file `const e = eval` shape, and there is nothing
+//! locally dangerous about destructuring an import. This detector consults
+//! the project-level [`ModuleGraph`] (built once per scan, before per-file
+//! analysis) to trace a local name back through a relative `require`/
+//! `import` to the dangerous function it was re-exported as.
+
+use super::Detector;
+use crate::analyzers::ast::module_graph::ModuleGraph;
+use crate::analyzers::ast::rules::AstRuleEntry;
+use crate::analyzers::ast::scope::{ResolvedValue, ScopeTracker};
+use crate::types::{Finding, Location};
+use std::path::Path;
+use std::sync::{Arc, RwLock};
+use tree_sitter::Node;
+
+pub struct CrossFileAliasDetector {
+    rule: AstRuleEntry,
+    module_graph: Arc<RwLock<Arc<ModuleGraph>>>,
+}
+
+impl CrossFileAliasDetector {
+    pub fn new(rule: AstRuleEntry, module_graph: Arc<RwLock<Arc<ModuleGraph>>>) -> Self {
+        Self { rule, module_graph }
+    }
+}
+
+impl Detector for CrossFileAliasDetector {
+    fn rule_id(&self) -> &str {
+        &self.rule.id
+    }
+
+    fn title(&self) -> &str {
+        &self.rule.title
+    }
+
+    fn handled_node_types(&self) -> &'static [&'static str] {
+        &["call_expression"]
+    }
+
+    fn analyze(
+        &self,
+        node: Node,
+        source: &str,
+        path: &Path,
+        scope_tracker: &ScopeTracker,
+    ) -> Vec<Finding> {
+        let mut findings = Vec::new();
+
+        let Some(callee) = node.child_by_field_name("function") else {
+            return findings;
+        };
+        if callee.kind() != "identifier" {
+            return findings;
+        }
+        let Ok(name) = callee.utf8_text(source.as_bytes()) else {
+            return findings;
+        };
+
+        let ResolvedValue::ImportResult {
+            module,
+            export: Some(export_name),
+        } = scope_tracker.resolve(name)
+        else {
+            return findings;
+        };
+
+        let graph = self.module_graph.read().unwrap();
+        let Some(dangerous_fn) = graph.resolve_export(path, &module, &export_name) else {
+            return findings;
+        };
+        let dangerous_fn = dangerous_fn.to_string();
+        drop(graph);
+
+        let snippet = node.utf8_text(source.as_bytes()).unwrap_or("").to_string();
+        let start_line = node.start_position().row + 1;
+        let end_line = node.end_position().row + 1;
+
+        let finding = Finding::new(
+            self.rule_id(),
+            self.title(),
+            format!(
+                "'{name}' is imported from '{module}' as '{export_name}', which that file \
+                re-exports as an alias of the dangerous function '{dangerous_fn}'. This splits \
+                the aliasing across files so neither one looks suspicious on its own."
+            ),
+            self.rule.severity(),
+            self.rule.category(),
+            Location::new(path.to_path_buf(), start_line, end_line),
+            snippet,
+        )
+        .with_remediation(&self.rule.remediation)
+        .with_cwe(self.rule.cwe())
+        .with_owasp_llm(self.rule.owasp_llm())
+        .with_attack_technique(self.rule.attack_technique())
+        .with_metadata("technique", "cross_file_alias")
+        .with_metadata("imported_name", name.to_string())
+        .with_metadata("source_module", module)
+        .with_metadata("source_export", export_name)
+        .with_metadata("dangerous_function", dangerous_fn)
+        .with_metadata("ast_analyzed", "true");
+
+        findings.push(finding);
+        findings
+    }
+}
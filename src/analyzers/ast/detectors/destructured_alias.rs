@@ -188,6 +188,9 @@ impl DestructuredAliasDetector {
                             snippet,
                         )
                         .with_remediation(&self.rule.remediation)
+                        .with_cwe(self.rule.cwe())
+                        .with_owasp_llm(self.rule.owasp_llm())
+                        .with_attack_technique(self.rule.attack_technique())
                         .with_metadata("technique", "destructured_aliasing")
                         .with_metadata("original", original_name.to_string())
                         .with_metadata("alias", alias_name.to_string())
@@ -258,6 +261,9 @@ impl DestructuredAliasDetector {
                                     snippet,
                                 )
                                 .with_remediation(&self.rule.remediation)
+                .with_cwe(self.rule.cwe())
+                .with_owasp_llm(self.rule.owasp_llm())
+                .with_attack_technique(self.rule.attack_technique())
                                 .with_metadata("technique", "import_aliasing")
                                 .with_metadata("original", original_name.to_string())
                                 .with_metadata("alias", alias_name.to_string())
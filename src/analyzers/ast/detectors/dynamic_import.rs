@@ -0,0 +1,159 @@
+//! Detector for dynamic `import()` calls with a computed specifier.
+//!
+//! Detects patterns like:
+//! - `import(someVar)`
+//! - `import('child' + '_process')`
+//!
+//! A plain string literal specifier (`import('./config.js')`) is the normal,
+//! statically-analyzable case and is not flagged.
+
+use super::Detector;
+use crate::analyzers::ast::rules::AstRuleEntry;
+use crate::analyzers::ast::scope::ScopeTracker;
+use crate::types::{Finding, Location};
+use std::path::Path;
+use tree_sitter::Node;
+
+const MAX_RESOLVE_DEPTH: usize = 10;
+
+pub struct DynamicImportDetector {
+    rule: AstRuleEntry,
+}
+
+impl DynamicImportDetector {
+    pub fn new(rule: AstRuleEntry) -> Self {
+        Self { rule }
+    }
+
+    fn get_string_value(node: Node, source: &str) -> Option<String> {
+        let text = node.utf8_text(source.as_bytes()).ok()?;
+        if (text.starts_with('"') && text.ends_with('"'))
+            || (text.starts_with('\'') && text.ends_with('\''))
+            || (text.starts_with('`') && text.ends_with('`'))
+        {
+            Some(text[1..text.len() - 1].to_string())
+        } else {
+            None
+        }
+    }
+
+    /// Statically resolve a specifier expression to a constant string,
+    /// supporting plain string literals and (possibly nested) `+`
+    /// concatenation of them.
+    fn resolve_specifier(node: Node, source: &str, depth: usize) -> Option<String> {
+        if depth > MAX_RESOLVE_DEPTH {
+            return None;
+        }
+
+        match node.kind() {
+            "string" => Self::get_string_value(node, source),
+            "binary_expression" => {
+                let operator = node.child_by_field_name("operator")?;
+                if operator.utf8_text(source.as_bytes()).ok()? != "+" {
+                    return None;
+                }
+                let left =
+                    Self::resolve_specifier(node.child_by_field_name("left")?, source, depth + 1)?;
+                let right =
+                    Self::resolve_specifier(node.child_by_field_name("right")?, source, depth + 1)?;
+                Some(format!("{left}{right}"))
+            }
+            "parenthesized_expression" => {
+                Self::resolve_specifier(node.named_child(0)?, source, depth + 1)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Detector for DynamicImportDetector {
+    fn rule_id(&self) -> &str {
+        &self.rule.id
+    }
+
+    fn title(&self) -> &str {
+        &self.rule.title
+    }
+
+    fn handled_node_types(&self) -> &'static [&'static str] {
+        &["call_expression"]
+    }
+
+    fn analyze(
+        &self,
+        node: Node,
+        source: &str,
+        path: &Path,
+        _scope_tracker: &ScopeTracker,
+    ) -> Vec<Finding> {
+        let mut findings = Vec::new();
+
+        let callee = match node.child_by_field_name("function") {
+            Some(c) => c,
+            None => return findings,
+        };
+        if callee.kind() != "import" {
+            return findings;
+        }
+
+        let args = match node.child_by_field_name("arguments") {
+            Some(a) => a,
+            None => return findings,
+        };
+        let specifier = match args.named_child(0) {
+            Some(s) => s,
+            None => return findings,
+        };
+
+        // A plain string literal specifier is the normal, statically
+        // analyzable case, not a computed/obfuscated one.
+        if specifier.kind() == "string" {
+            return findings;
+        }
+
+        let snippet = node.utf8_text(source.as_bytes()).unwrap_or("").to_string();
+        let start_line = node.start_position().row + 1;
+        let end_line = node.end_position().row + 1;
+        let specifier_text = specifier.utf8_text(source.as_bytes()).unwrap_or("");
+
+        let description = match Self::resolve_specifier(specifier, source, 0) {
+            Some(resolved) => format!(
+                "Dynamic import() specifier resolves to '{resolved}' via a computed \
+                expression rather than a plain string literal, which can evade \
+                dependency and module-usage scanning."
+            ),
+            None => format!(
+                "Dynamic import() specifier '{specifier_text}' is a non-literal, \
+                computed expression whose target module cannot be statically \
+                determined, which can evade dependency and module-usage scanning."
+            ),
+        };
+
+        let mut finding = Finding::new(
+            self.rule_id(),
+            self.title(),
+            description,
+            self.rule.severity(),
+            self.rule.category(),
+            Location::new(path.to_path_buf(), start_line, end_line).with_columns(
+                specifier.start_position().column + 1,
+                specifier.end_position().column + 1,
+            ),
+            snippet,
+        )
+        .with_remediation(&self.rule.remediation)
+        .with_cwe(self.rule.cwe())
+        .with_owasp_llm(self.rule.owasp_llm())
+        .with_attack_technique(self.rule.attack_technique())
+        .with_metadata("technique", "dynamic_module_load")
+        .with_metadata("specifier_expression", specifier_text.to_string())
+        .with_metadata("ast_analyzed", "true");
+
+        if let Some(resolved) = Self::resolve_specifier(specifier, source, 0) {
+            finding = finding.with_metadata("resolved_specifier", resolved);
+        }
+
+        findings.push(finding);
+        findings
+    }
+}
@@ -206,6 +206,9 @@ impl Detector for EscapeSequenceDetector {
                         snippet,
                     )
                     .with_remediation(&self.rule.remediation)
+                .with_cwe(self.rule.cwe())
+                .with_owasp_llm(self.rule.owasp_llm())
+                .with_attack_technique(self.rule.attack_technique())
                     .with_metadata("technique", "escape_sequence_obfuscation")
                     .with_metadata("decoded_function", decoded)
                     .with_metadata("ast_analyzed", "true"),
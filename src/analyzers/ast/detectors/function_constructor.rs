@@ -0,0 +1,158 @@
+//! Detector for `new Function(...)` calls whose body resolves to a constant
+//! string.
+//!
+//! `new Function(arg1, ..., argN, body)` compiles `body` as a function body
+//! at runtime, so a payload built this way never appears verbatim in the
+//! surrounding source and evades regex-based scanning. When every argument
+//! (including the body) is a statically-known string, this detector
+//! concatenates them and re-scans the reconstructed body with the full
+//! [`RuleSet`], surfacing whatever the regex rules would have found had the
+//! payload been written directly into the file.
+
+use super::Detector;
+use crate::analyzers::ast::rules::AstRuleEntry;
+use crate::analyzers::ast::scope::ScopeTracker;
+use crate::analyzers::ast::string_literal_value;
+use crate::rules::RuleSet;
+use crate::types::{truncate, Finding, Location, Severity};
+use anyhow::Result;
+use std::path::Path;
+use tree_sitter::Node;
+
+const MAX_RESOLVE_DEPTH: usize = 10;
+
+pub struct FunctionConstructorDetector {
+    rule: AstRuleEntry,
+    ruleset: RuleSet,
+}
+
+impl FunctionConstructorDetector {
+    pub fn new(rule: AstRuleEntry) -> Result<Self> {
+        let ruleset = RuleSet::new().with_builtin_rules()?;
+        Ok(Self { rule, ruleset })
+    }
+
+    /// Resolve a node to a constant string, folding `+` concatenation.
+    fn resolve_string(node: Node, source: &str, depth: usize) -> Option<String> {
+        if depth > MAX_RESOLVE_DEPTH {
+            return None;
+        }
+        match node.kind() {
+            "string" => string_literal_value(node, source),
+            "binary_expression" => {
+                let operator = node.child_by_field_name("operator")?;
+                if operator.utf8_text(source.as_bytes()).ok()? != "+" {
+                    return None;
+                }
+                let left =
+                    Self::resolve_string(node.child_by_field_name("left")?, source, depth + 1)?;
+                let right =
+                    Self::resolve_string(node.child_by_field_name("right")?, source, depth + 1)?;
+                Some(format!("{left}{right}"))
+            }
+            "parenthesized_expression" => {
+                Self::resolve_string(node.named_child(0)?, source, depth + 1)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Detector for FunctionConstructorDetector {
+    fn rule_id(&self) -> &str {
+        &self.rule.id
+    }
+
+    fn title(&self) -> &str {
+        &self.rule.title
+    }
+
+    fn handled_node_types(&self) -> &'static [&'static str] {
+        &["new_expression"]
+    }
+
+    fn analyze(
+        &self,
+        node: Node,
+        source: &str,
+        path: &Path,
+        _scope_tracker: &ScopeTracker,
+    ) -> Vec<Finding> {
+        let mut findings = Vec::new();
+
+        let constructor = match node.child_by_field_name("constructor") {
+            Some(c) => c,
+            None => return findings,
+        };
+        if constructor.kind() != "identifier"
+            || constructor.utf8_text(source.as_bytes()) != Ok("Function")
+        {
+            return findings;
+        }
+
+        let args = match node.child_by_field_name("arguments") {
+            Some(a) => a,
+            None => return findings,
+        };
+        let mut cursor = args.walk();
+        let arg_nodes: Vec<Node> = args.named_children(&mut cursor).collect();
+        if arg_nodes.is_empty() {
+            return findings;
+        }
+
+        // Every argument (parameter names and the body) must resolve to a
+        // constant string before it's safe to reconstruct and re-scan.
+        let resolved: Option<Vec<String>> = arg_nodes
+            .iter()
+            .map(|arg| Self::resolve_string(*arg, source, 0))
+            .collect();
+        let resolved = match resolved {
+            Some(r) => r,
+            None => return findings,
+        };
+        let body = resolved.last().expect("arg_nodes is non-empty");
+
+        // Re-scan the reconstructed body with the full rule set, as if it
+        // were its own JavaScript file.
+        let matches = self
+            .ruleset
+            .find_matches_for_file(body, "js", None, None, None);
+        if matches.is_empty() {
+            return findings;
+        }
+
+        let matched_titles: Vec<&str> = matches
+            .iter()
+            .map(|(rule, _)| rule.rule.title.as_str())
+            .collect();
+
+        let start_line = node.start_position().row + 1;
+        let end_line = node.end_position().row + 1;
+        let snippet = node.utf8_text(source.as_bytes()).unwrap_or("").to_string();
+
+        let finding = Finding::new(
+            self.rule_id(),
+            self.title(),
+            format!(
+                "new Function() body is a statically-known string that itself matches \
+                known malicious patterns: {}. Building a function body this way hides \
+                the payload from regex-based scanning of the source file.",
+                matched_titles.join(", ")
+            ),
+            Severity::Critical,
+            self.rule.category(),
+            Location::new(path.to_path_buf(), start_line, end_line),
+            snippet,
+        )
+        .with_remediation(&self.rule.remediation)
+        .with_cwe(self.rule.cwe())
+        .with_owasp_llm(self.rule.owasp_llm())
+        .with_attack_technique(self.rule.attack_technique())
+        .with_metadata("technique", "function_constructor_body")
+        .with_metadata("constructed_body", truncate(body, 200))
+        .with_metadata("matched_rules", matched_titles.join(", "));
+
+        findings.push(finding);
+        findings
+    }
+}
@@ -5,24 +5,58 @@
 
 mod comma_operator;
 mod computed_access;
+mod cross_file_alias;
 mod destructured_alias;
+mod dynamic_import;
 mod escape_sequences;
+mod function_constructor;
+mod js_decode_exec_chain;
+mod process_binding;
+mod proxy_wrapped_dangerous_function;
+mod python_decode_exec_chain;
+mod python_obfuscation;
+mod python_variable_aliasing;
+mod reflect_indirection;
+mod remote_fetch_exec_chain;
+mod require_computed_arg;
+mod require_member_access;
+mod sensitive_file_exfil_chain;
 mod string_concat;
 mod variable_aliasing;
+mod vm_misuse;
+mod with_statement;
 
 pub use comma_operator::CommaOperatorDetector;
 pub use computed_access::ComputedAccessDetector;
+pub use cross_file_alias::CrossFileAliasDetector;
 pub use destructured_alias::DestructuredAliasDetector;
+pub use dynamic_import::DynamicImportDetector;
 pub use escape_sequences::EscapeSequenceDetector;
+pub use function_constructor::FunctionConstructorDetector;
+pub use js_decode_exec_chain::JsDecodeExecChainDetector;
+pub use process_binding::ProcessBindingDetector;
+pub use proxy_wrapped_dangerous_function::ProxyWrappedDangerousFunctionDetector;
+pub use python_decode_exec_chain::PythonDecodeExecChainDetector;
+pub use python_obfuscation::PythonObfuscationDetector;
+pub use python_variable_aliasing::PythonVariableAliasingDetector;
+pub use reflect_indirection::ReflectIndirectionDetector;
+pub use remote_fetch_exec_chain::RemoteFetchExecChainDetector;
+pub use require_computed_arg::RequireComputedArgDetector;
+pub use require_member_access::RequireMemberAccessDetector;
+pub use sensitive_file_exfil_chain::SensitiveFileExfilChainDetector;
 pub use string_concat::StringConcatDetector;
 pub use variable_aliasing::VariableAliasingDetector;
+pub use vm_misuse::VmMisuseDetector;
+pub use with_statement::WithStatementDetector;
 
 use crate::types::Finding;
+use anyhow::Result;
 use std::collections::HashMap;
 use std::path::Path;
-use std::sync::Arc;
+use std::sync::{Arc, RwLock};
 use tree_sitter::Node;
 
+use super::module_graph::ModuleGraph;
 use super::rules::{AstRuleEntry, DangerousLists, DetectionStrategy};
 use super::scope::ScopeTracker;
 
@@ -55,11 +89,15 @@ pub struct DetectorSet {
 
 impl DetectorSet {
     /// Create a detector set from externalized AST rules.
-    pub fn from_rules(rules: &[AstRuleEntry], lists: Arc<DangerousLists>) -> Self {
+    pub fn from_rules(
+        rules: &[AstRuleEntry],
+        lists: Arc<DangerousLists>,
+        module_graph: Arc<RwLock<Arc<ModuleGraph>>>,
+    ) -> Result<Self> {
         let detectors: Vec<Box<dyn Detector>> = rules
             .iter()
-            .map(|rule| -> Box<dyn Detector> {
-                match rule.strategy {
+            .map(|rule| -> Result<Box<dyn Detector>> {
+                Ok(match rule.strategy {
                     DetectionStrategy::ComputedAccess => {
                         Box::new(ComputedAccessDetector::new(rule.clone(), lists.clone()))
                     }
@@ -78,9 +116,56 @@ impl DetectorSet {
                     DetectionStrategy::DestructuredAlias => {
                         Box::new(DestructuredAliasDetector::new(rule.clone(), lists.clone()))
                     }
-                }
+                    DetectionStrategy::PythonVariableAliasing => Box::new(
+                        PythonVariableAliasingDetector::new(rule.clone(), lists.clone()),
+                    ),
+                    DetectionStrategy::PythonObfuscation => {
+                        Box::new(PythonObfuscationDetector::new(rule.clone(), lists.clone()))
+                    }
+                    DetectionStrategy::PythonDecodeExecChain => Box::new(
+                        PythonDecodeExecChainDetector::new(rule.clone(), lists.clone()),
+                    ),
+                    DetectionStrategy::JsDecodeExecChain => {
+                        Box::new(JsDecodeExecChainDetector::new(rule.clone(), lists.clone()))
+                    }
+                    DetectionStrategy::DynamicImport => {
+                        Box::new(DynamicImportDetector::new(rule.clone()))
+                    }
+                    DetectionStrategy::RequireComputedArg => {
+                        Box::new(RequireComputedArgDetector::new(rule.clone(), lists.clone()))
+                    }
+                    DetectionStrategy::FunctionConstructor => {
+                        Box::new(FunctionConstructorDetector::new(rule.clone())?)
+                    }
+                    DetectionStrategy::VmMisuse => Box::new(VmMisuseDetector::new(rule.clone())),
+                    DetectionStrategy::ProcessBinding => {
+                        Box::new(ProcessBindingDetector::new(rule.clone()))
+                    }
+                    DetectionStrategy::CrossFileAlias => Box::new(CrossFileAliasDetector::new(
+                        rule.clone(),
+                        module_graph.clone(),
+                    )),
+                    DetectionStrategy::RequireMemberAccess => Box::new(
+                        RequireMemberAccessDetector::new(rule.clone(), lists.clone()),
+                    ),
+                    DetectionStrategy::RemoteFetchExecChain => Box::new(
+                        RemoteFetchExecChainDetector::new(rule.clone(), lists.clone()),
+                    ),
+                    DetectionStrategy::SensitiveFileExfilChain => Box::new(
+                        SensitiveFileExfilChainDetector::new(rule.clone(), lists.clone()),
+                    ),
+                    DetectionStrategy::ReflectIndirection => {
+                        Box::new(ReflectIndirectionDetector::new(rule.clone(), lists.clone()))
+                    }
+                    DetectionStrategy::ProxyWrappedDangerousFunction => Box::new(
+                        ProxyWrappedDangerousFunctionDetector::new(rule.clone(), lists.clone()),
+                    ),
+                    DetectionStrategy::WithStatement => {
+                        Box::new(WithStatementDetector::new(rule.clone(), lists.clone()))
+                    }
+                })
             })
-            .collect();
+            .collect::<Result<Vec<_>>>()?;
 
         // Pre-build node type → detector indices lookup
         let mut node_type_map: HashMap<String, Vec<usize>> = HashMap::new();
@@ -93,10 +178,10 @@ impl DetectorSet {
             }
         }
 
-        Self {
+        Ok(Self {
             detectors,
             node_type_map,
-        }
+        })
     }
 
     /// Get detector indices for a node type (zero-allocation lookup).
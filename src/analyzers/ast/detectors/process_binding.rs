@@ -0,0 +1,229 @@
+//! Detector for low-level Node internals that bypass the module system and
+//! the `child_process`-focused detectors entirely.
+//!
+//! `process.binding('spawn_sync')` and `process.dlopen(...)` reach straight
+//! into Node's native bindings, and `require('module')._load(...)` re-enters
+//! the module loader outside of `require()`'s cache and extension handling.
+//! None of these are covered by the existing regex rules or by detectors
+//! that only look for `child_process`/`eval`/`Function`. This detector
+//! resolves the sink through a plain alias (`const p = process`), a
+//! required-then-referenced `module` (`const m = require('module'); m._load(...)`),
+//! or a destructured `module` export (`const { _load } = require('module')`),
+//! in addition to the direct literal form.
+
+use super::Detector;
+use crate::analyzers::ast::rules::AstRuleEntry;
+use crate::analyzers::ast::scope::{ResolvedValue, ScopeTracker};
+use crate::analyzers::ast::string_literal_value;
+use crate::types::{Finding, Location, Severity};
+use std::path::Path;
+use tree_sitter::Node;
+
+/// `process` members that reach native bindings or dynamic libraries.
+const PROCESS_SINKS: &[&str] = &["binding", "dlopen"];
+
+/// The `module` export that bypasses `require()`'s cache/resolution.
+const MODULE_LOAD_EXPORT: &str = "_load";
+
+const MAX_ALIAS_DEPTH: usize = 10;
+
+/// Follow a chain of plain aliases (`const p = process; const q = p;`) back
+/// to the name it ultimately refers to. `ScopeTracker::resolve` collapses an
+/// alias chain that bottoms out in an unbound, non-dangerous name (like the
+/// `process` global) to `Unknown`, so this walks the raw bindings directly
+/// instead.
+fn resolve_alias_root(name: &str, scope_tracker: &ScopeTracker, depth: usize) -> Option<String> {
+    if depth > MAX_ALIAS_DEPTH {
+        return None;
+    }
+    match scope_tracker.lookup(name) {
+        Some(binding) => match &binding.points_to {
+            ResolvedValue::Alias(target) => resolve_alias_root(target, scope_tracker, depth + 1),
+            _ => None,
+        },
+        None => Some(name.to_string()),
+    }
+}
+
+pub struct ProcessBindingDetector {
+    rule: AstRuleEntry,
+}
+
+impl ProcessBindingDetector {
+    pub fn new(rule: AstRuleEntry) -> Self {
+        Self { rule }
+    }
+
+    /// If `object` is a `require('module')` call, return the literal module
+    /// name.
+    fn require_literal(object: Node, source: &str) -> Option<String> {
+        if object.kind() != "call_expression" {
+            return None;
+        }
+        let func = object.child_by_field_name("function")?;
+        if func.kind() != "identifier" || func.utf8_text(source.as_bytes()).ok()? != "require" {
+            return None;
+        }
+        let args = object.child_by_field_name("arguments")?;
+        string_literal_value(args.named_child(0)?, source)
+    }
+
+    /// If `callee` resolves to `process.binding`/`process.dlopen` or
+    /// `module._load` (directly, via a plain alias, via a required-then-
+    /// referenced module, or via a destructured import), return the sink's
+    /// display name and how it was reached.
+    fn resolve_sink(
+        callee: Node,
+        source: &str,
+        scope_tracker: &ScopeTracker,
+    ) -> Option<(String, &'static str)> {
+        match callee.kind() {
+            "member_expression" => {
+                let property = callee.child_by_field_name("property")?;
+                let property_name = property.utf8_text(source.as_bytes()).ok()?;
+                let object = callee.child_by_field_name("object")?;
+
+                if let Some(module) = Self::require_literal(object, source) {
+                    if module == "module" && property_name == MODULE_LOAD_EXPORT {
+                        return Some(("module._load".to_string(), "inline_require"));
+                    }
+                    return None;
+                }
+
+                if object.kind() != "identifier" {
+                    return None;
+                }
+                let object_name = object.utf8_text(source.as_bytes()).ok()?;
+
+                if object_name == "process" && PROCESS_SINKS.contains(&property_name) {
+                    return Some((format!("process.{property_name}"), "direct"));
+                }
+
+                if let ResolvedValue::ImportResult {
+                    module,
+                    export: None,
+                } = scope_tracker.resolve(object_name)
+                {
+                    if module == "module" && property_name == MODULE_LOAD_EXPORT {
+                        return Some(("module._load".to_string(), "aliased_import"));
+                    }
+                }
+
+                if PROCESS_SINKS.contains(&property_name)
+                    && resolve_alias_root(object_name, scope_tracker, 0).as_deref()
+                        == Some("process")
+                {
+                    return Some((format!("process.{property_name}"), "aliased_global"));
+                }
+
+                None
+            }
+            "identifier" => {
+                let name = callee.utf8_text(source.as_bytes()).ok()?;
+                if let ResolvedValue::ImportResult {
+                    module,
+                    export: Some(export),
+                } = scope_tracker.resolve(name)
+                {
+                    if module == "module" && export == MODULE_LOAD_EXPORT {
+                        return Some(("module._load".to_string(), "destructured_import"));
+                    }
+                }
+                None
+            }
+            _ => None,
+        }
+    }
+}
+
+fn technique_description(technique: &str) -> &'static str {
+    match technique {
+        "aliased_global" => "a plain alias of the `process` global",
+        "aliased_import" => "a `require('module')` result held in a variable",
+        "destructured_import" => "a destructured `require('module')` import",
+        "inline_require" => "an inline `require('module')` call",
+        _ => "a direct reference",
+    }
+}
+
+impl Detector for ProcessBindingDetector {
+    fn rule_id(&self) -> &str {
+        &self.rule.id
+    }
+
+    fn title(&self) -> &str {
+        &self.rule.title
+    }
+
+    fn handled_node_types(&self) -> &'static [&'static str] {
+        &["call_expression"]
+    }
+
+    fn analyze(
+        &self,
+        node: Node,
+        source: &str,
+        path: &Path,
+        scope_tracker: &ScopeTracker,
+    ) -> Vec<Finding> {
+        let mut findings = Vec::new();
+
+        let Some(callee) = node.child_by_field_name("function") else {
+            return findings;
+        };
+        let Some(args) = node.child_by_field_name("arguments") else {
+            return findings;
+        };
+
+        let Some((sink, technique)) = Self::resolve_sink(callee, source, scope_tracker) else {
+            return findings;
+        };
+
+        let arg_text = args
+            .named_child(0)
+            .and_then(|a| string_literal_value(a, source));
+
+        let description = match &arg_text {
+            Some(arg) => format!(
+                "{sink}('{arg}') is reached through {technique_desc}, bypassing both the \
+                module system and child_process-focused scanning to access Node's \
+                low-level internals directly.",
+                technique_desc = technique_description(technique)
+            ),
+            None => format!(
+                "{sink}(...) is reached through {technique_desc}, bypassing both the module \
+                system and child_process-focused scanning to access Node's low-level \
+                internals directly.",
+                technique_desc = technique_description(technique)
+            ),
+        };
+
+        let snippet = node.utf8_text(source.as_bytes()).unwrap_or("").to_string();
+        let start_line = node.start_position().row + 1;
+        let end_line = node.end_position().row + 1;
+
+        let mut finding = Finding::new(
+            self.rule_id(),
+            self.title(),
+            description,
+            Severity::Critical,
+            self.rule.category(),
+            Location::new(path.to_path_buf(), start_line, end_line),
+            snippet,
+        )
+        .with_remediation(&self.rule.remediation)
+        .with_cwe(self.rule.cwe())
+        .with_owasp_llm(self.rule.owasp_llm())
+        .with_attack_technique(self.rule.attack_technique())
+        .with_metadata("technique", technique.to_string())
+        .with_metadata("sink", sink)
+        .with_metadata("ast_analyzed", "true");
+
+        if let Some(arg) = arg_text {
+            finding = finding.with_metadata("argument", arg);
+        }
+
+        findings.push(finding);
+        findings
+    }
+}
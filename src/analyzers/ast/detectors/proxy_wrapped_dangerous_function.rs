@@ -0,0 +1,257 @@
+//! Detector for reaching dangerous functions through `Function.prototype`
+//! indirection (`.call`/`.apply`/`.bind`) or a `new Proxy(...)` wrapper.
+//!
+//! Detects patterns like:
+//! - `eval.call(null, code)` / `eval.apply(null, [code])`
+//! - `eval.bind(null)(code)` — flagged at the `.bind(...)` call, since that
+//!   alone produces an executable reference to the dangerous function
+//! - `Function.prototype.bind.call(eval)` — the generic
+//!   "borrow another function's `.bind`/`.call`" indirection
+//! - `new Proxy(eval, handler)` / `new Proxy(require('child_process'), handler)`
+//!
+//! These all reach the same dangerous function `VariableAliasingDetector`
+//! and `RequireMemberAccessDetector` already catch through a plain
+//! identifier or bracket access, but routed through the generic
+//! `Function.prototype`/`Proxy` machinery that neither of those inspects.
+
+use super::Detector;
+use crate::analyzers::ast::rules::{AstRuleEntry, DangerousLists};
+use crate::analyzers::ast::scope::{ResolvedValue, ScopeTracker};
+use crate::types::{Finding, Location};
+use std::path::Path;
+use std::sync::Arc;
+use tree_sitter::Node;
+
+/// `Function.prototype` methods that turn a function reference into an
+/// immediately- or eventually-invocable call without naming it directly.
+const INDIRECT_INVOKE_METHODS: &[&str] = &["call", "apply", "bind"];
+
+pub struct ProxyWrappedDangerousFunctionDetector {
+    rule: AstRuleEntry,
+    lists: Arc<DangerousLists>,
+}
+
+impl ProxyWrappedDangerousFunctionDetector {
+    pub fn new(rule: AstRuleEntry, lists: Arc<DangerousLists>) -> Self {
+        Self { rule, lists }
+    }
+
+    /// Resolve `node` to the name of a dangerous function it refers to,
+    /// whether it's a bare identifier or a member-expression module export
+    /// reached through an aliased `require()` (e.g. `cp.exec` where
+    /// `cp = require('child_process')`).
+    fn resolve_dangerous_function_name(
+        &self,
+        node: Node,
+        source: &str,
+        scope_tracker: &ScopeTracker,
+    ) -> Option<String> {
+        match node.kind() {
+            "identifier" => {
+                let name = node.utf8_text(source.as_bytes()).ok()?;
+                match scope_tracker.resolve(name) {
+                    ResolvedValue::DangerousFunction(func) => Some(func),
+                    _ if self.lists.is_dangerous_function(name) => Some(name.to_string()),
+                    _ => None,
+                }
+            }
+            "member_expression" => {
+                let object = node.child_by_field_name("object")?;
+                let property = node.child_by_field_name("property")?;
+                if object.kind() != "identifier" {
+                    return None;
+                }
+                let object_name = object.utf8_text(source.as_bytes()).ok()?;
+                let property_name = property.utf8_text(source.as_bytes()).ok()?;
+                if let ResolvedValue::ImportResult {
+                    module,
+                    export: None,
+                } = scope_tracker.resolve(object_name)
+                {
+                    if self.lists.is_dangerous_module(&module)
+                        && self.lists.is_dangerous_export(&module, property_name)
+                    {
+                        return Some(format!("{module}.{property_name}"));
+                    }
+                }
+                None
+            }
+            _ => None,
+        }
+    }
+
+    /// If `node` is `<something>.<method>`, return `(object, method)`.
+    fn member_property<'a>(&self, node: Node<'a>, source: &'a str) -> Option<(Node<'a>, &'a str)> {
+        if node.kind() != "member_expression" {
+            return None;
+        }
+        let object = node.child_by_field_name("object")?;
+        let property = node.child_by_field_name("property")?;
+        Some((object, property.utf8_text(source.as_bytes()).ok()?))
+    }
+
+    /// Detect `<dangerousFn>.call(...)` / `.apply(...)` / `.bind(...)`.
+    fn check_direct_indirect_invoke(
+        &self,
+        callee: Node,
+        source: &str,
+        scope_tracker: &ScopeTracker,
+    ) -> Option<String> {
+        let (object, method) = self.member_property(callee, source)?;
+        if !INDIRECT_INVOKE_METHODS.contains(&method) {
+            return None;
+        }
+        self.resolve_dangerous_function_name(object, source, scope_tracker)
+    }
+
+    /// Detect `Function.prototype.bind.call(<dangerousFn>, ...)` and
+    /// `Function.prototype.call.call(<dangerousFn>, ...)`: a generic
+    /// `Function.prototype.<method>` is itself invoked via `.call`, with
+    /// the dangerous function passed as the `thisArg` to bind to.
+    fn check_prototype_borrow(
+        &self,
+        call_node: Node,
+        callee: Node,
+        source: &str,
+        scope_tracker: &ScopeTracker,
+    ) -> Option<String> {
+        let (borrowed, outer_method) = self.member_property(callee, source)?;
+        if outer_method != "call" && outer_method != "apply" {
+            return None;
+        }
+        let (proto_owner, inner_method) = self.member_property(borrowed, source)?;
+        if !INDIRECT_INVOKE_METHODS.contains(&inner_method) {
+            return None;
+        }
+        let (ctor, prototype_prop) = self.member_property(proto_owner, source)?;
+        if prototype_prop != "prototype" || ctor.kind() != "identifier" {
+            return None;
+        }
+        if ctor.utf8_text(source.as_bytes()).ok()? != "Function" {
+            return None;
+        }
+
+        let args = call_node.child_by_field_name("arguments")?;
+        let target = args.named_child(0)?;
+        self.resolve_dangerous_function_name(target, source, scope_tracker)
+    }
+
+    /// Detect `new Proxy(<dangerousFn>, handler)`.
+    fn check_proxy_wrap(
+        &self,
+        constructor: Node,
+        args: Node,
+        source: &str,
+        scope_tracker: &ScopeTracker,
+    ) -> Option<String> {
+        if constructor.kind() != "identifier"
+            || constructor.utf8_text(source.as_bytes()).ok()? != "Proxy"
+        {
+            return None;
+        }
+        let target = args.named_child(0)?;
+        self.resolve_dangerous_function_name(target, source, scope_tracker)
+    }
+}
+
+impl Detector for ProxyWrappedDangerousFunctionDetector {
+    fn rule_id(&self) -> &str {
+        &self.rule.id
+    }
+
+    fn title(&self) -> &str {
+        &self.rule.title
+    }
+
+    fn handled_node_types(&self) -> &'static [&'static str] {
+        &["call_expression", "new_expression"]
+    }
+
+    fn analyze(
+        &self,
+        node: Node,
+        source: &str,
+        path: &Path,
+        scope_tracker: &ScopeTracker,
+    ) -> Vec<Finding> {
+        let result = match node.kind() {
+            "call_expression" => {
+                let Some(callee) = node.child_by_field_name("function") else {
+                    return Vec::new();
+                };
+                self.check_direct_indirect_invoke(callee, source, scope_tracker)
+                    .map(|func| (func, "function_prototype_indirection"))
+                    .or_else(|| {
+                        self.check_prototype_borrow(node, callee, source, scope_tracker)
+                            .map(|func| (func, "function_prototype_indirection"))
+                    })
+            }
+            "new_expression" => {
+                let (Some(constructor), Some(args)) = (
+                    node.child_by_field_name("constructor"),
+                    node.child_by_field_name("arguments"),
+                ) else {
+                    return Vec::new();
+                };
+                self.check_proxy_wrap(constructor, args, source, scope_tracker)
+                    .map(|func| (func, "proxy_wrap"))
+            }
+            _ => None,
+        };
+
+        match result {
+            Some((func, technique)) => vec![self.finding(node, source, path, technique, func)],
+            None => Vec::new(),
+        }
+    }
+}
+
+impl ProxyWrappedDangerousFunctionDetector {
+    fn finding(
+        &self,
+        node: Node,
+        source: &str,
+        path: &Path,
+        technique: &str,
+        func: String,
+    ) -> Finding {
+        let snippet = node.utf8_text(source.as_bytes()).unwrap_or("").to_string();
+        let start_line = node.start_position().row + 1;
+        let end_line = node.end_position().row + 1;
+
+        let description = if technique == "proxy_wrap" {
+            format!(
+                "The dangerous function '{func}' is wrapped in a Proxy. Calling the proxy \
+                executes '{func}' just as a direct call would, evading detection that only \
+                looks for a bare identifier or bracket access.",
+            )
+        } else {
+            format!(
+                "The dangerous function '{func}' is invoked indirectly through \
+                Function.prototype call/apply/bind indirection. This is functionally \
+                equivalent to a direct call but evades detection that only looks for a \
+                bare identifier alias.",
+            )
+        };
+
+        Finding::new(
+            self.rule_id(),
+            self.title(),
+            description,
+            self.rule.severity(),
+            self.rule.category(),
+            Location::new(path.to_path_buf(), start_line, end_line).with_columns(
+                node.start_position().column + 1,
+                node.end_position().column + 1,
+            ),
+            snippet,
+        )
+        .with_remediation(&self.rule.remediation)
+        .with_cwe(self.rule.cwe())
+        .with_owasp_llm(self.rule.owasp_llm())
+        .with_attack_technique(self.rule.attack_technique())
+        .with_metadata("technique", technique.to_string())
+        .with_metadata("function", func)
+        .with_metadata("ast_analyzed", "true")
+    }
+}
@@ -0,0 +1,153 @@
+//! Detector for Python decode-then-execute data-flow chains.
+//!
+//! Detects patterns like:
+//! - `payload = base64.b64decode(data); exec(payload)`
+//! - `code = codecs.decode(data, 'rot13'); eval(code)`
+//! - `src = zlib.decompress(data); compile(src, '<string>', 'exec')`
+
+use super::Detector;
+use crate::analyzers::ast::rules::{AstRuleEntry, DangerousLists};
+use crate::analyzers::ast::scope::{ResolvedValue, ScopeTracker};
+use crate::types::{Finding, Location};
+use std::path::Path;
+use std::sync::Arc;
+use tree_sitter::Node;
+
+/// Exec-family sinks whose first argument is source code or a code object.
+const SINK_FUNCTIONS: &[&str] = &["exec", "eval", "compile"];
+
+pub struct PythonDecodeExecChainDetector {
+    rule: AstRuleEntry,
+}
+
+impl PythonDecodeExecChainDetector {
+    /// `lists` isn't read directly: the decode-call binding is already
+    /// resolved into `ResolvedValue::DecodedData` while walking the tree, so
+    /// this detector only needs the scope tracker's resolution result. The
+    /// parameter is kept so construction mirrors the other detectors.
+    pub fn new(rule: AstRuleEntry, _lists: Arc<DangerousLists>) -> Self {
+        Self { rule }
+    }
+}
+
+impl Detector for PythonDecodeExecChainDetector {
+    fn rule_id(&self) -> &str {
+        &self.rule.id
+    }
+
+    fn title(&self) -> &str {
+        &self.rule.title
+    }
+
+    fn handled_node_types(&self) -> &'static [&'static str] {
+        &["call"]
+    }
+
+    fn analyze(
+        &self,
+        node: Node,
+        source: &str,
+        path: &Path,
+        scope_tracker: &ScopeTracker,
+    ) -> Vec<Finding> {
+        if node.kind() != "call" {
+            return Vec::new();
+        }
+
+        let callee = match node.child_by_field_name("function") {
+            Some(c) => c,
+            None => return Vec::new(),
+        };
+        if callee.kind() != "identifier" {
+            return Vec::new();
+        }
+        let callee_name = match callee.utf8_text(source.as_bytes()) {
+            Ok(text) => text,
+            Err(_) => return Vec::new(),
+        };
+        if !SINK_FUNCTIONS.contains(&callee_name) {
+            return Vec::new();
+        }
+
+        let args = match node.child_by_field_name("arguments") {
+            Some(a) => a,
+            None => return Vec::new(),
+        };
+        let first_arg = match args.named_child(0) {
+            Some(a) => a,
+            None => return Vec::new(),
+        };
+        if first_arg.kind() != "identifier" {
+            return Vec::new();
+        }
+        let arg_name = match first_arg.utf8_text(source.as_bytes()) {
+            Ok(text) => text,
+            Err(_) => return Vec::new(),
+        };
+
+        let ResolvedValue::DecodedData(decode_source) = scope_tracker.resolve(arg_name) else {
+            return Vec::new();
+        };
+
+        vec![self.finding(
+            node,
+            first_arg,
+            source,
+            path,
+            ChainMatch {
+                sink: callee_name.to_string(),
+                var_name: arg_name.to_string(),
+                decode_source,
+            },
+        )]
+    }
+}
+
+/// Details of a resolved decode-exec chain, describing the finding
+/// text/metadata to emit.
+struct ChainMatch {
+    sink: String,
+    var_name: String,
+    decode_source: String,
+}
+
+impl PythonDecodeExecChainDetector {
+    fn finding(
+        &self,
+        node: Node,
+        arg: Node,
+        source: &str,
+        path: &Path,
+        ctx: ChainMatch,
+    ) -> Finding {
+        let snippet = node.utf8_text(source.as_bytes()).unwrap_or("").to_string();
+        let start_line = node.start_position().row + 1;
+        let end_line = node.end_position().row + 1;
+
+        Finding::new(
+            self.rule_id(),
+            self.title(),
+            format!(
+                "'{}' holds the output of '{}' and is passed directly to {}(). \
+                This decode-then-execute chain is a common way to smuggle a \
+                payload past regex-based detection.",
+                ctx.var_name, ctx.decode_source, ctx.sink
+            ),
+            self.rule.severity(),
+            self.rule.category(),
+            Location::new(path.to_path_buf(), start_line, end_line).with_columns(
+                arg.start_position().column + 1,
+                arg.end_position().column + 1,
+            ),
+            snippet,
+        )
+        .with_remediation(&self.rule.remediation)
+        .with_cwe(self.rule.cwe())
+        .with_owasp_llm(self.rule.owasp_llm())
+        .with_attack_technique(self.rule.attack_technique())
+        .with_metadata("technique", "decode_exec_chain")
+        .with_metadata("decode_source", ctx.decode_source)
+        .with_metadata("sink", ctx.sink)
+        .with_metadata("ast_analyzed", "true")
+    }
+}
@@ -0,0 +1,256 @@
+//! Detector for Python reflection-based obfuscation of dangerous functions.
+//!
+//! Detects patterns like:
+//! - `getattr(__builtins__, 'ev' + 'al')(code)`
+//! - `globals()['exec'](code)`
+//! - `vars(os)['system'](cmd)`
+
+use super::Detector;
+use crate::analyzers::ast::rules::{AstRuleEntry, DangerousLists};
+use crate::analyzers::ast::scope::ScopeTracker;
+use crate::analyzers::ast::string_literal_value;
+use crate::types::{Finding, Location};
+use std::path::Path;
+use std::sync::Arc;
+use tree_sitter::Node;
+
+pub struct PythonObfuscationDetector {
+    rule: AstRuleEntry,
+    lists: Arc<DangerousLists>,
+    max_depth: usize,
+}
+
+/// Details of a resolved reflection-based lookup, describing the finding
+/// text/metadata to emit.
+struct ReflectionMatch {
+    description: String,
+    technique: &'static str,
+    resolved_function: String,
+}
+
+impl PythonObfuscationDetector {
+    pub fn new(rule: AstRuleEntry, lists: Arc<DangerousLists>) -> Self {
+        Self {
+            rule,
+            lists,
+            max_depth: 10,
+        }
+    }
+
+    /// Resolve a string literal, or a chain of `+`-concatenated string
+    /// literals, to its combined value.
+    fn resolve_concat(&self, node: Node, source: &str, depth: usize) -> Option<String> {
+        if depth > self.max_depth {
+            return None;
+        }
+
+        match node.kind() {
+            "string" => string_literal_value(node, source),
+            "binary_operator" => {
+                let operator = node.child_by_field_name("operator")?;
+                if operator.utf8_text(source.as_bytes()).ok()? != "+" {
+                    return None;
+                }
+
+                let left = node.child_by_field_name("left")?;
+                let right = node.child_by_field_name("right")?;
+
+                let left_val = self.resolve_concat(left, source, depth + 1)?;
+                let right_val = self.resolve_concat(right, source, depth + 1)?;
+
+                Some(format!("{}{}", left_val, right_val))
+            }
+            "parenthesized_expression" => {
+                let inner = node.named_child(0)?;
+                self.resolve_concat(inner, source, depth + 1)
+            }
+            _ => None,
+        }
+    }
+
+    fn build_finding(
+        &self,
+        node: Node,
+        highlight: Node,
+        source: &str,
+        path: &Path,
+        ctx: ReflectionMatch,
+    ) -> Finding {
+        let snippet = node.utf8_text(source.as_bytes()).unwrap_or("").to_string();
+        let start_line = node.start_position().row + 1;
+        let end_line = node.end_position().row + 1;
+
+        Finding::new(
+            self.rule_id(),
+            self.title(),
+            ctx.description,
+            self.rule.severity(),
+            self.rule.category(),
+            Location::new(path.to_path_buf(), start_line, end_line).with_columns(
+                highlight.start_position().column + 1,
+                highlight.end_position().column + 1,
+            ),
+            snippet,
+        )
+        .with_remediation(&self.rule.remediation)
+        .with_cwe(self.rule.cwe())
+        .with_owasp_llm(self.rule.owasp_llm())
+        .with_attack_technique(self.rule.attack_technique())
+        .with_metadata("technique", ctx.technique)
+        .with_metadata("resolved_function", ctx.resolved_function)
+        .with_metadata("ast_analyzed", "true")
+    }
+
+    /// `getattr(__builtins__, 'ev' + 'al')`
+    fn check_getattr(
+        &self,
+        call_node: Node,
+        callee: Node,
+        source: &str,
+        path: &Path,
+    ) -> Option<Finding> {
+        if callee.utf8_text(source.as_bytes()).ok()? != "getattr" {
+            return None;
+        }
+
+        let args = call_node.child_by_field_name("arguments")?;
+        let obj_arg = args.named_child(0)?;
+        let name_arg = args.named_child(1)?;
+
+        let obj_text = obj_arg.utf8_text(source.as_bytes()).ok()?;
+        if !self.lists.is_dangerous_global(obj_text) {
+            return None;
+        }
+
+        let resolved = self.resolve_concat(name_arg, source, 0)?;
+        if !self.lists.is_dangerous_function(&resolved) {
+            return None;
+        }
+
+        Some(self.build_finding(
+            call_node,
+            name_arg,
+            source,
+            path,
+            ReflectionMatch {
+                description: format!(
+                    "getattr() resolves to '{}' on '{}'. This pattern reaches a dangerous \
+                    function through reflection to evade regex-based detection.",
+                    resolved, obj_text
+                ),
+                technique: "getattr_obfuscation",
+                resolved_function: resolved,
+            },
+        ))
+    }
+
+    /// `globals()['exec']` / `locals()['exec']` / `vars(os)['system']`
+    fn check_reflection_subscript(
+        &self,
+        call_node: Node,
+        subscript: Node,
+        source: &str,
+        path: &Path,
+    ) -> Option<Finding> {
+        let object = subscript.child_by_field_name("value")?;
+        if object.kind() != "call" {
+            return None;
+        }
+
+        let inner_callee = object.child_by_field_name("function")?;
+        let inner_name = inner_callee.utf8_text(source.as_bytes()).ok()?;
+
+        let index = subscript.child_by_field_name("subscript")?;
+        let key = string_literal_value(index, source)?;
+
+        match inner_name {
+            "globals" | "locals" => {
+                if !self.lists.is_dangerous_function(&key) {
+                    return None;
+                }
+                Some(self.build_finding(
+                    call_node,
+                    subscript,
+                    source,
+                    path,
+                    ReflectionMatch {
+                        description: format!(
+                            "{}() lookup resolves to '{}'. This pattern reaches a dangerous \
+                            function through the namespace dict to evade regex-based detection.",
+                            inner_name, key
+                        ),
+                        technique: "globals_lookup",
+                        resolved_function: key,
+                    },
+                ))
+            }
+            "vars" => {
+                let inner_args = object.child_by_field_name("arguments")?;
+                let module_arg = inner_args.named_child(0)?;
+                let module_name = module_arg.utf8_text(source.as_bytes()).ok()?;
+
+                if !self.lists.is_dangerous_module(module_name)
+                    || !self.lists.is_dangerous_export(module_name, &key)
+                {
+                    return None;
+                }
+
+                Some(self.build_finding(
+                    call_node,
+                    subscript,
+                    source,
+                    path,
+                    ReflectionMatch {
+                        description: format!(
+                            "vars({}) lookup resolves to '{}.{}'. This pattern reaches a dangerous \
+                            function through the module's attribute dict to evade regex-based detection.",
+                            module_name, module_name, key
+                        ),
+                        technique: "vars_lookup",
+                        resolved_function: format!("{}.{}", module_name, key),
+                    },
+                ))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Detector for PythonObfuscationDetector {
+    fn rule_id(&self) -> &str {
+        &self.rule.id
+    }
+
+    fn title(&self) -> &str {
+        &self.rule.title
+    }
+
+    fn handled_node_types(&self) -> &'static [&'static str] {
+        &["call"]
+    }
+
+    fn analyze(
+        &self,
+        node: Node,
+        source: &str,
+        path: &Path,
+        _scope_tracker: &ScopeTracker,
+    ) -> Vec<Finding> {
+        if node.kind() != "call" {
+            return Vec::new();
+        }
+
+        let callee = match node.child_by_field_name("function") {
+            Some(c) => c,
+            None => return Vec::new(),
+        };
+
+        let finding = match callee.kind() {
+            "identifier" => self.check_getattr(node, callee, source, path),
+            "subscript" => self.check_reflection_subscript(node, callee, source, path),
+            _ => None,
+        };
+
+        finding.into_iter().collect()
+    }
+}
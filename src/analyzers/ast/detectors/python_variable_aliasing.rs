@@ -0,0 +1,189 @@
+//! Detector for Python variable aliasing of dangerous functions.
+//!
+//! Detects patterns like:
+//! - `e = eval; e(payload)`
+//! - `f = exec; f(payload)`
+//! - `funcs = {'run': eval}; funcs['run'](payload)` - indirect dict lookup
+
+use super::Detector;
+use crate::analyzers::ast::rules::{AstRuleEntry, DangerousLists};
+use crate::analyzers::ast::scope::{ResolvedValue, ScopeTracker};
+use crate::analyzers::ast::{dict_subscript_key, string_literal_value};
+use crate::types::{Finding, Location};
+use std::path::Path;
+use std::sync::Arc;
+use tree_sitter::Node;
+
+pub struct PythonVariableAliasingDetector {
+    rule: AstRuleEntry,
+}
+
+impl PythonVariableAliasingDetector {
+    /// `lists` isn't read directly: `ScopeTracker::resolve` already
+    /// consults the same `Arc<DangerousLists>` when following aliases, so
+    /// this detector only needs the scope tracker's resolution result. The
+    /// parameter is kept so construction mirrors the other detectors.
+    pub fn new(rule: AstRuleEntry, _lists: Arc<DangerousLists>) -> Self {
+        Self { rule }
+    }
+
+    fn finding(
+        &self,
+        node: Node,
+        callee: Node,
+        source: &str,
+        path: &Path,
+        ctx: AliasMatch,
+    ) -> Finding {
+        let snippet = node.utf8_text(source.as_bytes()).unwrap_or("").to_string();
+        let start_line = node.start_position().row + 1;
+        let end_line = node.end_position().row + 1;
+
+        Finding::new(
+            self.rule_id(),
+            self.title(),
+            format!(
+                "{} is an alias for '{}'. Calling it executes arbitrary code. \
+                This pattern is used to evade regex-based detection.",
+                ctx.alias_desc, ctx.func_name
+            ),
+            self.rule.severity(),
+            self.rule.category(),
+            Location::new(path.to_path_buf(), start_line, end_line).with_columns(
+                callee.start_position().column + 1,
+                callee.end_position().column + 1,
+            ),
+            snippet,
+        )
+        .with_remediation(&self.rule.remediation)
+        .with_cwe(self.rule.cwe())
+        .with_owasp_llm(self.rule.owasp_llm())
+        .with_attack_technique(self.rule.attack_technique())
+        .with_metadata("technique", ctx.technique)
+        .with_metadata(ctx.metadata_alias_key, ctx.metadata_alias_value)
+        .with_metadata("target_function", ctx.func_name)
+        .with_metadata("ast_analyzed", "true")
+    }
+}
+
+/// Details of a resolved alias, describing the finding text/metadata to emit.
+struct AliasMatch {
+    alias_desc: String,
+    func_name: String,
+    technique: &'static str,
+    metadata_alias_key: &'static str,
+    metadata_alias_value: String,
+}
+
+impl Detector for PythonVariableAliasingDetector {
+    fn rule_id(&self) -> &str {
+        &self.rule.id
+    }
+
+    fn title(&self) -> &str {
+        &self.rule.title
+    }
+
+    fn handled_node_types(&self) -> &'static [&'static str] {
+        &["call"]
+    }
+
+    fn analyze(
+        &self,
+        node: Node,
+        source: &str,
+        path: &Path,
+        scope_tracker: &ScopeTracker,
+    ) -> Vec<Finding> {
+        let mut findings = Vec::new();
+
+        if node.kind() != "call" {
+            return findings;
+        }
+
+        let callee = match node.child_by_field_name("function") {
+            Some(c) => c,
+            None => return findings,
+        };
+
+        match callee.kind() {
+            "identifier" => {
+                let callee_name = match callee.utf8_text(source.as_bytes()) {
+                    Ok(text) => text,
+                    Err(_) => return findings,
+                };
+
+                if let ResolvedValue::DangerousFunction(func_name) =
+                    scope_tracker.resolve(callee_name)
+                {
+                    if callee_name != func_name {
+                        findings.push(self.finding(
+                            node,
+                            callee,
+                            source,
+                            path,
+                            AliasMatch {
+                                alias_desc: format!("Variable '{}'", callee_name),
+                                func_name,
+                                technique: "variable_aliasing",
+                                metadata_alias_key: "alias",
+                                metadata_alias_value: callee_name.to_string(),
+                            },
+                        ));
+                    }
+                }
+            }
+            "subscript" => {
+                if let Some(finding) =
+                    self.check_dict_subscript_call(node, callee, source, path, scope_tracker)
+                {
+                    findings.push(finding);
+                }
+            }
+            _ => {}
+        }
+
+        findings
+    }
+}
+
+impl PythonVariableAliasingDetector {
+    /// Check `funcs['run'](payload)` where `funcs['run']` was bound to a
+    /// dangerous function by a preceding dict literal assignment.
+    fn check_dict_subscript_call(
+        &self,
+        call_node: Node,
+        subscript: Node,
+        source: &str,
+        path: &Path,
+        scope_tracker: &ScopeTracker,
+    ) -> Option<Finding> {
+        let object = subscript.child_by_field_name("value")?;
+        if object.kind() != "identifier" {
+            return None;
+        }
+        let object_name = object.utf8_text(source.as_bytes()).ok()?;
+
+        let index = subscript.child_by_field_name("subscript")?;
+        let key = string_literal_value(index, source)?;
+
+        let lookup_key = dict_subscript_key(object_name, &key);
+        if let ResolvedValue::DangerousFunction(func_name) = scope_tracker.resolve(&lookup_key) {
+            return Some(self.finding(
+                call_node,
+                subscript,
+                source,
+                path,
+                AliasMatch {
+                    alias_desc: format!("Dict entry '{}[{:?}]'", object_name, key),
+                    func_name,
+                    technique: "dict_lookup_aliasing",
+                    metadata_alias_key: "dict_key",
+                    metadata_alias_value: lookup_key,
+                },
+            ));
+        }
+
+        None
+    }
+}
@@ -0,0 +1,330 @@
+//! Detector for reaching dangerous functions through the `Reflect` API.
+//!
+//! Detects patterns like:
+//! - `Reflect.get(globalThis, 'eval')('alert(1)')`
+//! - `Reflect.apply(eval, undefined, [code])`
+//! - `Reflect.construct(Function, ['return alert(1)'])`
+//!
+//! These mirror the plain computed-access/aliasing shapes that
+//! `ComputedAccessDetector`/`VariableAliasingDetector` already catch, but
+//! routed through `Reflect.get`/`Reflect.apply`/`Reflect.construct` instead
+//! of bracket notation or a direct call, which would otherwise evade them.
+//! The property/function name argument is resolved the same way
+//! `ComputedAccessDetector` resolves a subscript index: a plain string, a
+//! template literal with constant substitutions, `+` concatenation, or a
+//! hex/unicode escape-encoded string.
+
+use super::Detector;
+use crate::analyzers::ast::rules::{AstRuleEntry, DangerousLists};
+use crate::analyzers::ast::scope::{ResolvedValue, ScopeTracker};
+use crate::types::{Finding, Location};
+use std::path::Path;
+use std::sync::Arc;
+use tree_sitter::Node;
+
+const MAX_RESOLVE_DEPTH: usize = 10;
+
+pub struct ReflectIndirectionDetector {
+    rule: AstRuleEntry,
+    lists: Arc<DangerousLists>,
+}
+
+impl ReflectIndirectionDetector {
+    pub fn new(rule: AstRuleEntry, lists: Arc<DangerousLists>) -> Self {
+        Self { rule, lists }
+    }
+
+    fn get_string_value(node: Node, source: &str) -> Option<String> {
+        let text = node.utf8_text(source.as_bytes()).ok()?;
+        if (text.starts_with('"') && text.ends_with('"'))
+            || (text.starts_with('\'') && text.ends_with('\''))
+            || (text.starts_with('`') && text.ends_with('`'))
+        {
+            Some(text[1..text.len() - 1].to_string())
+        } else {
+            None
+        }
+    }
+
+    /// Decode hex (`\x65`) and unicode (`e`/`\u{65}`) escape sequences
+    /// in a string. Returns `None` if the string contains no escapes, so
+    /// callers can tell an unescaped literal from a decoded one.
+    fn decode_escapes(s: &str) -> Option<String> {
+        if !s.contains('\\') {
+            return None;
+        }
+        let mut result = String::new();
+        let mut chars = s.chars().peekable();
+        while let Some(c) = chars.next() {
+            if c != '\\' {
+                result.push(c);
+                continue;
+            }
+            match chars.next() {
+                Some('x') => {
+                    let hex: String = (0..2).filter_map(|_| chars.next()).collect();
+                    match u8::from_str_radix(&hex, 16) {
+                        Ok(code) => result.push(code as char),
+                        Err(_) => return None,
+                    }
+                }
+                Some('u') => {
+                    if chars.peek() == Some(&'{') {
+                        chars.next();
+                        let mut hex = String::new();
+                        for ch in chars.by_ref() {
+                            if ch == '}' {
+                                break;
+                            }
+                            hex.push(ch);
+                        }
+                        match u32::from_str_radix(&hex, 16).ok().and_then(char::from_u32) {
+                            Some(decoded) => result.push(decoded),
+                            None => return None,
+                        }
+                    } else {
+                        let hex: String = (0..4).filter_map(|_| chars.next()).collect();
+                        match u32::from_str_radix(&hex, 16).ok().and_then(char::from_u32) {
+                            Some(decoded) => result.push(decoded),
+                            None => return None,
+                        }
+                    }
+                }
+                _ => return None,
+            }
+        }
+        Some(result)
+    }
+
+    fn resolve_template_string(node: Node, source: &str, depth: usize) -> Option<String> {
+        let mut result = String::new();
+        let mut cursor = node.walk();
+        for child in node.named_children(&mut cursor) {
+            match child.kind() {
+                "string_fragment" => result.push_str(child.utf8_text(source.as_bytes()).ok()?),
+                "template_substitution" => {
+                    let expr = child.named_child(0)?;
+                    result.push_str(&Self::resolve_constant_string(expr, source, depth + 1)?);
+                }
+                "escape_sequence" => result.push_str(child.utf8_text(source.as_bytes()).ok()?),
+                _ => return None,
+            }
+        }
+        Some(result)
+    }
+
+    /// Statically resolve a string-producing expression: a plain string
+    /// literal (decoding hex/unicode escapes if present), a template
+    /// literal with constant substitutions, `+` concatenation of either, or
+    /// a parenthesized expression wrapping one of those.
+    fn resolve_constant_string(node: Node, source: &str, depth: usize) -> Option<String> {
+        if depth > MAX_RESOLVE_DEPTH {
+            return None;
+        }
+
+        match node.kind() {
+            "string" => {
+                let raw = Self::get_string_value(node, source)?;
+                Some(Self::decode_escapes(&raw).unwrap_or(raw))
+            }
+            "template_string" => Self::resolve_template_string(node, source, depth),
+            "binary_expression" => {
+                let operator = node.child_by_field_name("operator")?;
+                if operator.utf8_text(source.as_bytes()).ok()? != "+" {
+                    return None;
+                }
+                let left = Self::resolve_constant_string(
+                    node.child_by_field_name("left")?,
+                    source,
+                    depth + 1,
+                )?;
+                let right = Self::resolve_constant_string(
+                    node.child_by_field_name("right")?,
+                    source,
+                    depth + 1,
+                )?;
+                Some(format!("{left}{right}"))
+            }
+            "parenthesized_expression" => {
+                Self::resolve_constant_string(node.named_child(0)?, source, depth + 1)
+            }
+            _ => None,
+        }
+    }
+
+    /// Resolve an argument node to the name of a dangerous function it
+    /// refers to, whether it's a bare identifier (`eval`, or a variable
+    /// aliased to a dangerous function via the scope tracker) or itself a
+    /// nested `Reflect.get(...)` call.
+    fn resolve_dangerous_function_name(
+        &self,
+        node: Node,
+        source: &str,
+        scope_tracker: &ScopeTracker,
+    ) -> Option<String> {
+        match node.kind() {
+            "identifier" => {
+                let name = node.utf8_text(source.as_bytes()).ok()?;
+                match scope_tracker.resolve(name) {
+                    ResolvedValue::DangerousFunction(func) => Some(func),
+                    _ if self.lists.is_dangerous_function(name) => Some(name.to_string()),
+                    _ => None,
+                }
+            }
+            "call_expression" => {
+                let (_, property) = self.reflect_call_kind(node, source)?;
+                if property != "get" {
+                    return None;
+                }
+                self.resolve_reflect_get(node, source, scope_tracker)
+            }
+            "parenthesized_expression" => {
+                self.resolve_dangerous_function_name(node.named_child(0)?, source, scope_tracker)
+            }
+            _ => None,
+        }
+    }
+
+    /// If `node` is a `Reflect.<method>(...)` call, return `(node, method)`.
+    fn reflect_call_kind<'a>(&self, node: Node<'a>, source: &str) -> Option<(Node<'a>, String)> {
+        if node.kind() != "call_expression" {
+            return None;
+        }
+        let callee = node.child_by_field_name("function")?;
+        if callee.kind() != "member_expression" {
+            return None;
+        }
+        let object = callee.child_by_field_name("object")?;
+        if object.kind() != "identifier" || object.utf8_text(source.as_bytes()).ok()? != "Reflect" {
+            return None;
+        }
+        let property = callee.child_by_field_name("property")?;
+        Some((
+            node,
+            property.utf8_text(source.as_bytes()).ok()?.to_string(),
+        ))
+    }
+
+    /// Resolve `Reflect.get(dangerousGlobal, propertyName)` to the dangerous
+    /// function name it exposes, e.g. `Reflect.get(globalThis, 'eval')` ->
+    /// `Some("eval")`.
+    fn resolve_reflect_get(
+        &self,
+        call_node: Node,
+        source: &str,
+        scope_tracker: &ScopeTracker,
+    ) -> Option<String> {
+        let args = call_node.child_by_field_name("arguments")?;
+        let object = args.named_child(0)?;
+        let property_arg = args.named_child(1)?;
+
+        let object_text = object.utf8_text(source.as_bytes()).ok()?;
+        if !self.lists.is_dangerous_global(object_text) {
+            return None;
+        }
+
+        let property = Self::resolve_constant_string(property_arg, source, 0).or_else(|| {
+            if property_arg.kind() != "identifier" {
+                return None;
+            }
+            let name = property_arg.utf8_text(source.as_bytes()).ok()?;
+            match scope_tracker.resolve(name) {
+                ResolvedValue::StringLiteral(s) => Some(s),
+                _ => None,
+            }
+        })?;
+
+        if self.lists.is_dangerous_function(&property) {
+            Some(property)
+        } else {
+            None
+        }
+    }
+}
+
+impl Detector for ReflectIndirectionDetector {
+    fn rule_id(&self) -> &str {
+        &self.rule.id
+    }
+
+    fn title(&self) -> &str {
+        &self.rule.title
+    }
+
+    fn handled_node_types(&self) -> &'static [&'static str] {
+        &["call_expression"]
+    }
+
+    fn analyze(
+        &self,
+        node: Node,
+        source: &str,
+        path: &Path,
+        scope_tracker: &ScopeTracker,
+    ) -> Vec<Finding> {
+        let Some((call_node, method)) = self.reflect_call_kind(node, source) else {
+            return Vec::new();
+        };
+
+        let finding = match method.as_str() {
+            "get" => self
+                .resolve_reflect_get(call_node, source, scope_tracker)
+                .map(|func| (func, "Reflect.get")),
+            "apply" => {
+                let args = call_node.child_by_field_name("arguments");
+                let fn_arg = args.and_then(|a| a.named_child(0));
+                fn_arg.and_then(|arg| {
+                    self.resolve_dangerous_function_name(arg, source, scope_tracker)
+                        .map(|func| (func, "Reflect.apply"))
+                })
+            }
+            "construct" => {
+                let args = call_node.child_by_field_name("arguments");
+                let ctor_arg = args.and_then(|a| a.named_child(0));
+                ctor_arg.and_then(|arg| {
+                    self.resolve_dangerous_function_name(arg, source, scope_tracker)
+                        .map(|func| (func, "Reflect.construct"))
+                })
+            }
+            _ => None,
+        };
+
+        match finding {
+            Some((func, api)) => vec![self.finding(call_node, source, path, api, func)],
+            None => Vec::new(),
+        }
+    }
+}
+
+impl ReflectIndirectionDetector {
+    fn finding(&self, node: Node, source: &str, path: &Path, api: &str, func: String) -> Finding {
+        let snippet = node.utf8_text(source.as_bytes()).unwrap_or("").to_string();
+        let start_line = node.start_position().row + 1;
+        let end_line = node.end_position().row + 1;
+
+        Finding::new(
+            self.rule_id(),
+            self.title(),
+            format!(
+                "'{api}' reaches the dangerous function '{func}' indirectly through the \
+                Reflect API. This is functionally equivalent to a direct call but evades \
+                detection that only looks for bracket notation or a bare identifier alias.",
+            ),
+            self.rule.severity(),
+            self.rule.category(),
+            Location::new(path.to_path_buf(), start_line, end_line).with_columns(
+                node.start_position().column + 1,
+                node.end_position().column + 1,
+            ),
+            snippet,
+        )
+        .with_remediation(&self.rule.remediation)
+        .with_cwe(self.rule.cwe())
+        .with_owasp_llm(self.rule.owasp_llm())
+        .with_attack_technique(self.rule.attack_technique())
+        .with_metadata("technique", "reflect_indirection")
+        .with_metadata("api", api.to_string())
+        .with_metadata("function", func)
+        .with_metadata("ast_analyzed", "true")
+    }
+}
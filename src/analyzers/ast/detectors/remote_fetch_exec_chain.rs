@@ -0,0 +1,193 @@
+//! Detector for remote-fetch-to-execute data-flow chains: the classic
+//! stage-2 downloader where a component fetches a remote payload and
+//! immediately runs it.
+//!
+//! Detects patterns like:
+//! - `const res = await fetch(url); eval(await res.text());`
+//! - `const res = await axios.get(url); new Function(res.data)();`
+//! - `const code = await res.json(); vm.runInThisContext(code);`
+
+use super::Detector;
+use crate::analyzers::ast::resolve_remote_data_source;
+use crate::analyzers::ast::rules::{AstRuleEntry, DangerousLists};
+use crate::analyzers::ast::scope::{ResolvedValue, ScopeTracker};
+use crate::types::{Finding, Location};
+use std::path::Path;
+use std::sync::Arc;
+use tree_sitter::Node;
+
+/// `vm` module functions that execute a string as JS source.
+const VM_SINK_FUNCTIONS: &[&str] = &[
+    "runInContext",
+    "runInNewContext",
+    "runInThisContext",
+    "compileFunction",
+];
+
+pub struct RemoteFetchExecChainDetector {
+    lists: Arc<DangerousLists>,
+    rule: AstRuleEntry,
+}
+
+impl RemoteFetchExecChainDetector {
+    pub fn new(rule: AstRuleEntry, lists: Arc<DangerousLists>) -> Self {
+        Self { rule, lists }
+    }
+
+    /// Identify whether `callee` is a call sink that executes its first
+    /// argument as code, returning a human-readable name for it.
+    fn sink_name(
+        &self,
+        callee: Node,
+        source: &str,
+        scope_tracker: &ScopeTracker,
+    ) -> Option<String> {
+        match callee.kind() {
+            "identifier" => {
+                let name = callee.utf8_text(source.as_bytes()).ok()?;
+                if self.lists.is_dangerous_function(name) {
+                    return Some(name.to_string());
+                }
+                if let ResolvedValue::DangerousFunction(target) = scope_tracker.resolve(name) {
+                    return Some(target);
+                }
+                None
+            }
+            "member_expression" => {
+                let property = callee.child_by_field_name("property")?;
+                let property_name = property.utf8_text(source.as_bytes()).ok()?;
+                let object = callee.child_by_field_name("object")?;
+                if object.kind() != "identifier" {
+                    return None;
+                }
+                let object_name = object.utf8_text(source.as_bytes()).ok()?;
+
+                if object_name == "vm" && VM_SINK_FUNCTIONS.contains(&property_name) {
+                    return Some(format!("vm.{property_name}"));
+                }
+
+                if let ResolvedValue::ImportResult {
+                    module,
+                    export: None,
+                } = scope_tracker.resolve(object_name)
+                {
+                    if self.lists.is_dangerous_module(&module)
+                        && self.lists.is_dangerous_export(&module, property_name)
+                    {
+                        return Some(format!("{module}.{property_name}"));
+                    }
+                }
+
+                None
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Detector for RemoteFetchExecChainDetector {
+    fn rule_id(&self) -> &str {
+        &self.rule.id
+    }
+
+    fn title(&self) -> &str {
+        &self.rule.title
+    }
+
+    fn handled_node_types(&self) -> &'static [&'static str] {
+        &["call_expression"]
+    }
+
+    fn analyze(
+        &self,
+        node: Node,
+        source: &str,
+        path: &Path,
+        scope_tracker: &ScopeTracker,
+    ) -> Vec<Finding> {
+        if node.kind() != "call_expression" {
+            return Vec::new();
+        }
+
+        let callee = match node.child_by_field_name("function") {
+            Some(c) => c,
+            None => return Vec::new(),
+        };
+        let Some(sink) = self.sink_name(callee, source, scope_tracker) else {
+            return Vec::new();
+        };
+
+        let args = match node.child_by_field_name("arguments") {
+            Some(a) => a,
+            None => return Vec::new(),
+        };
+        let first_arg = match args.named_child(0) {
+            Some(a) => a,
+            None => return Vec::new(),
+        };
+
+        let Some(remote_source) = resolve_remote_data_source(first_arg, source, scope_tracker, 0)
+        else {
+            return Vec::new();
+        };
+
+        vec![self.finding(
+            node,
+            first_arg,
+            source,
+            path,
+            ChainMatch {
+                sink,
+                remote_source,
+            },
+        )]
+    }
+}
+
+/// Details of a resolved remote-fetch-to-exec chain, describing the finding
+/// text/metadata to emit.
+struct ChainMatch {
+    sink: String,
+    remote_source: String,
+}
+
+impl RemoteFetchExecChainDetector {
+    fn finding(
+        &self,
+        node: Node,
+        arg: Node,
+        source: &str,
+        path: &Path,
+        ctx: ChainMatch,
+    ) -> Finding {
+        let snippet = node.utf8_text(source.as_bytes()).unwrap_or("").to_string();
+        let start_line = node.start_position().row + 1;
+        let end_line = node.end_position().row + 1;
+
+        Finding::new(
+            self.rule_id(),
+            self.title(),
+            format!(
+                "The body of '{}' is passed directly to {}(). This fetch-then-execute \
+                chain lets the remote server dictate what code runs on the host at \
+                request time, bypassing any review of the code shipped in this repo.",
+                ctx.remote_source, ctx.sink
+            ),
+            self.rule.severity(),
+            self.rule.category(),
+            Location::new(path.to_path_buf(), start_line, end_line).with_columns(
+                arg.start_position().column + 1,
+                arg.end_position().column + 1,
+            ),
+            snippet,
+        )
+        .with_remediation(&self.rule.remediation)
+        .with_cwe(self.rule.cwe())
+        .with_owasp_llm(self.rule.owasp_llm())
+        .with_attack_technique(self.rule.attack_technique())
+        .with_metadata("technique", "remote_fetch_exec_chain")
+        .with_metadata("remote_source", ctx.remote_source)
+        .with_metadata("sink", ctx.sink)
+        .with_metadata("ast_analyzed", "true")
+    }
+}
@@ -0,0 +1,241 @@
+//! Detector for `require()` calls with a computed argument.
+//!
+//! Detects patterns like:
+//! - `require(moduleName)` (a plain variable)
+//! - `require('child' + '_process')` (string concatenation)
+//! - `require(mods[1])` (array index, inline or via a tracked array variable)
+//! - `require(atob(encoded))` (a decoded string)
+//!
+//! A plain string literal argument (`require('fs')`) is the normal,
+//! statically-analyzable case and is not flagged.
+
+use super::Detector;
+use crate::analyzers::ast::rules::{AstRuleEntry, DangerousLists};
+use crate::analyzers::ast::scope::{ResolvedValue, ScopeTracker};
+use crate::analyzers::ast::{js_decode_source, string_literal_value};
+use crate::types::{Finding, Location, Severity};
+use std::path::Path;
+use std::sync::Arc;
+use tree_sitter::Node;
+
+const MAX_RESOLVE_DEPTH: usize = 10;
+
+/// What a computed `require()` argument was statically resolved to.
+enum Resolved {
+    /// A concrete module name, e.g. `"child_process"`.
+    Literal(String),
+    /// The argument is the output of a decode call, e.g. `"atob"`.
+    Decoded(String),
+}
+
+pub struct RequireComputedArgDetector {
+    rule: AstRuleEntry,
+    lists: Arc<DangerousLists>,
+}
+
+impl RequireComputedArgDetector {
+    pub fn new(rule: AstRuleEntry, lists: Arc<DangerousLists>) -> Self {
+        Self { rule, lists }
+    }
+
+    fn resolve_array_literal(node: Node, source: &str) -> Option<Vec<String>> {
+        let mut cursor = node.walk();
+        node.named_children(&mut cursor)
+            .map(|el| string_literal_value(el, source))
+            .collect()
+    }
+
+    fn resolve(
+        &self,
+        node: Node,
+        source: &str,
+        scope_tracker: &ScopeTracker,
+        depth: usize,
+    ) -> Option<Resolved> {
+        if depth > MAX_RESOLVE_DEPTH {
+            return None;
+        }
+
+        match node.kind() {
+            "string" => string_literal_value(node, source).map(Resolved::Literal),
+            "identifier" => {
+                let name = node.utf8_text(source.as_bytes()).ok()?;
+                match scope_tracker.resolve(name) {
+                    ResolvedValue::StringLiteral(s) => Some(Resolved::Literal(s)),
+                    ResolvedValue::DecodedData(source) => Some(Resolved::Decoded(source)),
+                    _ => None,
+                }
+            }
+            "binary_expression" => {
+                let operator = node.child_by_field_name("operator")?;
+                if operator.utf8_text(source.as_bytes()).ok()? != "+" {
+                    return None;
+                }
+                let left = self.resolve(
+                    node.child_by_field_name("left")?,
+                    source,
+                    scope_tracker,
+                    depth + 1,
+                )?;
+                let right = self.resolve(
+                    node.child_by_field_name("right")?,
+                    source,
+                    scope_tracker,
+                    depth + 1,
+                )?;
+                match (left, right) {
+                    (Resolved::Literal(a), Resolved::Literal(b)) => {
+                        Some(Resolved::Literal(format!("{a}{b}")))
+                    }
+                    _ => None,
+                }
+            }
+            "subscript_expression" => {
+                let object = node.child_by_field_name("object")?;
+                let index = node.child_by_field_name("index")?;
+                let idx: usize = index.utf8_text(source.as_bytes()).ok()?.parse().ok()?;
+
+                let items = match object.kind() {
+                    "array" => Self::resolve_array_literal(object, source)?,
+                    "identifier" => {
+                        let name = object.utf8_text(source.as_bytes()).ok()?;
+                        match scope_tracker.resolve(name) {
+                            ResolvedValue::StringArray(items) => items,
+                            _ => return None,
+                        }
+                    }
+                    _ => return None,
+                };
+
+                items.get(idx).cloned().map(Resolved::Literal)
+            }
+            "call_expression" => js_decode_source(node, source)
+                .filter(|dotted| self.lists.is_decode_function(dotted))
+                .map(Resolved::Decoded),
+            "parenthesized_expression" => {
+                self.resolve(node.named_child(0)?, source, scope_tracker, depth + 1)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Detector for RequireComputedArgDetector {
+    fn rule_id(&self) -> &str {
+        &self.rule.id
+    }
+
+    fn title(&self) -> &str {
+        &self.rule.title
+    }
+
+    fn handled_node_types(&self) -> &'static [&'static str] {
+        &["call_expression"]
+    }
+
+    fn analyze(
+        &self,
+        node: Node,
+        source: &str,
+        path: &Path,
+        scope_tracker: &ScopeTracker,
+    ) -> Vec<Finding> {
+        let mut findings = Vec::new();
+
+        let callee = match node.child_by_field_name("function") {
+            Some(c) => c,
+            None => return findings,
+        };
+        if callee.kind() != "identifier" || callee.utf8_text(source.as_bytes()) != Ok("require") {
+            return findings;
+        }
+
+        let args = match node.child_by_field_name("arguments") {
+            Some(a) => a,
+            None => return findings,
+        };
+        let arg = match args.named_child(0) {
+            Some(a) => a,
+            None => return findings,
+        };
+
+        // A plain string literal argument is the normal, statically
+        // analyzable case, not a computed/obfuscated one.
+        if arg.kind() == "string" {
+            return findings;
+        }
+
+        let snippet = node.utf8_text(source.as_bytes()).unwrap_or("").to_string();
+        let start_line = node.start_position().row + 1;
+        let end_line = node.end_position().row + 1;
+        let arg_text = arg.utf8_text(source.as_bytes()).unwrap_or("");
+
+        let resolved = self.resolve(arg, source, scope_tracker, 0);
+
+        let (severity, description, resolved_module) = match &resolved {
+            Some(Resolved::Literal(module)) if self.lists.is_dangerous_module(module) => (
+                Severity::High,
+                format!(
+                    "require() argument is a computed expression that resolves to '{module}', \
+                    a dangerous module. Computing the module name this way can evade \
+                    dependency and module-usage scanning."
+                ),
+                Some(module.clone()),
+            ),
+            Some(Resolved::Literal(module)) => (
+                self.rule.severity(),
+                format!(
+                    "require() argument resolves to '{module}' via a computed expression \
+                    rather than a plain string literal, which can evade dependency and \
+                    module-usage scanning."
+                ),
+                Some(module.clone()),
+            ),
+            Some(Resolved::Decoded(decode_source)) => (
+                Severity::High,
+                format!(
+                    "require() argument is decoded at runtime via '{decode_source}' rather \
+                    than a plain string literal, hiding the target module from static \
+                    dependency scanning."
+                ),
+                None,
+            ),
+            None => (
+                self.rule.severity(),
+                format!(
+                    "require() argument '{arg_text}' is a non-literal, computed expression \
+                    whose target module cannot be statically determined, which can evade \
+                    dependency and module-usage scanning."
+                ),
+                None,
+            ),
+        };
+
+        let mut finding = Finding::new(
+            self.rule_id(),
+            self.title(),
+            description,
+            severity,
+            self.rule.category(),
+            Location::new(path.to_path_buf(), start_line, end_line).with_columns(
+                arg.start_position().column + 1,
+                arg.end_position().column + 1,
+            ),
+            snippet,
+        )
+        .with_remediation(&self.rule.remediation)
+        .with_cwe(self.rule.cwe())
+        .with_owasp_llm(self.rule.owasp_llm())
+        .with_attack_technique(self.rule.attack_technique())
+        .with_metadata("technique", "computed_require_argument")
+        .with_metadata("argument_expression", arg_text.to_string())
+        .with_metadata("ast_analyzed", "true");
+
+        if let Some(module) = resolved_module {
+            finding = finding.with_metadata("resolved_module", module);
+        }
+
+        findings.push(finding);
+        findings
+    }
+}
@@ -0,0 +1,183 @@
+//! Detector for bracket-notation member access on a `require()` result.
+//!
+//! `DestructuredAliasDetector` catches `const {exec: run} = require('child_process')`,
+//! but a `require()` result can also be indexed directly without destructuring:
+//! - `require('child_process')['ex' + 'ec'](cmd)`
+//! - `const cp = require('child_process'); cp['spawn'](...)`
+//!
+//! Neither shape destructures anything, so `DestructuredAliasDetector` never
+//! sees them; this detector resolves the object (an inline `require()` call
+//! or a variable bound to one via the scope tracker) and the bracket index
+//! (a plain string, `+` concatenation, or a tracked identifier) to catch the
+//! same evasion in bracket form.
+
+use super::Detector;
+use crate::analyzers::ast::rules::{AstRuleEntry, DangerousLists};
+use crate::analyzers::ast::scope::{ResolvedValue, ScopeTracker};
+use crate::analyzers::ast::{resolve_js_string_expr, string_literal_value};
+use crate::types::{Finding, Location};
+use std::path::Path;
+use std::sync::Arc;
+use tree_sitter::Node;
+
+pub struct RequireMemberAccessDetector {
+    rule: AstRuleEntry,
+    lists: Arc<DangerousLists>,
+}
+
+impl RequireMemberAccessDetector {
+    pub fn new(rule: AstRuleEntry, lists: Arc<DangerousLists>) -> Self {
+        Self { rule, lists }
+    }
+
+    /// If `object` is an inline `require('module')` call, return the literal
+    /// module name.
+    fn require_literal(object: Node, source: &str) -> Option<String> {
+        if object.kind() != "call_expression" {
+            return None;
+        }
+        let func = object.child_by_field_name("function")?;
+        if func.kind() != "identifier" || func.utf8_text(source.as_bytes()).ok()? != "require" {
+            return None;
+        }
+        let args = object.child_by_field_name("arguments")?;
+        string_literal_value(args.named_child(0)?, source)
+    }
+
+    /// Resolve the module a subscripted `object` came from: an inline
+    /// `require('module')` call, or a variable bound to one via the scope
+    /// tracker (`const cp = require('module')`).
+    fn resolve_module(
+        object: Node,
+        source: &str,
+        scope_tracker: &ScopeTracker,
+    ) -> Option<(String, &'static str)> {
+        if let Some(module) = Self::require_literal(object, source) {
+            return Some((module, "inline_require"));
+        }
+
+        if object.kind() != "identifier" {
+            return None;
+        }
+        let name = object.utf8_text(source.as_bytes()).ok()?;
+        if let ResolvedValue::ImportResult {
+            module,
+            export: None,
+        } = scope_tracker.resolve(name)
+        {
+            return Some((module, "aliased_import"));
+        }
+
+        None
+    }
+}
+
+impl Detector for RequireMemberAccessDetector {
+    fn rule_id(&self) -> &str {
+        &self.rule.id
+    }
+
+    fn title(&self) -> &str {
+        &self.rule.title
+    }
+
+    fn handled_node_types(&self) -> &'static [&'static str] {
+        &["subscript_expression", "call_expression"]
+    }
+
+    fn analyze(
+        &self,
+        node: Node,
+        source: &str,
+        path: &Path,
+        scope_tracker: &ScopeTracker,
+    ) -> Vec<Finding> {
+        let mut findings = Vec::new();
+
+        if node.kind() == "call_expression" {
+            if let Some(callee) = node.child_by_field_name("function") {
+                if callee.kind() == "subscript_expression" {
+                    findings.extend(self.check_subscript(callee, source, path, scope_tracker));
+                }
+            }
+            return findings;
+        }
+
+        if node.kind() == "subscript_expression" {
+            findings.extend(self.check_subscript(node, source, path, scope_tracker));
+        }
+
+        findings
+    }
+}
+
+impl RequireMemberAccessDetector {
+    fn check_subscript(
+        &self,
+        node: Node,
+        source: &str,
+        path: &Path,
+        scope_tracker: &ScopeTracker,
+    ) -> Vec<Finding> {
+        let mut findings = Vec::new();
+
+        let Some(object) = node.child_by_field_name("object") else {
+            return findings;
+        };
+        let Some((module, technique)) = Self::resolve_module(object, source, scope_tracker) else {
+            return findings;
+        };
+        if !self.lists.is_dangerous_module(&module) {
+            return findings;
+        }
+
+        let Some(index) = node.child_by_field_name("index") else {
+            return findings;
+        };
+
+        let Some(export) = resolve_js_string_expr(index, source, scope_tracker, 0) else {
+            return findings;
+        };
+
+        if !self.lists.is_dangerous_export(&module, &export) {
+            return findings;
+        }
+
+        let snippet = node.utf8_text(source.as_bytes()).unwrap_or("").to_string();
+        let start_line = node.start_position().row + 1;
+        let end_line = node.end_position().row + 1;
+
+        findings.push(
+            Finding::new(
+                self.rule_id(),
+                self.title(),
+                format!(
+                    "Bracket-notation access to '{export}' on '{module}' (via {technique_desc}) \
+                    reaches the same dangerous export as a plain '.{export}' call while evading \
+                    detectors that only look for destructured or dotted access.",
+                    technique_desc = match technique {
+                        "inline_require" => "an inline require() call",
+                        _ => "a variable holding the require() result",
+                    }
+                ),
+                self.rule.severity(),
+                self.rule.category(),
+                Location::new(path.to_path_buf(), start_line, end_line).with_columns(
+                    node.start_position().column + 1,
+                    node.end_position().column + 1,
+                ),
+                snippet,
+            )
+            .with_remediation(&self.rule.remediation)
+            .with_cwe(self.rule.cwe())
+            .with_owasp_llm(self.rule.owasp_llm())
+            .with_attack_technique(self.rule.attack_technique())
+            .with_metadata("technique", technique.to_string())
+            .with_metadata("module", module)
+            .with_metadata("export", export)
+            .with_metadata("ast_analyzed", "true"),
+        );
+
+        findings
+    }
+}
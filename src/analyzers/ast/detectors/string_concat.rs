@@ -1,8 +1,10 @@
-//! Detector for string concatenation in property access.
+//! Detector for string/array construction in property access.
 //!
 //! Detects patterns like:
 //! - `window['ev' + 'al'](code)`
 //! - `window["Fu" + "nct" + "ion"](code)`
+//! - `window[['l', 'a', 'v', 'e'].reverse().join('')](code)`
+//! - `window['lave'.split('').reverse().join('')](code)`
 
 use super::Detector;
 use crate::analyzers::ast::rules::{AstRuleEntry, DangerousLists};
@@ -12,6 +14,14 @@ use std::path::Path;
 use std::sync::Arc;
 use tree_sitter::Node;
 
+/// A constant-folded value: either a plain string, or a list of strings
+/// (from an array literal or a `.split()` result) that array/string methods
+/// like `.reverse()`/`.join()` can still be chained onto.
+enum FoldedValue {
+    Str(String),
+    List(Vec<String>),
+}
+
 pub struct StringConcatDetector {
     rule: AstRuleEntry,
     lists: Arc<DangerousLists>,
@@ -27,45 +37,115 @@ impl StringConcatDetector {
         }
     }
 
-    fn resolve_concat(&self, node: Node, source: &str, depth: usize) -> Option<String> {
+    fn resolve_string_literal(&self, node: Node, source: &str) -> Option<String> {
+        if node.kind() != "string" {
+            return None;
+        }
+        let text = node.utf8_text(source.as_bytes()).ok()?;
+        if (text.starts_with('"') && text.ends_with('"'))
+            || (text.starts_with('\'') && text.ends_with('\''))
+            || (text.starts_with('`') && text.ends_with('`'))
+        {
+            Some(text[1..text.len() - 1].to_string())
+        } else {
+            None
+        }
+    }
+
+    /// Constant-fold a subset of JS string/array construction: literal
+    /// strings, `+` concatenation, array literals, and the
+    /// `.split()`/`.reverse()`/`.join()` method chain used to reassemble a
+    /// dangerous function name out of individually innocuous pieces.
+    fn resolve_value(&self, node: Node, source: &str, depth: usize) -> Option<FoldedValue> {
         if depth > self.max_depth {
             return None;
         }
 
         match node.kind() {
-            "string" => {
-                let text = node.utf8_text(source.as_bytes()).ok()?;
-                if (text.starts_with('"') && text.ends_with('"'))
-                    || (text.starts_with('\'') && text.ends_with('\''))
-                    || (text.starts_with('`') && text.ends_with('`'))
-                {
-                    Some(text[1..text.len() - 1].to_string())
-                } else {
-                    None
-                }
-            }
+            "string" => self
+                .resolve_string_literal(node, source)
+                .map(FoldedValue::Str),
             "binary_expression" => {
                 let operator = node.child_by_field_name("operator")?;
-                let op_text = operator.utf8_text(source.as_bytes()).ok()?;
-                if op_text != "+" {
+                if operator.utf8_text(source.as_bytes()).ok()? != "+" {
                     return None;
                 }
-
-                let left = node.child_by_field_name("left")?;
-                let right = node.child_by_field_name("right")?;
-
-                let left_val = self.resolve_concat(left, source, depth + 1)?;
-                let right_val = self.resolve_concat(right, source, depth + 1)?;
-
-                Some(format!("{}{}", left_val, right_val))
+                let left = self.resolve_string(node.child_by_field_name("left")?, source, depth)?;
+                let right =
+                    self.resolve_string(node.child_by_field_name("right")?, source, depth)?;
+                Some(FoldedValue::Str(format!("{left}{right}")))
+            }
+            "array" => {
+                let mut cursor = node.walk();
+                let elements = node
+                    .named_children(&mut cursor)
+                    .map(|el| self.resolve_string(el, source, depth + 1))
+                    .collect::<Option<Vec<_>>>()?;
+                Some(FoldedValue::List(elements))
+            }
+            "call_expression" => {
+                let callee = node.child_by_field_name("function")?;
+                if callee.kind() != "member_expression" {
+                    return None;
+                }
+                let object = callee.child_by_field_name("object")?;
+                let method = callee
+                    .child_by_field_name("property")?
+                    .utf8_text(source.as_bytes())
+                    .ok()?;
+                let object_value = self.resolve_value(object, source, depth + 1)?;
+                let args = node.child_by_field_name("arguments")?;
+
+                match method {
+                    "reverse" => match object_value {
+                        FoldedValue::List(mut items) => {
+                            items.reverse();
+                            Some(FoldedValue::List(items))
+                        }
+                        FoldedValue::Str(_) => None,
+                    },
+                    "join" => {
+                        let separator = match args.named_child(0) {
+                            Some(sep) => self.resolve_string_literal(sep, source)?,
+                            None => String::new(),
+                        };
+                        match object_value {
+                            FoldedValue::List(items) => {
+                                Some(FoldedValue::Str(items.join(&separator)))
+                            }
+                            FoldedValue::Str(_) => None,
+                        }
+                    }
+                    "split" => {
+                        let separator = match args.named_child(0) {
+                            Some(sep) => self.resolve_string_literal(sep, source)?,
+                            None => return None,
+                        };
+                        match object_value {
+                            FoldedValue::Str(s) if separator.is_empty() => Some(FoldedValue::List(
+                                s.chars().map(|c| c.to_string()).collect(),
+                            )),
+                            _ => None,
+                        }
+                    }
+                    _ => None,
+                }
             }
             "parenthesized_expression" => {
-                let inner = node.named_child(0)?;
-                self.resolve_concat(inner, source, depth + 1)
+                self.resolve_value(node.named_child(0)?, source, depth + 1)
             }
             _ => None,
         }
     }
+
+    /// Like `resolve_value`, but requires the result to be a plain string
+    /// (a fully-joined array/split chain, or a plain string literal).
+    fn resolve_string(&self, node: Node, source: &str, depth: usize) -> Option<String> {
+        match self.resolve_value(node, source, depth)? {
+            FoldedValue::Str(s) => Some(s),
+            FoldedValue::List(_) => None,
+        }
+    }
 }
 
 impl Detector for StringConcatDetector {
@@ -120,11 +200,14 @@ impl Detector for StringConcatDetector {
             None => return findings,
         };
 
-        if index.kind() != "binary_expression" {
+        if !matches!(
+            index.kind(),
+            "binary_expression" | "call_expression" | "array"
+        ) {
             return findings;
         }
 
-        if let Some(resolved) = self.resolve_concat(index, source, 0) {
+        if let Some(resolved) = self.resolve_string(index, source, 0) {
             if self.lists.is_dangerous_function(&resolved) {
                 let snippet = node.utf8_text(source.as_bytes()).unwrap_or("").to_string();
 
@@ -136,7 +219,7 @@ impl Detector for StringConcatDetector {
                         self.rule_id(),
                         self.title(),
                         format!(
-                            "String concatenation resolves to '{}' on '{}'. \
+                            "String construction resolves to '{}' on '{}'. \
                             This pattern is used to evade regex-based detection by splitting dangerous function names.",
                             resolved, object_text
                         ),
@@ -147,6 +230,9 @@ impl Detector for StringConcatDetector {
                         snippet,
                     )
                     .with_remediation(&self.rule.remediation)
+                    .with_cwe(self.rule.cwe())
+                    .with_owasp_llm(self.rule.owasp_llm())
+                    .with_attack_technique(self.rule.attack_technique())
                     .with_metadata("technique", "string_concatenation")
                     .with_metadata("resolved_function", resolved)
                     .with_metadata("ast_analyzed", "true"),
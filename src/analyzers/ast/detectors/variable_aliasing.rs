@@ -88,6 +88,9 @@ impl Detector for VariableAliasingDetector {
                             snippet,
                         )
                         .with_remediation(&self.rule.remediation)
+                        .with_cwe(self.rule.cwe())
+                        .with_owasp_llm(self.rule.owasp_llm())
+                        .with_attack_technique(self.rule.attack_technique())
                         .with_metadata("technique", "variable_aliasing")
                         .with_metadata("alias", callee_name.to_string())
                         .with_metadata("target_function", func_name)
@@ -120,6 +123,9 @@ impl Detector for VariableAliasingDetector {
                                     snippet,
                                 )
                                 .with_remediation("Review the shell command execution and ensure user input is properly sanitized.")
+                                .with_cwe(crate::compliance::default_cwe(&FindingCategory::ShellExecution))
+                                .with_owasp_llm(crate::compliance::default_owasp_llm(&FindingCategory::ShellExecution))
+                                .with_attack_technique(crate::compliance::default_attack_technique(&FindingCategory::ShellExecution))
                                 .with_metadata("technique", "import_aliasing")
                                 .with_metadata("alias", callee_name.to_string())
                                 .with_metadata("module", module)
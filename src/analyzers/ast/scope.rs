@@ -23,6 +23,32 @@ pub enum ResolvedValue {
         /// Specific export (e.g., "exec", "readFile"). None means default export.
         export: Option<String>,
     },
+    /// Result of a decode/decompress call (e.g. `base64.b64decode(...)`),
+    /// carrying the dotted name of the call that produced it so a later
+    /// `exec`/`eval`/`compile` sink can report where the payload came from.
+    DecodedData(String),
+    /// A statically-known string literal (e.g. `const mod = 'child_process'`).
+    StringLiteral(String),
+    /// A statically-known array of string literal elements (e.g.
+    /// `const mods = ['fs', 'net']`), so a later indexed access like
+    /// `mods[1]` can be resolved.
+    StringArray(Vec<String>),
+    /// The (still-unread) result of a network call (`fetch(url)`,
+    /// `axios.get(url)`, `https.get(url)`), carrying a description of the
+    /// call that produced it. Not itself dangerous — only reading its body
+    /// (`.text()`/`.json()`/`.data`) produces [`ResolvedValue::RemoteData`].
+    RemoteResponse(String),
+    /// The body of a network response (e.g. `await res.text()`,
+    /// `res.data`), carrying a description of where it came from so a later
+    /// `eval`/`Function`/`vm.*`/`child_process` sink can report the
+    /// fetch-to-execute chain.
+    RemoteData(String),
+    /// The contents of a file matched against a known sensitive-file
+    /// pattern (e.g. `~/.ssh/id_rsa`, `.aws/credentials`, a browser
+    /// credential store, a crypto wallet file), carrying the pattern's id
+    /// so a later network/DNS/child_process sink can report which secret
+    /// leaked.
+    SensitiveFileData(String),
     /// Unknown or untracked value.
     Unknown,
 }
@@ -98,13 +124,36 @@ impl ScopeTracker {
         }
     }
 
-    /// Add a binding to the current scope.
+    /// Add a binding to the current scope. Used for genuine declarations
+    /// (`let`/`const`/`var` in JS, a destructured `require()`), which always
+    /// introduce a new name in the innermost scope — shadowing whatever an
+    /// outer scope holds under the same name.
     pub fn add_binding(&mut self, name: String, points_to: ResolvedValue, line: usize) {
         if let Some(scope) = self.scopes.last_mut() {
             scope.add_binding(name, points_to, line);
         }
     }
 
+    /// Update a binding in place for a plain reassignment (`f = eval;`,
+    /// Python's `f = eval`, `s += 'al'`), as opposed to a fresh declaration.
+    /// Blocks (`if`, `for`, loop bodies, ...) are pushed as scopes in this
+    /// tracker even though they don't create real JS/Python scopes, so a
+    /// reassignment must update whichever scope already holds `name` rather
+    /// than shadowing it in the current scope — otherwise the new value
+    /// would vanish the moment the block's scope is popped, and the
+    /// reassignment would be invisible to code after the block. If no scope
+    /// already holds `name`, it's treated as a new implicit binding in the
+    /// current scope, same as `add_binding`.
+    pub fn assign(&mut self, name: &str, points_to: ResolvedValue, line: usize) {
+        for scope in self.scopes.iter_mut().rev() {
+            if scope.get_binding(name).is_some() {
+                scope.add_binding(name.to_string(), points_to, line);
+                return;
+            }
+        }
+        self.add_binding(name.to_string(), points_to, line);
+    }
+
     /// Look up a variable, searching from innermost to outermost scope.
     pub fn lookup(&self, name: &str) -> Option<&Binding> {
         for scope in self.scopes.iter().rev() {
@@ -221,6 +270,43 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_reassignment_updates_declaring_scope_not_current_scope() {
+        let mut tracker = ScopeTracker::new(10, test_lists());
+        tracker.add_binding(
+            "f".to_string(),
+            ResolvedValue::Alias("console.log".to_string()),
+            1,
+        );
+
+        // A block boundary (`if`, loop body, ...) pushes a scope even though
+        // it isn't a real JS/Python scope; a reassignment inside it must
+        // still be visible after the block exits.
+        tracker.push_scope();
+        tracker.assign("f", ResolvedValue::DangerousFunction("eval".to_string()), 2);
+        tracker.pop_scope();
+
+        match tracker.resolve("f") {
+            ResolvedValue::DangerousFunction(name) => assert_eq!(name, "eval"),
+            other => panic!("Expected DangerousFunction after reassignment, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_reassignment_of_undeclared_name_becomes_implicit_binding() {
+        let mut tracker = ScopeTracker::new(10, test_lists());
+        tracker.assign(
+            "leaked",
+            ResolvedValue::DangerousFunction("eval".to_string()),
+            1,
+        );
+
+        match tracker.resolve("leaked") {
+            ResolvedValue::DangerousFunction(name) => assert_eq!(name, "eval"),
+            other => panic!("Expected DangerousFunction, got {other:?}"),
+        }
+    }
+
     #[test]
     fn test_global_dangerous_functions() {
         let tracker = ScopeTracker::new(10, test_lists());
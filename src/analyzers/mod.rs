@@ -1,10 +1,12 @@
 //! Analysis engines for security scanning.
 
+#[cfg(feature = "native")]
 pub mod ai;
 pub mod ast;
 pub mod injection_context;
 pub mod static_analysis;
 
+#[cfg(feature = "native")]
 pub use ai::{AiAnalyzer, AiAnalyzerConfig, AiBackend, ContentType};
 pub use ast::{AstAnalyzer, AstAnalyzerConfig};
 pub use static_analysis::{AnalyzerConfig, StaticAnalyzer};
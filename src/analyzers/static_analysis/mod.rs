@@ -1,8 +1,11 @@
 //! Static analysis engine for scanning code and configuration files.
 
+use crate::adapters::ComponentType;
 use crate::decoders::{calculate_entropy, Decoder};
-use crate::rules::RuleSet;
-use crate::types::{Finding, FindingCategory, Location, ScanResult, Severity};
+use crate::rules::{RuleSet, RuleTarget};
+use crate::types::{
+    Confidence, Finding, FindingCategory, FixSuggestion, Location, ScanResult, Severity,
+};
 use anyhow::Result;
 use regex::Regex;
 use sha2::{Digest, Sha256};
@@ -24,6 +27,10 @@ pub struct AnalyzerConfig {
     pub min_entropy_length: usize,
     /// Whether to analyze decoded content.
     pub analyze_decoded: bool,
+    /// Language code for finding titles/descriptions/remediations (e.g.
+    /// "es", "ja"), selected via `--lang`/config. Rules with no translation
+    /// for this language fall back to their English text.
+    pub lang: String,
 }
 
 impl Default for AnalyzerConfig {
@@ -35,6 +42,7 @@ impl Default for AnalyzerConfig {
             entropy_threshold: 5.5,
             min_entropy_length: 50,
             analyze_decoded: true,
+            lang: "en".to_string(),
         }
     }
 }
@@ -46,6 +54,8 @@ pub struct StaticAnalyzer {
     decoder: Decoder,
     /// Pre-compiled regex for entropy string literal extraction.
     entropy_pattern: Regex,
+    /// Pre-compiled regexes for `obfuscator.io`-style bundle fingerprinting.
+    obfuscator_patterns: ObfuscatorPatterns,
 }
 
 impl StaticAnalyzer {
@@ -57,6 +67,7 @@ impl StaticAnalyzer {
             rules,
             decoder: Decoder::new(),
             entropy_pattern: Regex::new(r#"['"`]([^'"`]{50,})['"`]"#).unwrap(),
+            obfuscator_patterns: ObfuscatorPatterns::new(),
         })
     }
 
@@ -68,22 +79,27 @@ impl StaticAnalyzer {
             rules,
             decoder: Decoder::new(),
             entropy_pattern: Regex::new(r#"['"`]([^'"`]{50,})['"`]"#).unwrap(),
+            obfuscator_patterns: ObfuscatorPatterns::new(),
         })
     }
 
     /// Scan a single file and return findings.
     pub fn scan_file(&self, path: &Path) -> Result<ScanResult> {
         let content = std::fs::read_to_string(path)?;
-        self.scan_content(&content, path, None)
+        self.scan_content(&content, path, None, None)
     }
 
     /// Scan pre-read content and return findings.
     /// If `content_hash` is provided, skips recomputing SHA-256.
+    /// `component_type`, if known, lets component-scoped rules (see
+    /// `Rule::component_types`) apply — e.g. a rule that only fires inside a
+    /// `Hook`, not an example `Plugin` script.
     pub fn scan_content(
         &self,
         content: &str,
         path: &Path,
         content_hash: Option<String>,
+        component_type: Option<ComponentType>,
     ) -> Result<ScanResult> {
         let start = Instant::now();
         let mut result = ScanResult::new(path.to_path_buf());
@@ -116,11 +132,12 @@ impl StaticAnalyzer {
         let line_index = LineIndex::new(content);
 
         // Run pattern matching
-        let mut findings = self.analyze_content(content, path, ext, &line_index);
+        let mut findings = self.analyze_content(content, path, ext, &line_index, component_type);
 
         // Analyze decoded content
         if self.config.analyze_decoded {
-            let decoded_findings = self.analyze_decoded_content(content, path, ext, &line_index);
+            let decoded_findings =
+                self.analyze_decoded_content(content, path, ext, &line_index, component_type);
             findings.extend(decoded_findings);
         }
 
@@ -130,6 +147,10 @@ impl StaticAnalyzer {
             findings.extend(entropy_findings);
         }
 
+        if matches!(ext, "js" | "mjs" | "cjs" | "jsx") {
+            findings.extend(self.analyze_obfuscator_fingerprint(content, path));
+        }
+
         result.findings = findings;
         result.scan_time_ms = start.elapsed().as_millis() as u64;
 
@@ -143,59 +164,176 @@ impl StaticAnalyzer {
         path: &Path,
         ext: &str,
         line_index: &LineIndex,
+        component_type: Option<ComponentType>,
     ) -> Vec<Finding> {
         let mut findings = Vec::new();
 
         // Use RegexSet pre-filter: single-pass identifies which rules match,
         // then only extract positions from those rules.
         let filename = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
-        for (rule, matches) in self
-            .rules
-            .find_matches_for_file(content, ext, Some(filename))
-        {
-            for mat in matches {
-                let (start_line, start_col) = line_index.offset_to_line_col(mat.start());
-                let (end_line, end_col) = line_index.offset_to_line_col(mat.end());
-
-                let snippet = get_context_snippet(content, mat.start(), mat.end(), 50);
-
-                let mut finding = Finding::new(
-                    &rule.rule.id,
-                    &rule.rule.title,
-                    &rule.rule.description,
-                    rule.rule.severity,
-                    rule.rule.category.clone(),
-                    Location::new(path.to_path_buf(), start_line, end_line)
-                        .with_columns(start_col, end_col),
-                    snippet,
-                );
 
-                if let Some(ref rem) = rule.rule.remediation {
-                    finding = finding.with_remediation(rem);
-                }
+        // Rules with a `target` match against a same-length, position-
+        // preserving masked copy of `content` instead of the raw bytes, so
+        // e.g. a `frontmatter`-targeted rule can't be tripped by the
+        // markdown body below it. Masking is skipped entirely (no owned
+        // copy made) unless some loaded rule actually asks for that target.
+        const MASKED_TARGETS: [RuleTarget; 3] = [
+            RuleTarget::Frontmatter,
+            RuleTarget::MarkdownBody,
+            RuleTarget::JsonValue,
+        ];
+        let masked_contents: Vec<(RuleTarget, String)> = MASKED_TARGETS
+            .into_iter()
+            .filter(|t| self.rules.has_rules_with_target(*t))
+            .map(|t| (t, crate::rules::target::mask_for_target(content, t)))
+            .collect();
+
+        let mut match_passes: Vec<(&str, Option<RuleTarget>)> = vec![(content, None)];
+        for (target, masked) in &masked_contents {
+            match_passes.push((masked.as_str(), Some(*target)));
+        }
 
-                // Cap severity for documentation files — code patterns in docs
-                // are informational, not actionable. Content-attack rules
-                // (prompt injection, hidden instructions) are exempt since
-                // markdown IS their attack surface.
-                if matches!(ext, "md" | "txt" | "rst" | "adoc")
-                    && !rule.rule.id.starts_with("INJECT-")
-                    && !rule.rule.id.starts_with("AUTH-")
-                    && !rule.rule.id.starts_with("HIDDEN-")
-                    && !rule.rule.id.starts_with("MDCODE-")
-                    && finding.severity > Severity::Low
-                {
-                    finding
-                        .metadata
-                        .entry("original_severity".to_string())
-                        .or_insert_with(|| format!("{}", finding.severity));
-                    finding.severity = Severity::Low;
-                }
+        for (pass_content, target) in match_passes {
+            for (rule, matches) in self.rules.find_matches_for_file(
+                pass_content,
+                ext,
+                Some(filename),
+                component_type,
+                target,
+            ) {
+                // Composite rules (all_of/any_of/none_of) return one match per
+                // required pattern, and scoring rules return one match per
+                // present indicator, rather than one match per finding —
+                // collapse them into a single finding anchored on the first
+                // match so a co-occurrence/scoring rule doesn't produce a
+                // finding per pattern.
+                let match_iter: Box<dyn Iterator<Item = regex::Match>> =
+                    if rule.rule.composite.is_some() || rule.rule.scoring.is_some() {
+                        Box::new(matches.into_iter().take(1))
+                    } else {
+                        Box::new(matches.into_iter())
+                    };
+                // Second pass: a `context` condition narrows candidate matches
+                // down to those with another pattern nearby (e.g. a base64 blob
+                // within 5 lines of `eval`), without changing whether the rule
+                // can match on its own.
+                let match_iter =
+                    match_iter.filter(|mat| rule.context_satisfied(pass_content, mat.start()));
+                for mat in match_iter {
+                    let (start_line, start_col) = line_index.offset_to_line_col(mat.start());
+                    let (end_line, end_col) = line_index.offset_to_line_col(mat.end());
+
+                    let snippet = get_context_snippet(content, mat.start(), mat.end(), 50);
+
+                    let mut finding = Finding::new(
+                        &rule.rule.id,
+                        rule.rule.localized_title(&self.config.lang),
+                        rule.rule.localized_description(&self.config.lang),
+                        rule.rule.severity,
+                        rule.rule.category.clone(),
+                        Location::new(path.to_path_buf(), start_line, end_line)
+                            .with_columns(start_col, end_col),
+                        snippet,
+                    )
+                    .with_confidence(rule.rule.confidence)
+                    .with_cwe(if rule.rule.cwe.is_empty() {
+                        crate::compliance::default_cwe(&rule.rule.category)
+                    } else {
+                        rule.rule.cwe.clone()
+                    })
+                    .with_owasp_llm(if rule.rule.owasp_llm.is_empty() {
+                        crate::compliance::default_owasp_llm(&rule.rule.category)
+                    } else {
+                        rule.rule.owasp_llm.clone()
+                    })
+                    .with_attack_technique(
+                        if rule.rule.attack_technique.is_empty() {
+                            crate::compliance::default_attack_technique(&rule.rule.category)
+                        } else {
+                            rule.rule.attack_technique.clone()
+                        },
+                    );
+
+                    if let Some(rem) = rule.rule.localized_remediation(&self.config.lang) {
+                        finding = finding.with_remediation(rem);
+                    }
+
+                    for (name, value) in rule.named_captures_at(pass_content, &mat) {
+                        finding = finding.with_metadata(name, value.to_string());
+                    }
+
+                    if let Some((score, _)) = rule.scoring_match(pass_content) {
+                        finding = finding.with_metadata("score", format!("{score}"));
+                        finding = finding.with_metadata(
+                            "threshold",
+                            format!("{}", rule.rule.scoring.as_ref().unwrap().threshold),
+                        );
+                    }
+
+                    if let Some(fix) =
+                        build_fix_for_rule(&rule.rule.id, content, mat.start(), mat.end())
+                    {
+                        finding = finding.with_fix(fix);
+                    }
+
+                    // Cap severity for documentation files — code patterns in docs
+                    // are informational, not actionable. Content-attack rules
+                    // (prompt injection, hidden instructions) are exempt since
+                    // markdown IS their attack surface.
+                    if matches!(ext, "md" | "txt" | "rst" | "adoc")
+                        && !rule.rule.id.starts_with("INJECT-")
+                        && !rule.rule.id.starts_with("AUTH-")
+                        && !rule.rule.id.starts_with("HIDDEN-")
+                        && !rule.rule.id.starts_with("MDCODE-")
+                        && finding.severity > Severity::Low
+                    {
+                        finding
+                            .metadata
+                            .entry("original_severity".to_string())
+                            .or_insert_with(|| format!("{}", finding.severity));
+                        finding.severity = Severity::Low;
+                    }
 
-                findings.push(finding);
+                    findings.push(finding);
+                }
             }
         }
 
+        // If the document already tripped an instruction-override/authority
+        // rule and also switches script mid-way through, flag the language
+        // switch itself: translating a payload into a second language is a
+        // common way to smuggle it past phrase rules tuned for one language.
+        if matches!(ext, "md" | "txt" | "rst" | "adoc")
+            && findings
+                .iter()
+                .any(|f| crate::analyzers::injection_context::is_injection_rule(&f.rule_id))
+            && crate::analyzers::injection_context::has_mixed_script_switch(content)
+        {
+            findings.push(
+                Finding::new(
+                    "INJECT-010",
+                    "Mid-document language switching",
+                    "This document switches between unrelated scripts partway through and also \
+                 contains an instruction-override or authority-impersonation pattern. \
+                 Translating a payload into a second language is a common way to evade \
+                 phrase rules tuned for a single language.",
+                    Severity::Medium,
+                    FindingCategory::PromptInjection,
+                    Location::new(path.to_path_buf(), 1, 1),
+                    truncate(content, 80),
+                )
+                .with_cwe(crate::compliance::default_cwe(
+                    &FindingCategory::PromptInjection,
+                ))
+                .with_owasp_llm(crate::compliance::default_owasp_llm(
+                    &FindingCategory::PromptInjection,
+                ))
+                .with_attack_technique(
+                    crate::compliance::default_attack_technique(&FindingCategory::PromptInjection),
+                ),
+            );
+        }
+
         findings
     }
 
@@ -206,6 +344,7 @@ impl StaticAnalyzer {
         path: &Path,
         ext: &str,
         line_index: &LineIndex,
+        component_type: Option<ComponentType>,
     ) -> Vec<Finding> {
         let mut findings = Vec::new();
 
@@ -221,8 +360,13 @@ impl StaticAnalyzer {
 
                 // Check if the decoded content contains suspicious patterns
                 let decoded_line_index = LineIndex::new(&decoded.decoded);
-                let decoded_findings =
-                    self.analyze_content(&decoded.decoded, path, ext, &decoded_line_index);
+                let decoded_findings = self.analyze_content(
+                    &decoded.decoded,
+                    path,
+                    ext,
+                    &decoded_line_index,
+                    component_type,
+                );
 
                 if !decoded_findings.is_empty() {
                     // Create a finding for the obfuscated malicious content
@@ -249,7 +393,17 @@ impl StaticAnalyzer {
                     )
                     .with_metadata("encoding", decoded.encoding.to_string())
                     .with_metadata("decode_depth", (depth + 1).to_string())
-                    .with_remediation("Review the decoded content and remove if malicious.");
+                    .with_remediation("Review the decoded content and remove if malicious.")
+                    .with_confidence(Confidence::High)
+                    .with_cwe(crate::compliance::default_cwe(
+                        &FindingCategory::Obfuscation,
+                    ))
+                    .with_owasp_llm(crate::compliance::default_owasp_llm(
+                        &FindingCategory::Obfuscation,
+                    ))
+                    .with_attack_technique(
+                        crate::compliance::default_attack_technique(&FindingCategory::Obfuscation),
+                    );
 
                     findings.push(finding);
                 }
@@ -287,7 +441,17 @@ impl StaticAnalyzer {
                             .with_columns(start_col, start_col + s.chars().count()),
                         truncate(s, 80),
                     )
-                    .with_metadata("entropy", format!("{:.2}", entropy));
+                    .with_metadata("entropy", format!("{:.2}", entropy))
+                    .with_confidence(Confidence::Low)
+                    .with_cwe(crate::compliance::default_cwe(
+                        &FindingCategory::Obfuscation,
+                    ))
+                    .with_owasp_llm(crate::compliance::default_owasp_llm(
+                        &FindingCategory::Obfuscation,
+                    ))
+                    .with_attack_technique(
+                        crate::compliance::default_attack_technique(&FindingCategory::Obfuscation),
+                    );
 
                     findings.push(finding);
                 }
@@ -297,6 +461,72 @@ impl StaticAnalyzer {
         findings
     }
 
+    /// Fingerprint the structural signature `obfuscator.io` (and similar
+    /// commercial JS obfuscators) leaves behind, even when the payload
+    /// itself trips no other rule: hex-named identifiers (`_0x1a2b`), the
+    /// self-invoking string-array rotation used to deobfuscate string
+    /// literals at runtime, and the `setInterval`-driven `debugger` loop
+    /// used for anti-tampering ("self-defending" mode). Any one signal
+    /// alone is common in legitimate minified bundles, so this only fires
+    /// once at least two co-occur, with confidence scaling with how many do.
+    fn analyze_obfuscator_fingerprint(&self, content: &str, path: &Path) -> Vec<Finding> {
+        let patterns = &self.obfuscator_patterns;
+
+        let mut signals = Vec::new();
+        if patterns.hex_identifier.find_iter(content).count() >= HEX_IDENTIFIER_MIN_COUNT {
+            signals.push("hex_identifier_names");
+        }
+        if patterns.string_array_rotation.is_match(content) {
+            signals.push("rotating_string_array");
+        }
+        if content.contains("debugger") && patterns.debug_protection_loop.is_match(content) {
+            signals.push("self_defending_debugger_loop");
+        }
+
+        if signals.len() < 2 {
+            return Vec::new();
+        }
+
+        let confidence = if signals.len() == 3 {
+            Confidence::High
+        } else {
+            Confidence::Medium
+        };
+
+        vec![Finding::new(
+            "OBFUSC-BUNDLE",
+            "Commercially obfuscated code in agent component",
+            format!(
+                "This file matches the structural signature of a commercial JS obfuscator \
+                (e.g. obfuscator.io): {}. Legitimate minified bundles rarely combine more than \
+                one of these; together they indicate the source was deliberately hidden rather \
+                than just compressed.",
+                signals.join(", ")
+            ),
+            Severity::High,
+            FindingCategory::Obfuscation,
+            Location::new(path.to_path_buf(), 1, 1),
+            truncate(content, 80),
+        )
+        .with_metadata("signals", signals.join(","))
+        .with_metadata("signal_count", signals.len().to_string())
+        .with_confidence(confidence)
+        .with_remediation(
+            "Review the original, unobfuscated source of this component before trusting it; \
+            commercial obfuscation on agent-facing code is a common way to hide malicious logic \
+            from reviewers.",
+        )
+        .with_cwe(crate::compliance::default_cwe(
+            &FindingCategory::Obfuscation,
+        ))
+        .with_owasp_llm(crate::compliance::default_owasp_llm(
+            &FindingCategory::Obfuscation,
+        ))
+        .with_attack_technique(crate::compliance::default_attack_technique(
+            &FindingCategory::Obfuscation,
+        ))]
+    }
+
     /// Load external rules from a directory, tagging them as External.
     /// Returns the number of rules loaded, or an error.
     pub fn load_external_rules_dir(
@@ -311,6 +541,12 @@ impl StaticAnalyzer {
     pub fn rule_count(&self) -> usize {
         self.rules.rule_count()
     }
+
+    /// The underlying rule set, e.g. for resolving deprecated rule ID
+    /// aliases when matching suppressions.
+    pub fn ruleset(&self) -> &RuleSet {
+        &self.rules
+    }
 }
 
 impl Default for StaticAnalyzer {
@@ -319,6 +555,39 @@ impl Default for StaticAnalyzer {
     }
 }
 
+/// Minimum number of hex-style identifiers (`_0x1a2b`) before that naming
+/// convention alone counts as an obfuscator signal, rather than a coincidence
+/// or a single deliberately-named variable.
+const HEX_IDENTIFIER_MIN_COUNT: usize = 5;
+
+/// Pre-compiled regexes for `obfuscator.io`-style structural fingerprinting.
+struct ObfuscatorPatterns {
+    /// `_0x1a2b`-style identifier, the default naming scheme for renamed
+    /// variables/functions.
+    hex_identifier: Regex,
+    /// The self-invoking array-rotation IIFE obfuscator.io emits to
+    /// deobfuscate its string table at runtime:
+    /// `_0x1a2b['push'](_0x1a2b['shift']())`.
+    string_array_rotation: Regex,
+    /// The `setInterval(_0x1a2b, N)` polling loop used by "self-defending"
+    /// mode to repeatedly re-trigger a function containing a `debugger`
+    /// statement, so pausing in devtools re-triggers the breakpoint.
+    debug_protection_loop: Regex,
+}
+
+impl ObfuscatorPatterns {
+    fn new() -> Self {
+        Self {
+            hex_identifier: Regex::new(r"_0x[0-9a-f]{4,8}\b").unwrap(),
+            string_array_rotation: Regex::new(
+                r"_0x[0-9a-f]{4,8}\['push'\]\(_0x[0-9a-f]{4,8}\['shift'\]\(\)\)",
+            )
+            .unwrap(),
+            debug_protection_loop: Regex::new(r"setInterval\(_0x[0-9a-f]{4,8},\s*\d+\)").unwrap(),
+        }
+    }
+}
+
 /// Pre-computed line offset index for O(log n) line/column lookups.
 struct LineIndex {
     line_starts: Vec<usize>,
@@ -346,6 +615,64 @@ impl LineIndex {
     }
 }
 
+/// Build a fix suggestion for the handful of rules where the flagged span
+/// can be stripped from its line with no risk of altering surrounding
+/// behavior. Everything else (code patterns, credential leaks, etc.) is
+/// left unfixed since a mechanical edit there could easily be wrong.
+fn build_fix_for_rule(
+    rule_id: &str,
+    content: &str,
+    match_start: usize,
+    match_end: usize,
+) -> Option<FixSuggestion> {
+    match rule_id {
+        "HIDDEN-001" => Some(strip_span_fix(
+            content,
+            match_start,
+            match_end,
+            "Remove the zero-width characters hiding content on this line",
+        )),
+        "HIDDEN-002" => {
+            // The rule only matches the opening of the comment, so extend
+            // the span to its closing `-->` before stripping it. Only do
+            // this when the comment closes on the same line as the match —
+            // if it spans further we can't be sure what else is on those
+            // lines, so leave it unfixed.
+            let tail = &content[match_end..];
+            let close_offset = tail.find("-->")?;
+            if tail[..close_offset].contains('\n') {
+                return None;
+            }
+            let comment_end = match_end + close_offset + "-->".len();
+            Some(strip_span_fix(
+                content,
+                match_start,
+                comment_end,
+                "Delete the hidden HTML comment",
+            ))
+        }
+        _ => None,
+    }
+}
+
+/// Remove the byte range `[start, end)` from its line, returning the
+/// resulting line text (empty if nothing meaningful remains).
+fn strip_span_fix(content: &str, start: usize, end: usize, description: &str) -> FixSuggestion {
+    let line_start = content[..start].rfind('\n').map(|i| i + 1).unwrap_or(0);
+    let line_end = content[end..]
+        .find('\n')
+        .map(|i| end + i)
+        .unwrap_or(content.len());
+    let mut replacement = format!("{}{}", &content[line_start..start], &content[end..line_end]);
+    if replacement.trim().is_empty() {
+        replacement.clear();
+    }
+    FixSuggestion {
+        description: description.to_string(),
+        replacement,
+    }
+}
+
 /// Get a snippet of content around a match with context (UTF-8 safe).
 fn get_context_snippet(content: &str, start: usize, end: usize, context: usize) -> String {
     // Find valid UTF-8 boundaries
@@ -413,6 +740,40 @@ mod tests {
         assert!(result.findings.iter().any(|f| f.rule_id == "INJECT-001"));
     }
 
+    #[test]
+    fn test_rule_match_carries_confidence() {
+        let analyzer = StaticAnalyzer::new().unwrap();
+
+        let mut file = NamedTempFile::with_suffix(".js").unwrap();
+        writeln!(file, "const result = eval(userInput);").unwrap();
+
+        let result = analyzer.scan_file(file.path()).unwrap();
+        let finding = result
+            .findings
+            .iter()
+            .find(|f| f.rule_id == "EXEC-001")
+            .unwrap();
+        assert_eq!(finding.confidence, crate::types::Confidence::Medium);
+    }
+
+    #[test]
+    fn test_rule_match_carries_compliance_tags() {
+        let analyzer = StaticAnalyzer::new().unwrap();
+
+        let mut file = NamedTempFile::with_suffix(".js").unwrap();
+        writeln!(file, "const result = eval(userInput);").unwrap();
+
+        let result = analyzer.scan_file(file.path()).unwrap();
+        let finding = result
+            .findings
+            .iter()
+            .find(|f| f.rule_id == "EXEC-001")
+            .unwrap();
+        assert!(!finding.cwe.is_empty());
+        assert!(!finding.owasp_llm.is_empty());
+        assert!(!finding.attack_technique.is_empty());
+    }
+
     #[test]
     fn test_line_index() {
         let content = "line1\nline2\nline3";
@@ -421,4 +782,77 @@ mod tests {
         assert_eq!(idx.offset_to_line_col(6), (2, 1));
         assert_eq!(idx.offset_to_line_col(8), (2, 3));
     }
+
+    #[test]
+    fn test_obfuscator_fingerprint_detects_rotation_plus_hex_idents() {
+        let analyzer = StaticAnalyzer::new().unwrap();
+
+        let mut file = NamedTempFile::with_suffix(".js").unwrap();
+        writeln!(
+            file,
+            "var _0xa1b2 = ['log', 'foo', 'bar', 'baz', 'qux'];\n\
+             (function(_0xc3d4, _0xe5f6) {{\n\
+             while (--_0xe5f6) {{ _0xc3d4['push'](_0xc3d4['shift']()); }}\n\
+             }}(_0xa1b2, 0x1a2));"
+        )
+        .unwrap();
+
+        let result = analyzer.scan_file(file.path()).unwrap();
+        let finding = result
+            .findings
+            .iter()
+            .find(|f| f.rule_id == "OBFUSC-BUNDLE")
+            .expect("expected OBFUSC-BUNDLE finding");
+        assert_eq!(finding.severity, Severity::High);
+        assert_eq!(finding.confidence, Confidence::Medium);
+        assert!(finding
+            .metadata
+            .get("signals")
+            .unwrap()
+            .contains("rotating_string_array"));
+    }
+
+    #[test]
+    fn test_obfuscator_fingerprint_all_three_signals_is_high_confidence() {
+        let analyzer = StaticAnalyzer::new().unwrap();
+
+        let mut file = NamedTempFile::with_suffix(".js").unwrap();
+        writeln!(
+            file,
+            "var _0xa1b2 = ['log', 'foo', 'bar', 'baz', 'qux'];\n\
+             (function(_0xc3d4, _0xe5f6) {{\n\
+             while (--_0xe5f6) {{ _0xc3d4['push'](_0xc3d4['shift']()); }}\n\
+             }}(_0xa1b2, 0x1a2));\n\
+             function _0x9988() {{ debugger; }}\n\
+             setInterval(_0x9988, 4000);"
+        )
+        .unwrap();
+
+        let result = analyzer.scan_file(file.path()).unwrap();
+        let finding = result
+            .findings
+            .iter()
+            .find(|f| f.rule_id == "OBFUSC-BUNDLE")
+            .expect("expected OBFUSC-BUNDLE finding");
+        assert_eq!(finding.confidence, Confidence::High);
+        assert_eq!(finding.metadata.get("signal_count").unwrap(), "3");
+    }
+
+    #[test]
+    fn test_obfuscator_fingerprint_single_signal_not_flagged() {
+        let analyzer = StaticAnalyzer::new().unwrap();
+
+        // A handful of hex-named identifiers alone (e.g. from an unrelated
+        // minifier) shouldn't trip the fingerprint on their own.
+        let mut file = NamedTempFile::with_suffix(".js").unwrap();
+        writeln!(
+            file,
+            "var _0xa1b2 = 1; var _0xc3d4 = 2; var _0xe5f6 = 3; \
+             var _0x1122 = 4; var _0x3344 = 5;"
+        )
+        .unwrap();
+
+        let result = analyzer.scan_file(file.path()).unwrap();
+        assert!(!result.findings.iter().any(|f| f.rule_id == "OBFUSC-BUNDLE"));
+    }
 }
@@ -0,0 +1,85 @@
+//! Binary file detection and embedded string extraction.
+//!
+//! Files that are true binaries (compiled `.node` addons, `.wasm` modules,
+//! images, ...) can't be usefully scanned with regex/AST: they either
+//! aren't valid UTF-8 at all, or matching raw bytes as text produces
+//! garbage matches. Detected binaries are instead reduced to their
+//! printable ASCII strings (like the Unix `strings` tool) before being fed
+//! through the same rule engine, catching e.g. hardcoded secrets or C2
+//! URLs embedded in a compiled blob rather than skipping the file outright.
+
+use crate::adapters::is_binary_file;
+use std::path::Path;
+
+/// Number of leading bytes sniffed to decide whether content is binary.
+const SNIFF_LEN: usize = 8192;
+
+/// Minimum run length (in bytes) of printable ASCII kept as a "string".
+const MIN_STRING_LEN: usize = 6;
+
+/// Heuristic binary-content check: a NUL byte within the first `SNIFF_LEN`
+/// bytes almost never occurs in real text. This is the same heuristic git
+/// uses to decide whether to diff a file as text or binary.
+pub fn is_binary_content(bytes: &[u8]) -> bool {
+    bytes.iter().take(SNIFF_LEN).any(|&b| b == 0)
+}
+
+/// True if `path`'s extension marks it as a known binary type, or its
+/// content sniffs as binary. Combining both catches renamed or
+/// extension-less binaries that the extension list alone would miss.
+pub fn is_binary(path: &Path, bytes: &[u8]) -> bool {
+    is_binary_file(path) || is_binary_content(bytes)
+}
+
+/// Extract printable ASCII runs of at least `MIN_STRING_LEN` bytes, one per
+/// line — a lightweight stand-in for file content when regex/AST analysis
+/// can't run against raw binary bytes.
+pub fn extract_strings(bytes: &[u8]) -> String {
+    let mut out = String::new();
+    let mut run_start: Option<usize> = None;
+
+    for (i, &b) in bytes.iter().enumerate() {
+        let printable = (0x20..=0x7e).contains(&b);
+        if printable && run_start.is_none() {
+            run_start = Some(i);
+        } else if !printable {
+            if let Some(start) = run_start.take() {
+                push_run(&mut out, &bytes[start..i]);
+            }
+        }
+    }
+    if let Some(start) = run_start {
+        push_run(&mut out, &bytes[start..]);
+    }
+
+    out
+}
+
+fn push_run(out: &mut String, run: &[u8]) {
+    if run.len() >= MIN_STRING_LEN {
+        if let Ok(s) = std::str::from_utf8(run) {
+            out.push_str(s);
+            out.push('\n');
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_nul_byte_as_binary() {
+        assert!(is_binary_content(b"hello\0world"));
+        assert!(!is_binary_content(b"hello world"));
+    }
+
+    #[test]
+    fn extracts_printable_runs_above_min_length() {
+        let bytes = b"\x00\x00api_key=sekrit12345\x00\x01\x02hi\x00longer_string_here";
+        let strings = extract_strings(bytes);
+        assert!(strings.contains("api_key=sekrit12345"));
+        assert!(strings.contains("longer_string_here"));
+        assert!(!strings.contains("hi\n"));
+    }
+}
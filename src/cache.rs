@@ -16,6 +16,19 @@ const CACHE_VERSION: u32 = 1;
 /// Maximum age of a cache entry before it's considered stale (7 days).
 const MAX_AGE_SECS: i64 = 7 * 24 * 3600;
 
+/// OS cache directory, falling back to `/tmp`. On non-`native` builds (e.g.
+/// wasm32, where there's no OS cache directory to detect) this always falls
+/// back to `/tmp`.
+#[cfg(feature = "native")]
+fn default_cache_dir() -> PathBuf {
+    dirs::cache_dir().unwrap_or_else(|| PathBuf::from("/tmp"))
+}
+
+#[cfg(not(feature = "native"))]
+fn default_cache_dir() -> PathBuf {
+    PathBuf::from("/tmp")
+}
+
 /// Inputs that affect scan output. Any change produces a different profile hash,
 /// causing all existing cache entries to miss.
 pub struct ScanProfile {
@@ -71,10 +84,7 @@ pub struct ScanCache {
 impl ScanCache {
     /// Create a new cache, creating the directory if needed.
     pub fn new(profile: ScanProfile) -> Result<Self> {
-        let cache_dir = dirs::cache_dir()
-            .unwrap_or_else(|| PathBuf::from("/tmp"))
-            .join("vexscan")
-            .join("results");
+        let cache_dir = default_cache_dir().join("vexscan").join("results");
         std::fs::create_dir_all(&cache_dir)?;
         Ok(Self {
             cache_dir,
@@ -12,6 +12,101 @@ pub enum CacheSubcommand {
     Clear,
 }
 
+/// Subcommands for the `baseline` command
+#[derive(Subcommand, Debug)]
+pub enum BaselineSubcommand {
+    /// Snapshot current findings into a baseline file, so they're suppressed
+    /// on future scans and only newly introduced findings are reported.
+    /// Useful for adopting vexscan on an existing repo full of legacy hits.
+    Create {
+        /// Path to scan
+        path: PathBuf,
+
+        /// Where to write the baseline file
+        #[arg(short, long, default_value = ".vexscan-baseline.json")]
+        output: PathBuf,
+
+        /// Platform to scan (auto-detect if not specified)
+        #[arg(short, long)]
+        platform: Option<String>,
+
+        /// Enable AST-based analysis for obfuscation detection
+        #[arg(long)]
+        ast: bool,
+
+        /// Enable dependency scanning (check package.json for malicious packages)
+        #[arg(long)]
+        deps: bool,
+
+        /// Skip dependencies (node_modules, etc.) during scan
+        #[arg(long)]
+        skip_deps: bool,
+    },
+
+    /// Scan a path and suppress findings already recorded in a baseline
+    /// file, reporting only newly introduced findings. Equivalent to
+    /// `scan --baseline <path>`.
+    Apply {
+        /// Path to scan
+        path: PathBuf,
+
+        /// Baseline file created with `vexscan baseline create`
+        #[arg(short, long)]
+        baseline: PathBuf,
+
+        /// Platform to scan (auto-detect if not specified)
+        #[arg(short, long)]
+        platform: Option<String>,
+
+        /// Enable AST-based analysis for obfuscation detection
+        #[arg(long)]
+        ast: bool,
+
+        /// Enable dependency scanning (check package.json for malicious packages)
+        #[arg(long)]
+        deps: bool,
+
+        /// Skip dependencies (node_modules, etc.) during scan
+        #[arg(long)]
+        skip_deps: bool,
+    },
+}
+
+/// Subcommands for the `hook` command
+#[derive(Subcommand, Debug)]
+pub enum HookSubcommand {
+    /// Install a git pre-commit hook that runs `vexscan scan
+    /// --changed-since HEAD --fail-on high --skip-deps` (skipping AI
+    /// analysis and dependency scanning for a fast, sub-second check) and
+    /// blocks the commit if it finds anything.
+    Install {
+        /// Path to the git repository to install the hook into
+        #[arg(default_value = ".")]
+        path: PathBuf,
+
+        /// Overwrite an existing pre-commit hook
+        #[arg(long)]
+        force: bool,
+    },
+}
+
+/// Subcommands for the `history` command
+#[derive(Subcommand, Debug)]
+pub enum HistorySubcommand {
+    /// Show recorded scan history and a trendline of findings over time
+    Show {
+        /// Only show history for scans whose target contains this substring
+        #[arg(short, long)]
+        target: Option<String>,
+
+        /// Maximum number of most recent records to show
+        #[arg(short = 'n', long, default_value_t = 20)]
+        limit: usize,
+    },
+    /// Delete all recorded scan history
+    Clear,
+}
+
 /// Subcommands for the `rules` command
 #[derive(Subcommand, Debug)]
 pub enum RulesSubcommand {
@@ -29,6 +124,52 @@ pub enum RulesSubcommand {
         #[arg(long)]
         verbose: bool,
     },
+
+    /// Validate a rules directory: regex compilation, required fields,
+    /// duplicate IDs, and unknown severity/confidence values. Reports every
+    /// problem found with the offending file (and line, for JSON syntax
+    /// errors), instead of only surfacing it as a warning log at scan time.
+    Validate {
+        /// Path to a rules directory (e.g. containing official/ and
+        /// community/ subdirectories, or JSON rule files directly)
+        path: PathBuf,
+    },
+
+    /// Lint a rules directory for likely-mistake smells that still pass
+    /// `validate`: duplicate IDs, patterns that are a literal subset of
+    /// another pattern on the same rule, `.*`/`.+` combined with
+    /// `dot_matches_newline` (unbounded across the whole file), and
+    /// classic catastrophic-backtracking shapes like `(a+)+`. These are
+    /// warnings for rule authors to review, not necessarily bugs.
+    Lint {
+        /// Path to a rules directory (e.g. containing official/ and
+        /// community/ subdirectories, or JSON rule files directly)
+        path: PathBuf,
+    },
+
+    /// Sync the community rules directory (~/.vexscan/rules/community/)
+    /// from an upstream repository: shows a changelog of added/modified/
+    /// removed rules and validates the new rules before activating them.
+    /// The existing installed rules are left untouched if validation fails.
+    Update {
+        /// GitHub URL of the repository to sync community rules from
+        #[arg(long, default_value = "https://github.com/edimuj/vexscan")]
+        source: String,
+
+        /// Branch to sync from (default branch if not specified)
+        #[arg(long)]
+        branch: Option<String>,
+
+        /// Show the changelog without installing anything
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Install rules even if some fail their own `should_match`/
+        /// `should_not_match` test cases, instead of refusing to activate
+        /// them. Failing rules are still listed as warnings.
+        #[arg(long)]
+        allow_failing_rules: bool,
+    },
 }
 
 /// Security scanner for AI agent plugins, skills, and MCP servers.
@@ -52,15 +193,33 @@ pub struct Cli {
     #[arg(short, long, global = true)]
     pub config: Option<PathBuf>,
 
+    /// Language for finding titles/descriptions/remediations (e.g. "es",
+    /// "ja"). Falls back to the config file's `lang`, then "en". Rules with
+    /// no translation for the selected language fall back to English.
+    #[arg(long, global = true)]
+    pub lang: Option<String>,
+
+    /// Render the report through a user-supplied Tera template instead of
+    /// `-f`/`--format`. The full scan report (same shape as `-f json`) is
+    /// exposed as the template context.
+    #[arg(long, global = true, value_name = "PATH")]
+    pub template: Option<PathBuf>,
+
     #[command(subcommand)]
     pub command: Commands,
 }
 
+// Subcommands naturally vary a lot in flag count; this enum is built once
+// per invocation and never hot-path cloned, so boxing fields to shrink it
+// isn't worth the added indirection.
+#[allow(clippy::large_enum_variant)]
 #[derive(Subcommand, Debug)]
 pub enum Commands {
     /// Scan a path or platform for security issues
     Scan {
-        /// Path to scan (file or directory)
+        /// Path to scan (file or directory), or `npm:package@version` /
+        /// `npm:package` (latest) to download and scan exactly what npm
+        /// would publish and install
         #[arg(default_value = ".")]
         path: PathBuf,
 
@@ -72,10 +231,32 @@ pub enum Commands {
         #[arg(long)]
         ai: bool,
 
-        /// AI backend to use (claude, openai, ollama)
+        /// AI backend to use (claude, openai, gemini, ollama, local)
         #[arg(long, default_value = "claude")]
         ai_backend: String,
 
+        /// Path to a local GGUF model file (with `--ai-backend local`)
+        #[arg(long)]
+        ai_model_path: Option<PathBuf>,
+
+        /// Have the AI triage the static/AST findings already detected for
+        /// each file (classifying each as a true positive, likely false
+        /// positive, or needing review) instead of independently
+        /// re-scanning file content for new findings. Only meaningful with
+        /// --ai
+        #[arg(long)]
+        ai_triage: bool,
+
+        /// Restrict the AI phase to a targeted prompt-injection scan over
+        /// prompt/instruction files and MCP server config, using an
+        /// injection-specialized prompt that reports the specific
+        /// manipulative sentences found (with byte offsets, for
+        /// highlighting) instead of independently re-scanning every file.
+        /// --ai-triage takes priority if both are set. Only meaningful with
+        /// --ai
+        #[arg(long)]
+        ai_injection_scan: bool,
+
         /// Output file (writes to stdout if not specified)
         #[arg(short, long)]
         output: Option<PathBuf>,
@@ -88,10 +269,26 @@ pub enum Commands {
         #[arg(long, default_value = "high")]
         fail_on: String,
 
+        /// Minimum confidence to report (low, medium, high)
+        #[arg(long, default_value = "low")]
+        min_confidence: String,
+
         /// Skip node_modules directories entirely (focus on actual code)
         #[arg(long)]
         skip_deps: bool,
 
+        /// Scan node_modules selectively: only packages declaring a
+        /// preinstall/postinstall/prepare script (plus files that script
+        /// invokes), instead of skipping node_modules entirely
+        #[arg(long)]
+        node_modules_scripts_only: bool,
+
+        /// Don't honor .gitignore/.vexscanignore when discovering files
+        /// (scan everything the adapter finds, including build artifacts
+        /// and vendored junk those files normally exclude)
+        #[arg(long)]
+        no_ignore_files: bool,
+
         /// Enable entropy analysis (disabled by default due to false positives)
         #[arg(long)]
         enable_entropy: bool,
@@ -116,17 +313,122 @@ pub enum Commands {
         #[arg(long)]
         no_cache: bool,
 
-        /// Only scan installed/published files (skip tests, examples, docs)
+        /// Resume a scan interrupted by a crash or Ctrl-C: forces result
+        /// caching on for this run (even if --no-cache was also passed) and
+        /// reports how many files were already covered by cache entries from
+        /// the previous attempt, so multi-hour scans of huge trees don't
+        /// have to start over from file one
+        #[arg(long)]
+        resume: bool,
+
+        /// Downgrade findings in dev-only files (tests, examples, docs) to
+        /// Low severity and confidence instead of reporting them at full
+        /// strength. Unlike `--skip-dev-only`, these files are still scanned
+        /// and their findings still appear in the report, just quieted down.
         #[arg(long)]
         installed_only: bool,
 
+        /// Skip dev-only files (tests, examples, docs) entirely instead of
+        /// downgrading their findings. Faster, but malware hidden in a
+        /// dev-only path won't be reported at all.
+        #[arg(long)]
+        skip_dev_only: bool,
+
         /// Scan all files at full severity (disable scope-based severity capping)
         #[arg(long)]
         include_dev: bool,
 
+        /// Show a MITRE ATT&CK/ATLAS technique coverage matrix in the report
+        #[arg(long)]
+        attack_matrix: bool,
+
+        /// How to aggregate the CLI text report's detailed findings:
+        /// "file" (default, one section per file/component), "rule"
+        /// (collapse repeats of the same rule into one section with a
+        /// count), or "severity" (one section per severity level)
+        #[arg(long, default_value = "file")]
+        group_by: String,
+
+        /// Mask secret-like substrings (API keys, tokens) in finding
+        /// snippets so the report itself doesn't leak credentials when
+        /// shared or archived (e.g. in CI logs)
+        #[arg(long)]
+        redact_snippets: bool,
+
+        /// Apply safe automatic fixes to findings that support them
+        #[arg(long)]
+        fix: bool,
+
+        /// Preview the fixes --fix would apply, without modifying any files
+        #[arg(long)]
+        fix_dry_run: bool,
+
+        /// Baseline file of previously-accepted findings to suppress (see `vexscan init`)
+        #[arg(long, value_name = "PATH")]
+        baseline: Option<PathBuf>,
+
         /// Max parallel threads (default: half of available CPUs, 0 = all CPUs)
         #[arg(short = 'j', long, value_name = "N")]
         jobs: Option<usize>,
+
+        /// Only scan files changed since this git ref (e.g. `HEAD`,
+        /// `main`), including uncommitted changes. Useful for fast
+        /// pre-commit checks on large repos. Requires `path` to be inside a
+        /// git repository.
+        #[arg(long, value_name = "REF")]
+        changed_since: Option<String>,
+
+        /// Report per-phase timing (discovery, static, AST, AI, deps),
+        /// files/bytes scanned, and the slowest files, to help tune
+        /// configuration on huge trees
+        #[arg(long)]
+        stats: bool,
+
+        /// Skip files larger than this size in bytes instead of scanning
+        /// them (e.g. `10485760` for 10MB). Unlimited by default.
+        #[arg(long, value_name = "BYTES")]
+        max_file_size: Option<u64>,
+
+        /// Scan at most this many discovered files; the rest are recorded
+        /// as skipped rather than analyzed. Unlimited by default.
+        #[arg(long, value_name = "N")]
+        max_total_files: Option<usize>,
+
+        /// Stop starting new file analysis after this many seconds;
+        /// already-started files still finish, remaining ones are recorded
+        /// as skipped. Unlimited by default.
+        #[arg(long, value_name = "SECONDS")]
+        max_scan_duration: Option<u64>,
+
+        /// Keep at most this many findings per file, dropping the rest.
+        /// Unlimited by default.
+        #[arg(long, value_name = "N")]
+        max_findings_per_file: Option<usize>,
+
+        /// Cap how many files may be analyzed at once, on top of --jobs's
+        /// thread pool size. Useful for keeping a scan from crowding out
+        /// other work on a developer laptop or a shared CI runner.
+        /// Unlimited (bounded only by --jobs) by default.
+        #[arg(long, value_name = "N")]
+        max_concurrent_files: Option<usize>,
+
+        /// Cap how many AI backend requests may be in flight at once. Only
+        /// meaningful with --ai. Defaults to a conservative built-in limit
+        /// rather than firing one request per file.
+        #[arg(long, value_name = "N")]
+        max_concurrent_ai_requests: Option<usize>,
+
+        /// Cap disk read throughput during scanning to roughly this many
+        /// bytes per second, to avoid saturating IO on shared or
+        /// resource-constrained machines. Unlimited by default.
+        #[arg(long, value_name = "BYTES")]
+        max_io_bytes_per_sec: Option<u64>,
+
+        /// Stop submitting new files for AI analysis once the estimated
+        /// cost of AI backend calls so far in this scan reaches this many
+        /// US dollars. Only meaningful with --ai. Unlimited by default.
+        #[arg(long, value_name = "USD")]
+        max_ai_cost_usd: Option<f64>,
     },
 
     /// Watch for new plugin/skill installations and scan automatically
@@ -147,14 +449,27 @@ pub enum Commands {
         #[arg(long, default_value = "medium")]
         min_severity: String,
 
+        /// Minimum confidence to alert on (low, medium, high)
+        #[arg(long, default_value = "low")]
+        min_confidence: String,
+
         /// Custom paths to watch (can be used multiple times)
         #[arg(long = "path", value_name = "PATH")]
         watch_paths: Vec<std::path::PathBuf>,
 
-        /// Only scan installed/published files (skip tests, examples, docs)
+        /// Downgrade findings in dev-only files (tests, examples, docs) to
+        /// Low severity and confidence instead of reporting them at full
+        /// strength. Unlike `--skip-dev-only`, these files are still scanned
+        /// and their findings still appear in the report, just quieted down.
         #[arg(long)]
         installed_only: bool,
 
+        /// Skip dev-only files (tests, examples, docs) entirely instead of
+        /// downgrading their findings. Faster, but malware hidden in a
+        /// dev-only path won't be reported at all.
+        #[arg(long)]
+        skip_dev_only: bool,
+
         /// Scan all files at full severity (disable scope-based severity capping)
         #[arg(long)]
         include_dev: bool,
@@ -218,6 +533,20 @@ pub enum Commands {
         output: PathBuf,
     },
 
+    /// Explain a rule or a specific finding: full description, why the
+    /// technique is dangerous, CWE/OWASP/ATT&CK mappings, references,
+    /// example malicious/benign code, and remediation guidance
+    Explain {
+        /// A rule ID (e.g. "INJECT-001"), or a finding fingerprint from a
+        /// `-f json` scan report (requires --report)
+        id: String,
+
+        /// Scan report to resolve a finding fingerprint against (only
+        /// needed when `id` is a fingerprint rather than a rule ID)
+        #[arg(long)]
+        report: Option<PathBuf>,
+    },
+
     /// Vet and install a plugin/skill (scan first, install if clean)
     Install {
         /// GitHub URL or local path to install
@@ -271,14 +600,29 @@ pub enum Commands {
         #[arg(long)]
         no_cache: bool,
 
-        /// Only scan installed/published files (skip tests, examples, docs)
+        /// Downgrade findings in dev-only files (tests, examples, docs) to
+        /// Low severity and confidence instead of reporting them at full
+        /// strength. Unlike `--skip-dev-only`, these files are still scanned
+        /// and their findings still appear in the report, just quieted down.
         #[arg(long)]
         installed_only: bool,
 
+        /// Skip dev-only files (tests, examples, docs) entirely instead of
+        /// downgrading their findings. Faster, but malware hidden in a
+        /// dev-only path won't be reported at all.
+        #[arg(long)]
+        skip_dev_only: bool,
+
         /// Scan all files at full severity (disable scope-based severity capping)
         #[arg(long)]
         include_dev: bool,
 
+        /// Mask secret-like substrings (API keys, tokens) in finding
+        /// snippets so the report itself doesn't leak credentials when
+        /// shared or archived (e.g. in CI logs)
+        #[arg(long)]
+        redact_snippets: bool,
+
         /// Max parallel threads (default: half of available CPUs, 0 = all CPUs)
         #[arg(short = 'j', long, value_name = "N")]
         jobs: Option<usize>,
@@ -304,6 +648,10 @@ pub enum Commands {
         #[arg(long, default_value = "high")]
         fail_on: String,
 
+        /// Minimum confidence to report (low, medium, high)
+        #[arg(long, default_value = "low")]
+        min_confidence: String,
+
         /// Skip dependencies (node_modules, etc.)
         #[arg(long)]
         skip_deps: bool,
@@ -332,14 +680,40 @@ pub enum Commands {
         #[arg(long)]
         no_cache: bool,
 
-        /// Only scan installed/published files (skip tests, examples, docs)
+        /// Downgrade findings in dev-only files (tests, examples, docs) to
+        /// Low severity and confidence instead of reporting them at full
+        /// strength. Unlike `--skip-dev-only`, these files are still scanned
+        /// and their findings still appear in the report, just quieted down.
         #[arg(long)]
         installed_only: bool,
 
+        /// Skip dev-only files (tests, examples, docs) entirely instead of
+        /// downgrading their findings. Faster, but malware hidden in a
+        /// dev-only path won't be reported at all.
+        #[arg(long)]
+        skip_dev_only: bool,
+
         /// Scan all files at full severity (disable scope-based severity capping)
         #[arg(long)]
         include_dev: bool,
 
+        /// Show a MITRE ATT&CK/ATLAS technique coverage matrix in the report
+        #[arg(long)]
+        attack_matrix: bool,
+
+        /// How to aggregate the CLI text report's detailed findings:
+        /// "file" (default, one section per file/component), "rule"
+        /// (collapse repeats of the same rule into one section with a
+        /// count), or "severity" (one section per severity level)
+        #[arg(long, default_value = "file")]
+        group_by: String,
+
+        /// Mask secret-like substrings (API keys, tokens) in finding
+        /// snippets so the report itself doesn't leak credentials when
+        /// shared or archived (e.g. in CI logs)
+        #[arg(long)]
+        redact_snippets: bool,
+
         /// Max parallel threads (default: half of available CPUs, 0 = all CPUs)
         #[arg(short = 'j', long, value_name = "N")]
         jobs: Option<usize>,
@@ -350,4 +724,119 @@ pub enum Commands {
         #[command(subcommand)]
         subcommand: CacheSubcommand,
     },
+
+    /// Compare two JSON scan reports (`-f json`) and show new, fixed, and
+    /// persisting findings
+    Compare {
+        /// Path to the older JSON scan report
+        old: PathBuf,
+
+        /// Path to the newer JSON scan report
+        new: PathBuf,
+
+        /// Output the diff as JSON instead of a human-readable summary
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Scan a path and apply safe automated fixes for remediable findings
+    /// (e.g. stripping hidden HTML-comment instructions). Equivalent to
+    /// `scan --fix`/`scan --fix-dry-run`, without the full report output.
+    Fix {
+        /// Path to scan and fix
+        path: PathBuf,
+
+        /// Platform to scan (auto-detect if not specified)
+        #[arg(short, long)]
+        platform: Option<String>,
+
+        /// Enable AST-based analysis for obfuscation detection
+        #[arg(long)]
+        ast: bool,
+
+        /// Enable dependency scanning (check package.json for malicious packages)
+        #[arg(long)]
+        deps: bool,
+
+        /// Skip dependencies (node_modules, etc.) during scan
+        #[arg(long)]
+        skip_deps: bool,
+
+        /// Preview the fixes that would be applied, without modifying any files
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Create or apply a baseline of previously-accepted findings
+    Baseline {
+        #[command(subcommand)]
+        subcommand: BaselineSubcommand,
+    },
+
+    /// Interactively walk through findings one at a time, accepting,
+    /// suppressing, or fixing each. Suppress decisions are written to the
+    /// config allowlist (or a baseline file, with `--baseline`) as they're
+    /// made, so an interrupted review doesn't lose progress.
+    Review {
+        /// Path to scan and review
+        path: PathBuf,
+
+        /// Platform to scan (auto-detect if not specified)
+        #[arg(short, long)]
+        platform: Option<String>,
+
+        /// Enable AST-based analysis for obfuscation detection
+        #[arg(long)]
+        ast: bool,
+
+        /// Enable dependency scanning (check package.json for malicious packages)
+        #[arg(long)]
+        deps: bool,
+
+        /// Skip dependencies (node_modules, etc.) during scan
+        #[arg(long)]
+        skip_deps: bool,
+
+        /// Write suppress decisions to this baseline file instead of the
+        /// config allowlist
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+    },
+
+    /// Inspect locally recorded scan history and trends. Every `vexscan scan`
+    /// appends a summary (counts per severity/rule, timestamp, target) to a
+    /// local log, so trends across scans can be tracked over time.
+    History {
+        #[command(subcommand)]
+        subcommand: HistorySubcommand,
+    },
+
+    /// Run a long-lived HTTP server exposing the scanner as a REST API
+    /// (`GET /health`, `GET /rules`, `POST /scan`), so other tooling can
+    /// query it without spawning a process per request.
+    Serve {
+        /// Address and port to listen on
+        #[arg(short, long, default_value = "127.0.0.1:8787")]
+        addr: String,
+    },
+
+    /// Manage git hook integration
+    Hook {
+        #[command(subcommand)]
+        subcommand: HookSubcommand,
+    },
+
+    /// Discover MCP server configurations (settings.json, .claude.json,
+    /// .mcp.json, and similar) and audit each server entry — command,
+    /// args, env, and transport — against the MCP-* rules with a
+    /// structured per-server verdict
+    AuditMcp {
+        /// Path to scan for MCP configuration files
+        #[arg(default_value = ".")]
+        path: PathBuf,
+
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
 }
@@ -0,0 +1,107 @@
+//! Diffs two scan reports (see `-f json`) to surface new, fixed, and
+//! persisting findings between runs — e.g. before/after an agent config
+//! change, or across CI runs on the same repo.
+
+use crate::types::{Finding, ScanReport};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// The stable key used to match a finding across two reports: its file
+/// path relative to the scan root (so the same tree scanned from a
+/// different absolute path still matches) plus its content fingerprint.
+type FindingKey = (PathBuf, u64);
+
+fn finding_key(report: &ScanReport, path: &Path, finding: &Finding) -> FindingKey {
+    let rel = path.strip_prefix(&report.scan_root).unwrap_or(path);
+    (rel.to_path_buf(), finding.fingerprint())
+}
+
+/// Result of comparing two scan reports.
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct ReportDiff {
+    /// Findings present in `new` but not `old`.
+    pub new_findings: Vec<Finding>,
+    /// Findings present in `old` but not `new` (resolved since the last scan).
+    pub fixed_findings: Vec<Finding>,
+    /// Findings present in both reports, unchanged.
+    pub persisting_findings: Vec<Finding>,
+}
+
+/// Diff `old` against `new`, keyed by (relative file path, finding
+/// fingerprint).
+pub fn diff_reports(old: &ScanReport, new: &ScanReport) -> ReportDiff {
+    let mut old_by_key: HashMap<FindingKey, Finding> = HashMap::new();
+    for result in &old.results {
+        for finding in &result.findings {
+            old_by_key.insert(finding_key(old, &result.path, finding), finding.clone());
+        }
+    }
+
+    let mut diff = ReportDiff::default();
+    for result in &new.results {
+        for finding in &result.findings {
+            let key = finding_key(new, &result.path, finding);
+            if old_by_key.remove(&key).is_some() {
+                diff.persisting_findings.push(finding.clone());
+            } else {
+                diff.new_findings.push(finding.clone());
+            }
+        }
+    }
+
+    diff.fixed_findings = old_by_key.into_values().collect();
+    diff
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{FindingCategory, Location, ScanResult, Severity};
+
+    fn finding(rule_id: &str, line: usize) -> Finding {
+        Finding::new(
+            rule_id,
+            "Test finding",
+            "A test finding.",
+            Severity::High,
+            FindingCategory::CodeExecution,
+            Location::new(PathBuf::from("/repo/a.js"), line, line),
+            "eval(x)",
+        )
+    }
+
+    fn report_with(findings: Vec<Finding>) -> ScanReport {
+        let mut result = ScanResult::new(PathBuf::from("/repo/a.js"));
+        result.findings = findings;
+        let mut report = ScanReport::new(PathBuf::from("/repo"));
+        report.results.push(result);
+        report
+    }
+
+    #[test]
+    fn test_diff_classifies_new_fixed_persisting() {
+        let old = report_with(vec![finding("EXEC-001", 1), finding("EXEC-002", 5)]);
+        let new = report_with(vec![finding("EXEC-001", 1), finding("EXEC-003", 9)]);
+
+        let diff = diff_reports(&old, &new);
+        assert_eq!(diff.persisting_findings.len(), 1);
+        assert_eq!(diff.persisting_findings[0].rule_id, "EXEC-001");
+        assert_eq!(diff.new_findings.len(), 1);
+        assert_eq!(diff.new_findings[0].rule_id, "EXEC-003");
+        assert_eq!(diff.fixed_findings.len(), 1);
+        assert_eq!(diff.fixed_findings[0].rule_id, "EXEC-002");
+    }
+
+    #[test]
+    fn test_diff_matches_across_different_scan_roots() {
+        let old = report_with(vec![finding("EXEC-001", 1)]);
+        let mut new = report_with(vec![finding("EXEC-001", 1)]);
+        new.scan_root = PathBuf::from("/other/repo");
+        new.results[0].path = PathBuf::from("/other/repo/a.js");
+
+        let diff = diff_reports(&old, &new);
+        assert_eq!(diff.persisting_findings.len(), 1);
+        assert!(diff.new_findings.is_empty());
+        assert!(diff.fixed_findings.is_empty());
+    }
+}
@@ -0,0 +1,105 @@
+//! CWE, OWASP LLM Top 10, and MITRE ATT&CK/ATLAS mappings for findings.
+//!
+//! Rules can specify explicit `cwe`/`owasp_llm`/`attack_technique` IDs (see
+//! `Rule` and `AstRuleEntry`), but most rules don't bother — the mapping is
+//! largely determined by `FindingCategory` anyway. These functions
+//! supply that default so every finding ends up tagged for
+//! compliance-oriented consumers even when the rule author didn't
+//! set anything explicitly.
+
+use crate::types::FindingCategory;
+
+/// Default CWE ID(s) for a finding category, used when a rule doesn't
+/// specify its own.
+pub fn default_cwe(category: &FindingCategory) -> Vec<String> {
+    match category {
+        FindingCategory::CodeExecution => vec!["CWE-94".to_string()],
+        FindingCategory::ShellExecution => vec!["CWE-78".to_string()],
+        FindingCategory::SensitiveFileAccess => vec!["CWE-552".to_string()],
+        FindingCategory::DataExfiltration => vec!["CWE-200".to_string()],
+        FindingCategory::Obfuscation => vec!["CWE-506".to_string()],
+        FindingCategory::PromptInjection => vec!["CWE-1427".to_string()],
+        FindingCategory::AuthorityImpersonation => vec!["CWE-290".to_string()],
+        FindingCategory::CredentialAccess => vec!["CWE-798".to_string()],
+        FindingCategory::PrivilegeEscalation => vec!["CWE-269".to_string()],
+        FindingCategory::SuspiciousDependency => vec!["CWE-1357".to_string()],
+        FindingCategory::HiddenInstructions => vec!["CWE-506".to_string()],
+        FindingCategory::Other(_) => vec![],
+    }
+}
+
+/// Default OWASP Top 10 for LLM Applications category for a finding
+/// category, used when a rule doesn't specify its own.
+pub fn default_owasp_llm(category: &FindingCategory) -> Vec<String> {
+    match category {
+        FindingCategory::PromptInjection => vec!["LLM01:2025".to_string()],
+        FindingCategory::AuthorityImpersonation => vec!["LLM01:2025".to_string()],
+        FindingCategory::HiddenInstructions => vec!["LLM01:2025".to_string()],
+        FindingCategory::DataExfiltration => vec!["LLM02:2025".to_string()],
+        FindingCategory::CredentialAccess => vec!["LLM02:2025".to_string()],
+        FindingCategory::SensitiveFileAccess => vec!["LLM02:2025".to_string()],
+        FindingCategory::SuspiciousDependency => vec!["LLM03:2025".to_string()],
+        FindingCategory::CodeExecution => vec!["LLM05:2025".to_string()],
+        FindingCategory::ShellExecution => vec!["LLM05:2025".to_string()],
+        FindingCategory::Obfuscation => vec!["LLM05:2025".to_string()],
+        FindingCategory::PrivilegeEscalation => vec!["LLM06:2025".to_string()],
+        FindingCategory::Other(_) => vec![],
+    }
+}
+
+/// Default MITRE ATT&CK/ATLAS technique ID(s) for a finding category, used
+/// when a rule doesn't specify its own. Categories rooted in LLM/agent
+/// behavior map to ATLAS technique IDs (`AML.T...`); the rest map to
+/// regular ATT&CK Enterprise technique IDs.
+pub fn default_attack_technique(category: &FindingCategory) -> Vec<String> {
+    match category {
+        FindingCategory::CodeExecution => vec!["T1059".to_string()],
+        FindingCategory::ShellExecution => vec!["T1059.004".to_string()],
+        FindingCategory::SensitiveFileAccess => vec!["T1005".to_string()],
+        FindingCategory::DataExfiltration => vec!["T1041".to_string()],
+        FindingCategory::Obfuscation => vec!["T1027".to_string()],
+        FindingCategory::PromptInjection => vec!["AML.T0051".to_string()],
+        FindingCategory::AuthorityImpersonation => vec!["AML.T0051".to_string()],
+        FindingCategory::HiddenInstructions => vec!["AML.T0051".to_string()],
+        FindingCategory::CredentialAccess => vec!["T1552".to_string()],
+        FindingCategory::PrivilegeEscalation => vec!["T1548".to_string()],
+        FindingCategory::SuspiciousDependency => vec!["AML.T0010".to_string()],
+        FindingCategory::Other(_) => vec![],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_cwe_known_category() {
+        assert_eq!(
+            default_cwe(&FindingCategory::ShellExecution),
+            vec!["CWE-78".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_default_owasp_llm_known_category() {
+        assert_eq!(
+            default_owasp_llm(&FindingCategory::PromptInjection),
+            vec!["LLM01:2025".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_other_category_has_no_defaults() {
+        assert!(default_cwe(&FindingCategory::Other("custom".to_string())).is_empty());
+        assert!(default_owasp_llm(&FindingCategory::Other("custom".to_string())).is_empty());
+        assert!(default_attack_technique(&FindingCategory::Other("custom".to_string())).is_empty());
+    }
+
+    #[test]
+    fn test_default_attack_technique_known_category() {
+        assert_eq!(
+            default_attack_technique(&FindingCategory::PromptInjection),
+            vec!["AML.T0051".to_string()]
+        );
+    }
+}
@@ -1,9 +1,23 @@
-//! Configuration for the scanner, including allowlists and trusted packages.
+//! Configuration for the scanner, including allowlists, trusted packages,
+//! and per-finding suppressions (see `SuppressionRule`).
 
+use crate::rules::RuleSet;
 use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
 use std::sync::OnceLock;
 
+/// The user's home directory, if known. On non-`native` builds (e.g.
+/// wasm32, where there's no OS-level home directory) this is always `None`.
+#[cfg(feature = "native")]
+fn home_dir() -> Option<PathBuf> {
+    dirs::home_dir()
+}
+
+#[cfg(not(feature = "native"))]
+fn home_dir() -> Option<PathBuf> {
+    None
+}
+
 /// Extensions that are executable and should NEVER be skipped, regardless of filename.
 const EXECUTABLE_EXTENSIONS: &[&str] = &[
     "js", "mjs", "cjs", "ts", "tsx", "jsx", // JavaScript/TypeScript
@@ -34,6 +48,19 @@ pub struct Config {
     #[serde(default)]
     pub skip_paths: Vec<String>,
 
+    /// If non-empty, only scan paths matching at least one of these glob
+    /// patterns (e.g. `["**/*.md", "**/settings.json"]`), instead of the
+    /// platform's full discovery set. Checked before `exclude`.
+    #[serde(default)]
+    pub include: Vec<String>,
+
+    /// Additional glob patterns to exclude from scanning, checked the same
+    /// way as `include` rather than `skip_paths` — unlike `skip_paths`,
+    /// this is a precise scoping decision made by the caller, so it also
+    /// excludes files with executable extensions.
+    #[serde(default)]
+    pub exclude: Vec<String>,
+
     /// Trusted npm packages (won't be scanned).
     #[serde(default)]
     pub trusted_packages: Vec<String>,
@@ -54,6 +81,11 @@ pub struct Config {
     #[serde(default)]
     pub disabled_rules: Vec<String>,
 
+    /// Findings to suppress (kept for audit) rather than disabling their
+    /// rule outright. See `SuppressionRule`.
+    #[serde(default)]
+    pub suppressions: Vec<SuppressionRule>,
+
     /// Only scan third-party/unknown sources (skip official and trusted).
     #[serde(default)]
     pub third_party_only: bool,
@@ -63,20 +95,95 @@ pub struct Config {
     #[serde(default)]
     pub extra_rules_dirs: Vec<PathBuf>,
 
+    /// Risk-score exposure multipliers by component type ("plugin",
+    /// "config", "hook", "prompt", "mcp-server", "memory", "other").
+    /// Types not listed here fall back to a sensible built-in default
+    /// (see `scoring::ComponentTypeWeights`). Use this to tune how much a
+    /// finding in a hook or MCP server config should outweigh the same
+    /// finding in documentation.
+    #[serde(default)]
+    pub component_type_weights: std::collections::HashMap<String, f64>,
+
+    /// Language code for finding titles/descriptions/remediations (e.g.
+    /// "es", "ja"). Overridable with `--lang`. Rules with no translation
+    /// for this language fall back to English.
+    #[serde(default = "default_lang")]
+    pub lang: String,
+
+    /// Maps a severity name ("critical", "high", "medium", "low", "info")
+    /// to the process exit code `--fail-on` should use when the scan's
+    /// highest severity reaches or exceeds it. Severities not listed here
+    /// fall back to exit code 1, so CI pipelines and wrappers can
+    /// distinguish e.g. "critical found" from "just high" without parsing
+    /// the report.
+    #[serde(default)]
+    pub exit_codes: std::collections::HashMap<String, i32>,
+
     /// Pre-compiled glob patterns for skip_paths (lazily initialized).
     #[serde(skip)]
     compiled_skip_globs: OnceLock<globset::GlobSet>,
+
+    /// Pre-compiled glob patterns for `include` (lazily initialized).
+    #[serde(skip)]
+    compiled_include_globs: OnceLock<globset::GlobSet>,
+
+    /// Pre-compiled glob patterns for `exclude` (lazily initialized).
+    #[serde(skip)]
+    compiled_exclude_globs: OnceLock<globset::GlobSet>,
+}
+
+fn default_lang() -> String {
+    "en".to_string()
 }
 
 fn default_entropy_threshold() -> f64 {
     5.5
 }
 
+/// A config-level allowlist entry: accepts the risk of a specific rule
+/// (optionally scoped to a path glob) with a recorded reason and owner,
+/// instead of disabling the rule for everyone.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SuppressionRule {
+    /// Rule ID to suppress (e.g. "HIDDEN-002").
+    pub rule_id: String,
+    /// Only suppress findings in files matching this glob, if given.
+    #[serde(default)]
+    pub path_glob: Option<String>,
+    /// Why this finding is accepted as risk.
+    #[serde(default)]
+    pub reason: Option<String>,
+    /// Who accepted the risk.
+    #[serde(default)]
+    pub by: Option<String>,
+}
+
+impl SuppressionRule {
+    /// Whether this entry covers the given rule ID and path.
+    pub fn matches(&self, rule_id: &str, path: &Path) -> bool {
+        if self.rule_id != rule_id {
+            return false;
+        }
+        self.matches_path(path)
+    }
+
+    /// Whether this entry's path glob (if any) covers `path`.
+    fn matches_path(&self, path: &Path) -> bool {
+        match &self.path_glob {
+            Some(glob) => globset::Glob::new(glob)
+                .map(|g| g.compile_matcher().is_match(path))
+                .unwrap_or(false),
+            None => true,
+        }
+    }
+}
+
 impl Config {
     /// Load config from a TOML file.
-    pub fn load(path: &Path) -> anyhow::Result<Self> {
+    pub fn load(path: &Path) -> Result<Self, crate::error::VexscanError> {
         let content = std::fs::read_to_string(path)?;
-        let config: Config = toml::from_str(&content)?;
+        let config: Config =
+            toml::from_str(&content).map_err(|e| crate::error::VexscanError::config(path, e))?;
         Ok(config)
     }
 
@@ -88,7 +195,7 @@ impl Config {
         }
 
         // Try home directory
-        if let Some(home) = dirs::home_dir() {
+        if let Some(home) = home_dir() {
             if let Ok(config) = Self::load(&home.join(".vexscan.toml")) {
                 return config;
             }
@@ -143,6 +250,8 @@ impl Config {
                 "**/NEWS.md".to_string(),
                 "**/NEWS.txt".to_string(),
             ],
+            include: vec![],
+            exclude: vec![],
             trusted_packages: vec![
                 // Validation libraries (use atob/base64 legitimately)
                 "zod".to_string(),
@@ -172,15 +281,21 @@ impl Config {
             skip_python_cache: true,
             entropy_threshold: 5.5,
             disabled_rules: vec![],
+            suppressions: vec![],
             third_party_only: false,
             extra_rules_dirs: Self::default_extra_rules_dirs(),
+            component_type_weights: std::collections::HashMap::new(),
+            lang: default_lang(),
+            exit_codes: std::collections::HashMap::new(),
             compiled_skip_globs: OnceLock::new(),
+            compiled_include_globs: OnceLock::new(),
+            compiled_exclude_globs: OnceLock::new(),
         }
     }
 
     /// Resolve default extra rules directories (convention: ~/.vexscan/rules/).
     fn default_extra_rules_dirs() -> Vec<PathBuf> {
-        if let Some(home) = dirs::home_dir() {
+        if let Some(home) = home_dir() {
             let dir = home.join(".vexscan").join("rules");
             if dir.is_dir() {
                 return vec![dir];
@@ -191,7 +306,7 @@ impl Config {
 
     /// Resolve ~ prefixes in extra_rules_dirs paths.
     pub fn resolved_extra_rules_dirs(&self) -> Vec<PathBuf> {
-        let home = dirs::home_dir();
+        let home = home_dir();
         let mut dirs = self
             .extra_rules_dirs
             .iter()
@@ -232,6 +347,47 @@ impl Config {
         })
     }
 
+    /// Get the pre-compiled GlobSet for `include`, compiling on first access.
+    fn include_glob_set(&self) -> &globset::GlobSet {
+        Self::compiled_glob_set(&self.compiled_include_globs, &self.include)
+    }
+
+    /// Get the pre-compiled GlobSet for `exclude`, compiling on first access.
+    fn exclude_glob_set(&self) -> &globset::GlobSet {
+        Self::compiled_glob_set(&self.compiled_exclude_globs, &self.exclude)
+    }
+
+    /// Compile `patterns` into a `GlobSet`, caching the result in `cell`.
+    fn compiled_glob_set<'a>(
+        cell: &'a OnceLock<globset::GlobSet>,
+        patterns: &[String],
+    ) -> &'a globset::GlobSet {
+        cell.get_or_init(|| {
+            let mut builder = globset::GlobSetBuilder::new();
+            for pattern in patterns {
+                if let Ok(glob) = globset::Glob::new(pattern) {
+                    builder.add(glob);
+                }
+            }
+            builder
+                .build()
+                .unwrap_or_else(|_| globset::GlobSetBuilder::new().build().unwrap())
+        })
+    }
+
+    /// Check whether a path is within the user-configured `include`/`exclude`
+    /// scope. Unlike `should_skip_path` (a trust-based allowlist), this is an
+    /// explicit scoping decision, so it applies even to executable files.
+    pub fn is_in_scope(&self, path: &Path) -> bool {
+        if !self.include.is_empty() && !self.include_glob_set().is_match(path) {
+            return false;
+        }
+        if self.exclude_glob_set().is_match(path) {
+            return false;
+        }
+        true
+    }
+
     /// Check if a path should be skipped.
     ///
     /// SECURITY: Files with executable extensions are only skipped in TRUSTED contexts
@@ -346,6 +502,38 @@ impl Config {
         self.disabled_rules.iter().any(|r| r == rule_id)
     }
 
+    /// Find the first allowlist entry (if any) that suppresses this finding.
+    /// `rules` resolves deprecated rule IDs so an entry written against a
+    /// rule's old ID keeps matching after it's renamed (see
+    /// `RuleSet::canonical_rule_id`).
+    pub fn matching_suppression(
+        &self,
+        rule_id: &str,
+        path: &Path,
+        rules: &RuleSet,
+    ) -> Option<&SuppressionRule> {
+        let canonical_finding_id = rules.canonical_rule_id(rule_id);
+        self.suppressions.iter().find(|s| {
+            rules.canonical_rule_id(&s.rule_id) == canonical_finding_id && s.matches_path(path)
+        })
+    }
+
+    /// Build the risk-score component-type weighting table from
+    /// `component_type_weights`, filling in built-in defaults for any
+    /// type left unspecified.
+    pub fn component_type_weights(&self) -> crate::scoring::ComponentTypeWeights {
+        crate::scoring::ComponentTypeWeights::from_overrides(&self.component_type_weights)
+    }
+
+    /// The process exit code to use when a scan's highest severity reaches
+    /// `severity`, per `exit_codes`. Falls back to 1 if unconfigured.
+    pub fn exit_code_for(&self, severity: crate::types::Severity) -> i32 {
+        self.exit_codes
+            .get(&severity.to_string())
+            .copied()
+            .unwrap_or(1)
+    }
+
     /// Check if a path is from a trusted/official source.
     /// Used with --third-party-only to skip these and only scan unknown plugins.
     pub fn is_trusted_source(&self, path: &Path) -> bool {
@@ -439,6 +627,16 @@ skip_paths = [
     "**/CHANGES.md",
 ]
 
+# If non-empty, only scan paths matching at least one of these glob
+# patterns instead of the platform's full discovery set. Checked before
+# `exclude`. Unlike `skip_paths`, this also excludes executable files -
+# it's an explicit scoping decision, not a trust-based allowlist.
+# include = ["**/*.md", "**/settings.json"]
+
+# Additional glob patterns to exclude from scanning, checked the same way
+# as `include`.
+# exclude = ["**/vendor/**"]
+
 # Trusted npm packages - these won't be scanned
 # Add packages you trust and don't want flagged
 trusted_packages = [
@@ -478,10 +676,41 @@ disabled_rules = [
     # "ENTROPY-001",  # Uncomment to disable entropy checks
 ]
 
+# Accept the risk of specific findings instead of disabling the rule
+# outright. Suppressed findings are kept in a dedicated report section
+# with the reason/owner below, rather than silently dropped.
+# [[suppressions]]
+# rule_id = "HIDDEN-002"
+# path_glob = "**/docs/**"
+# reason = "Example HTML comment in documentation, not executed"
+# by = "alice"
+
 # Additional directories to load rules from at runtime.
 # ~/.vexscan/rules/ is always checked automatically (zero-config).
 # Add extra directories here for organization-specific rules.
 # extra_rules_dirs = ["/path/to/custom/rules"]
+
+# Risk-score exposure multipliers by component type. A finding in a hook
+# or MCP server config runs automatically, so it's weighted higher by
+# default than the same finding in a prompt or memory file. Override any
+# of "plugin", "config", "hook", "prompt", "mcp-server", "memory", "other"
+# here; unlisted types keep their built-in default weight.
+# [component_type_weights]
+# hook = 1.5
+# mcp-server = 1.5
+
+# Language for finding titles/descriptions/remediations (e.g. "es", "ja").
+# Overridable with --lang. Rules with no translation for the selected
+# language fall back to English. Default: "en".
+# lang = "en"
+
+# Map severities to distinct process exit codes for `scan`/`vet`'s
+# --fail-on check, so CI pipelines can branch on the exact outcome without
+# parsing the report. Severities not listed here exit with code 1.
+# [exit_codes]
+# critical = 3
+# high = 2
+# medium = 1
 "#
     .to_string()
 }
@@ -501,6 +730,41 @@ mod tests {
         assert!(!config.should_skip_path(Path::new("/project/node_modules/suspicious-pkg/evil.js")));
     }
 
+    #[test]
+    fn test_is_in_scope_no_patterns_scans_everything() {
+        let config = Config::with_defaults();
+        assert!(config.is_in_scope(Path::new("/project/anything.js")));
+    }
+
+    #[test]
+    fn test_is_in_scope_include_restricts_to_matching_paths() {
+        let mut config = Config::with_defaults();
+        config.include = vec!["**/*.md".to_string(), "**/settings.json".to_string()];
+
+        assert!(config.is_in_scope(Path::new("/project/README.md")));
+        assert!(config.is_in_scope(Path::new("/project/.claude/settings.json")));
+        assert!(!config.is_in_scope(Path::new("/project/index.js")));
+    }
+
+    #[test]
+    fn test_is_in_scope_exclude_removes_matching_paths() {
+        let mut config = Config::with_defaults();
+        config.exclude = vec!["**/vendor/**".to_string()];
+
+        assert!(!config.is_in_scope(Path::new("/project/vendor/lib.js")));
+        assert!(config.is_in_scope(Path::new("/project/src/lib.js")));
+    }
+
+    #[test]
+    fn test_is_in_scope_exclude_applies_even_to_executables() {
+        // Unlike should_skip_path, is_in_scope is an explicit user scoping
+        // decision, so it isn't restricted to non-executable files.
+        let mut config = Config::with_defaults();
+        config.exclude = vec!["**/vendor/**".to_string()];
+
+        assert!(!config.is_in_scope(Path::new("/project/vendor/evil.js")));
+    }
+
     #[test]
     fn test_skip_node_modules() {
         let mut config = Config::with_defaults();
@@ -0,0 +1,152 @@
+//! Correlate related findings into composite attack chains.
+//!
+//! A single malicious file often trips several independent rules that,
+//! together, tell a stronger story than any one alone — e.g. a base64 blob
+//! (Obfuscation) that decodes into an `eval()` call (CodeExecution) which
+//! then posts data to a remote host (DataExfiltration). Reporting these as
+//! three disconnected findings understates the risk and buries the story;
+//! this pass links them into one composite Critical finding, referencing
+//! the originals as children instead of replacing them (so the individual
+//! rule hits stay in the report for audit).
+
+use crate::types::{Confidence, Finding, FindingCategory, Severity};
+
+/// A known multi-stage attack pattern: if a file has at least one finding
+/// in every one of `stages` (in any order), they're correlated into one
+/// composite finding.
+struct Chain {
+    id: &'static str,
+    title: &'static str,
+    description: &'static str,
+    stages: &'static [FindingCategory],
+}
+
+fn known_chains() -> &'static [Chain] {
+    &[Chain {
+        id: "CHAIN-001",
+        title: "Obfuscated payload decodes into code that exfiltrates data",
+        description: "This file contains an encoded/obfuscated blob, a code-execution \
+                       pattern, and a network exfiltration pattern together. Individually \
+                       each may be innocuous, but combined they match the shape of a \
+                       staged attack: hide a payload, decode and run it, then phone home \
+                       with the results.",
+        stages: &[
+            FindingCategory::Obfuscation,
+            FindingCategory::CodeExecution,
+            FindingCategory::DataExfiltration,
+        ],
+    }]
+}
+
+/// Scan a single file's findings for known attack chains and, for every
+/// chain fully present, append one composite Critical finding tagging the
+/// contributing findings as its children via shared `chain_id` metadata.
+pub fn correlate(findings: &mut Vec<Finding>) {
+    let mut composites = Vec::new();
+
+    for chain in known_chains() {
+        let member_indices: Vec<usize> = chain
+            .stages
+            .iter()
+            .filter_map(|stage| findings.iter().position(|f| &f.category == stage))
+            .collect();
+
+        if member_indices.len() != chain.stages.len() {
+            continue;
+        }
+
+        let chain_id = chain.id.to_string();
+        let mut members = Vec::new();
+        for &idx in &member_indices {
+            let member = &mut findings[idx];
+            member
+                .metadata
+                .insert("chain_id".to_string(), chain_id.clone());
+            member
+                .metadata
+                .insert("chain_role".to_string(), "child".to_string());
+            members.push(format!("{}:{}", member.rule_id, member.location.start_line));
+        }
+
+        let anchor = &findings[member_indices[0]];
+        let composite = Finding::new(
+            chain.id,
+            chain.title,
+            chain.description,
+            Severity::Critical,
+            anchor.category.clone(),
+            anchor.location.clone(),
+            format!("Correlated chain: {}", members.join(" -> ")),
+        )
+        .with_confidence(Confidence::High)
+        .with_metadata("chain_id", chain_id)
+        .with_metadata("chain_role", "composite")
+        .with_metadata("chain_members", members.join(", "));
+
+        composites.push(composite);
+    }
+
+    findings.extend(composites);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Finding, FindingCategory, Location, Severity};
+    use std::path::PathBuf;
+
+    fn finding(category: FindingCategory, rule_id: &str) -> Finding {
+        Finding::new(
+            rule_id,
+            "Test finding",
+            "A test finding",
+            Severity::Medium,
+            category,
+            Location::new(PathBuf::from("test.js"), 1, 1),
+            "snippet",
+        )
+    }
+
+    #[test]
+    fn test_full_chain_produces_composite() {
+        let mut findings = vec![
+            finding(FindingCategory::Obfuscation, "OBFUS-001"),
+            finding(FindingCategory::CodeExecution, "EXEC-001"),
+            finding(FindingCategory::DataExfiltration, "EXFIL-001"),
+        ];
+
+        correlate(&mut findings);
+
+        assert_eq!(findings.len(), 4);
+        let composite = findings.last().unwrap();
+        assert_eq!(composite.rule_id, "CHAIN-001");
+        assert_eq!(composite.severity, Severity::Critical);
+        assert!(findings[..3]
+            .iter()
+            .all(|f| f.metadata.get("chain_role").map(String::as_str) == Some("child")));
+    }
+
+    #[test]
+    fn test_partial_chain_produces_no_composite() {
+        let mut findings = vec![
+            finding(FindingCategory::Obfuscation, "OBFUS-001"),
+            finding(FindingCategory::CodeExecution, "EXEC-001"),
+        ];
+
+        correlate(&mut findings);
+
+        assert_eq!(findings.len(), 2);
+    }
+
+    #[test]
+    fn test_unrelated_findings_produce_no_composite() {
+        let mut findings = vec![
+            finding(FindingCategory::CredentialAccess, "CRED-001"),
+            finding(FindingCategory::PrivilegeEscalation, "PRIV-001"),
+        ];
+
+        correlate(&mut findings);
+
+        assert_eq!(findings.len(), 2);
+    }
+}
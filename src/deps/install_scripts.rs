@@ -0,0 +1,150 @@
+//! Selective `node_modules` discovery targeting install scripts.
+//!
+//! Walking all of `node_modules` is expensive and mostly wasted: legitimate
+//! packages don't execute arbitrary code at install time. Real npm
+//! supply-chain attacks (event-stream, ua-parser-js, etc.) run their
+//! payload from a `preinstall`/`postinstall`/`prepare` script declared in
+//! `package.json`. This module finds exactly those packages and any local
+//! file their script appears to reference, so a scan can cover a fraction
+//! of `node_modules` while still catching where the risk actually lives.
+
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+/// npm lifecycle scripts that run automatically on install and are the
+/// classic vector for supply-chain payloads.
+const INSTALL_SCRIPT_HOOKS: &[&str] = &["preinstall", "postinstall", "prepare"];
+
+/// Find every file worth scanning under `scan_root/node_modules`: each
+/// package's `package.json` that declares an install script, plus any
+/// local file that script appears to invoke. Returns an empty list if
+/// there's no `node_modules` directory.
+pub fn discover_install_script_files(scan_root: &Path) -> Vec<PathBuf> {
+    let node_modules = scan_root.join("node_modules");
+    if !node_modules.is_dir() {
+        return Vec::new();
+    }
+
+    let mut files = Vec::new();
+    for manifest_path in package_manifests(&node_modules) {
+        let Ok(content) = std::fs::read_to_string(&manifest_path) else {
+            continue;
+        };
+        let Ok(manifest) = serde_json::from_str::<serde_json::Value>(&content) else {
+            continue;
+        };
+        let Some(scripts) = manifest.get("scripts").and_then(|s| s.as_object()) else {
+            continue;
+        };
+        let install_commands: Vec<&str> = INSTALL_SCRIPT_HOOKS
+            .iter()
+            .filter_map(|hook| scripts.get(*hook).and_then(|v| v.as_str()))
+            .collect();
+        if install_commands.is_empty() {
+            continue;
+        }
+
+        files.push(manifest_path.clone());
+        let pkg_dir = manifest_path.parent().unwrap_or(&node_modules);
+        for command in install_commands {
+            files.extend(referenced_script_files(pkg_dir, command));
+        }
+    }
+    files
+}
+
+/// Every `package.json` under `node_modules`, at any nesting depth (nested
+/// dependencies have their own `node_modules` with their own manifests).
+fn package_manifests(node_modules: &Path) -> Vec<PathBuf> {
+    WalkDir::new(node_modules)
+        .follow_links(true)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_name() == "package.json" && e.path().is_file())
+        .map(|e| e.path().to_path_buf())
+        .collect()
+}
+
+/// Best-effort extraction of local files an install script command
+/// references (e.g. `node scripts/postinstall.js` or `./install.sh`).
+/// Only paths that actually exist under `pkg_dir` are returned.
+fn referenced_script_files(pkg_dir: &Path, command: &str) -> Vec<PathBuf> {
+    command
+        .split_whitespace()
+        .map(|token| token.trim_matches(['"', '\'']))
+        .filter_map(|token| {
+            let candidate = pkg_dir.join(token);
+            candidate.is_file().then_some(candidate)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn write(path: &Path, content: &str) {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).unwrap();
+        }
+        fs::write(path, content).unwrap();
+    }
+
+    #[test]
+    fn test_finds_package_with_postinstall_and_its_script() {
+        let tmp = TempDir::new().unwrap();
+        let root = tmp.path();
+        write(
+            &root.join("node_modules/evil-pkg/package.json"),
+            r#"{"name": "evil-pkg", "scripts": {"postinstall": "node scripts/setup.js"}}"#,
+        );
+        write(
+            &root.join("node_modules/evil-pkg/scripts/setup.js"),
+            "console.log('hi')",
+        );
+
+        let files = discover_install_script_files(root);
+        assert!(files.contains(&root.join("node_modules/evil-pkg/package.json")));
+        assert!(files.contains(&root.join("node_modules/evil-pkg/scripts/setup.js")));
+    }
+
+    #[test]
+    fn test_ignores_packages_without_install_scripts() {
+        let tmp = TempDir::new().unwrap();
+        let root = tmp.path();
+        write(
+            &root.join("node_modules/benign-pkg/package.json"),
+            r#"{"name": "benign-pkg", "scripts": {"test": "jest"}}"#,
+        );
+
+        assert!(discover_install_script_files(root).is_empty());
+    }
+
+    #[test]
+    fn test_no_node_modules_returns_empty() {
+        let tmp = TempDir::new().unwrap();
+        assert!(discover_install_script_files(tmp.path()).is_empty());
+    }
+
+    #[test]
+    fn test_finds_nested_dependency_install_scripts() {
+        let tmp = TempDir::new().unwrap();
+        let root = tmp.path();
+        write(
+            &root.join("node_modules/foo/node_modules/nested-evil/package.json"),
+            r#"{"name": "nested-evil", "scripts": {"preinstall": "sh install.sh"}}"#,
+        );
+        write(
+            &root.join("node_modules/foo/node_modules/nested-evil/install.sh"),
+            "#!/bin/sh\necho hi",
+        );
+
+        let files = discover_install_script_files(root);
+        assert!(
+            files.contains(&root.join("node_modules/foo/node_modules/nested-evil/package.json"))
+        );
+        assert!(files.contains(&root.join("node_modules/foo/node_modules/nested-evil/install.sh")));
+    }
+}
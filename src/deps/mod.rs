@@ -6,6 +6,7 @@
 //! - Suspicious install scripts
 //! - Deprecated packages with known vulnerabilities
 
+pub mod install_scripts;
 pub mod malicious;
 pub mod typosquat;
 
@@ -0,0 +1,57 @@
+//! Typed error for the library's core entry points.
+//!
+//! Most of vexscan's internals still use `anyhow::Result` — this crate is a
+//! CLI first and a library second, and most callers just want to log and
+//! bail. `VexscanError` exists for the entry points embedders actually need
+//! to branch on programmatically (e.g. "was this a bad config file, or did
+//! the AI backend time out?"). The [`VexscanError::Other`] variant bridges
+//! to the rest of the codebase's `anyhow::Error` so this can be adopted
+//! incrementally without a flag-day rewrite.
+
+use std::path::Path;
+
+/// Errors returned by vexscan's public library entry points.
+#[derive(Debug, thiserror::Error)]
+pub enum VexscanError {
+    /// A config file (`vexscan.toml`, baseline, extra rules dir, ...) was
+    /// missing, malformed, or otherwise unusable.
+    #[error("config error: {0}")]
+    Config(String),
+
+    /// An I/O operation failed.
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// A detection rule (built-in or user-supplied) failed to compile.
+    #[error("failed to compile rule {rule_id}: {source}")]
+    RuleCompile {
+        rule_id: String,
+        #[source]
+        source: regex::Error,
+    },
+
+    /// A platform adapter (Claude Code, Cursor, ...) failed to discover or
+    /// read components.
+    #[error("adapter error: {0}")]
+    Adapter(String),
+
+    /// The AI analyzer backend failed to complete a request.
+    #[error("AI analysis error: {0}")]
+    Ai(String),
+
+    /// An operation exceeded its allotted time.
+    #[error("{operation} timed out after {seconds}s")]
+    Timeout { operation: String, seconds: u64 },
+
+    /// Anything else, bridged from the rest of the codebase's
+    /// `anyhow::Result`-based internals.
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+impl VexscanError {
+    /// Build a [`VexscanError::Config`] naming the offending path.
+    pub fn config(path: &Path, reason: impl std::fmt::Display) -> Self {
+        Self::Config(format!("{}: {}", path.display(), reason))
+    }
+}
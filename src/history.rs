@@ -0,0 +1,135 @@
+//! Local scan history, so trends in findings over time can be inspected
+//! without re-scanning or wiring up an external dashboard.
+//!
+//! Each completed scan appends one record to a JSON-lines log under the OS
+//! cache directory (see `cache::default_cache_dir`, duplicated here rather
+//! than shared since the two stores are versioned independently).
+
+use crate::types::{ScanReport, Severity};
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::io::Write;
+use std::path::PathBuf;
+
+/// OS cache directory, falling back to `/tmp`. On non-`native` builds this
+/// always falls back to `/tmp`.
+#[cfg(feature = "native")]
+fn default_cache_dir() -> PathBuf {
+    dirs::cache_dir().unwrap_or_else(|| PathBuf::from("/tmp"))
+}
+
+#[cfg(not(feature = "native"))]
+fn default_cache_dir() -> PathBuf {
+    PathBuf::from("/tmp")
+}
+
+fn history_path() -> PathBuf {
+    default_cache_dir().join("vexscan").join("history.jsonl")
+}
+
+/// One recorded scan: what was scanned, when, and how many findings of each
+/// severity/rule it produced.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistoryRecord {
+    pub timestamp: i64,
+    pub target: String,
+    pub total_findings: usize,
+    pub by_severity: HashMap<Severity, usize>,
+    pub by_rule: HashMap<String, usize>,
+}
+
+impl HistoryRecord {
+    fn from_report(report: &ScanReport, target: &str) -> Self {
+        let mut by_rule: HashMap<String, usize> = HashMap::new();
+        for result in &report.results {
+            for finding in &result.findings {
+                *by_rule.entry(finding.rule_id.clone()).or_insert(0) += 1;
+            }
+        }
+
+        Self {
+            timestamp: chrono::Utc::now().timestamp(),
+            target: target.to_string(),
+            total_findings: report.total_findings(),
+            by_severity: report.findings_count_by_severity(),
+            by_rule,
+        }
+    }
+}
+
+/// Append a record of `report`'s findings to the history log. `target` is a
+/// human-readable label for what was scanned (a path or an `npm:` source).
+pub fn record_scan(report: &ScanReport, target: &str) -> Result<()> {
+    let record = HistoryRecord::from_report(report, target);
+    let path = history_path();
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    let line = serde_json::to_string(&record)?;
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)?;
+    writeln!(file, "{}", line)?;
+    Ok(())
+}
+
+/// Load every recorded history entry, oldest first. Malformed lines (e.g.
+/// from a future incompatible version) are skipped rather than failing the
+/// whole load.
+pub fn load_history() -> Result<Vec<HistoryRecord>> {
+    let path = history_path();
+    let Ok(content) = std::fs::read_to_string(&path) else {
+        return Ok(Vec::new());
+    };
+
+    Ok(content
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect())
+}
+
+/// Delete all recorded scan history. Returns the number of records removed.
+pub fn clear_history() -> Result<usize> {
+    let count = load_history()?.len();
+    let path = history_path();
+    if path.exists() {
+        std::fs::remove_file(&path)?;
+    }
+    Ok(count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{FindingCategory, Location, ScanResult, Severity};
+    use std::path::PathBuf;
+
+    fn report_with_finding(rule_id: &str, severity: Severity) -> ScanReport {
+        let mut result = ScanResult::new(PathBuf::from("test.js"));
+        result.findings.push(crate::types::Finding::new(
+            rule_id,
+            "Test finding",
+            "A test finding",
+            severity,
+            FindingCategory::CodeExecution,
+            Location::new(PathBuf::from("test.js"), 1, 1),
+            "eval(x)",
+        ));
+        let mut report = ScanReport::new(PathBuf::from("."));
+        report.results.push(result);
+        report
+    }
+
+    #[test]
+    fn record_from_report_counts_by_severity_and_rule() {
+        let report = report_with_finding("TEST-001", Severity::High);
+        let record = HistoryRecord::from_report(&report, "test-target");
+        assert_eq!(record.target, "test-target");
+        assert_eq!(record.total_findings, 1);
+        assert_eq!(record.by_severity.get(&Severity::High), Some(&1));
+        assert_eq!(record.by_rule.get("TEST-001"), Some(&1));
+    }
+}
@@ -24,32 +24,72 @@
 //!     Ok(())
 //! }
 //! ```
+//!
+//! Embedders that need finer control over rules, detectors, or progress
+//! feedback can use `ScannerBuilder` instead of assembling a `ScanConfig` by
+//! hand:
+//!
+//! ```no_run
+//! use vexscan::{ScannerBuilder, ScanEvent};
+//! use std::path::PathBuf;
+//!
+//! # #[tokio::main]
+//! # async fn main() -> anyhow::Result<()> {
+//! let scanner = ScannerBuilder::new()
+//!     .with_detectors(true)
+//!     .with_cache(true)
+//!     .on_event(|event| {
+//!         if let ScanEvent::FileScanned { path, findings } = event {
+//!             println!("{}: {} finding(s)", path.display(), findings);
+//!         }
+//!     })
+//!     .build()?;
+//! let report = scanner.scan_path(&PathBuf::from("./plugins")).await?;
+//! # Ok(())
+//! # }
+//! ```
 
 pub mod adapters;
 pub mod analyzers;
+pub mod binary;
 pub mod cache;
 pub mod cli;
+pub mod compare;
+pub mod compliance;
 pub mod components;
 pub mod config;
+pub mod correlation;
 pub mod decoders;
 pub mod deps;
 pub mod domains;
+pub mod error;
+pub mod fixer;
+pub mod history;
+pub mod mcp_audit;
+pub mod redaction;
 pub mod reporters;
+pub mod review;
 pub mod rules;
 pub mod scope;
+pub mod scoring;
+// HTTP API server (`vexscan serve`). Only available with the `native`
+// feature, since it needs a real network listener.
+#[cfg(feature = "native")]
+pub mod server;
+pub mod suppression;
 pub mod trace;
 pub mod types;
 
 // Re-exports for convenience
-pub use analyzers::{
-    AiAnalyzer, AiAnalyzerConfig, AiBackend, AnalyzerConfig, AstAnalyzer, AstAnalyzerConfig,
-    StaticAnalyzer,
-};
+#[cfg(feature = "native")]
+pub use analyzers::{AiAnalyzer, AiAnalyzerConfig, AiBackend};
+pub use analyzers::{AnalyzerConfig, AstAnalyzer, AstAnalyzerConfig, StaticAnalyzer};
 pub use cache::{ScanCache, ScanProfile};
 pub use components::{ComponentIndex, ComponentKind, DetectedComponent};
 pub use config::Config;
 pub use decoders::Decoder;
 pub use deps::{DependencyAnalyzer, DependencyAnalyzerConfig};
+pub use error::VexscanError;
 pub use reporters::{report, OutputFormat};
 pub use rules::{
     loader::{
@@ -61,24 +101,29 @@ pub use rules::{
 };
 pub use scope::{detect_scope, InstallScope, ScopeMap};
 pub use trace::ReferenceGraph;
-pub use types::{truncate, Finding, Platform, ScanReport, ScanResult, Severity};
+pub use types::{
+    truncate, Confidence, Finding, LimitReason, LimitsReport, Platform, ScanReport, ScanResult,
+    ScanStats, Severity, SkippedFile, SuppressedFinding, TruncatedFindings,
+};
 
 use adapters::{create_adapter, detect_platform, PlatformAdapter};
 use anyhow::Result;
 use std::io::{IsTerminal, Write};
 use std::path::{Path, PathBuf};
 use std::sync::{
-    atomic::{AtomicUsize, Ordering},
+    atomic::{AtomicBool, AtomicUsize, Ordering},
     Arc,
 };
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 /// Configuration for the scanner.
 #[derive(Debug, Clone)]
 pub struct ScanConfig {
-    /// Enable AI-powered analysis.
+    /// Enable AI-powered analysis. Only available with the `native` feature.
+    #[cfg(feature = "native")]
     pub enable_ai: bool,
-    /// AI analyzer configuration.
+    /// AI analyzer configuration. Only available with the `native` feature.
+    #[cfg(feature = "native")]
     pub ai_config: Option<AiAnalyzerConfig>,
     /// Enable AST-based analysis for obfuscation detection.
     pub enable_ast: bool,
@@ -92,20 +137,126 @@ pub struct ScanConfig {
     pub static_config: AnalyzerConfig,
     /// Minimum severity to include in results.
     pub min_severity: Severity,
+    /// Minimum confidence to include in results.
+    pub min_confidence: Confidence,
     /// Platform to scan (auto-detect if None).
     pub platform: Option<Platform>,
     /// Filter configuration (allowlists, trusted packages).
     pub filter_config: Config,
     /// Enable result caching (default true, disabled when AI is on).
     pub enable_cache: bool,
-    /// Only scan installed/published files (skip dev-only files entirely).
+    /// Downgrade findings in dev-only files (tests, examples, docs) to Low
+    /// severity and confidence instead of reporting them at full strength.
+    /// Dev-only files are still scanned and their findings still appear in
+    /// the report — use `skip_dev_only` to omit them entirely instead.
     pub installed_only: bool,
+    /// Skip dev-only files entirely instead of downgrading their findings.
+    /// Faster, but malware hidden in a dev-only path won't be reported at all.
+    pub skip_dev_only: bool,
     /// Scan all files at full severity (disable scope-based severity capping).
     pub include_dev: bool,
     /// Additional directories to load rules from at runtime.
     pub extra_rules_dirs: Vec<PathBuf>,
     /// Max parallel threads for scanning (0 = all CPUs, default = half CPUs).
     pub max_threads: usize,
+    /// Previously-accepted findings to suppress rather than re-report.
+    pub baseline: Option<crate::suppression::Baseline>,
+    /// Mask secret-like substrings (API keys, tokens) in finding snippets
+    /// before the report is returned, so reports don't themselves leak
+    /// credentials when shared or archived in CI logs.
+    pub redact_snippets: bool,
+    /// Restrict scanning to exactly these files (as absolute, canonicalized
+    /// paths), e.g. the output of a `git diff --name-only`. `None` scans
+    /// everything discovered, as usual.
+    pub changed_paths: Option<std::collections::HashSet<PathBuf>>,
+    /// Collect per-phase timing and the slowest files into
+    /// `ScanReport.stats`, for the `--stats` CLI flag. Off by default since
+    /// it adds bookkeeping overhead that most scans don't need.
+    pub collect_stats: bool,
+    /// Skip files larger than this many bytes instead of scanning them.
+    /// `None` (default) means unlimited.
+    pub max_file_size: Option<u64>,
+    /// Scan at most this many discovered files; the rest are recorded as
+    /// skipped rather than analyzed. `None` (default) means unlimited.
+    pub max_total_files: Option<usize>,
+    /// Stop starting new file analysis once this much wall-clock time has
+    /// elapsed since the scan began; already-started files still finish,
+    /// remaining ones are recorded as skipped. `None` (default) means
+    /// unlimited.
+    pub max_scan_duration: Option<Duration>,
+    /// Keep at most this many findings per file, dropping the rest (after
+    /// severity/confidence filtering). `None` (default) means unlimited.
+    pub max_findings_per_file: Option<usize>,
+    /// Instead of the full `node_modules` walk, scan only packages that
+    /// declare a `preinstall`/`postinstall`/`prepare` script (plus any
+    /// local file that script appears to invoke) — where real npm
+    /// supply-chain payloads run. Cheaper than scanning all of
+    /// `node_modules` while still covering the actual risk. Overridden by
+    /// `filter_config.skip_node_modules`, which skips it entirely.
+    pub node_modules_scripts_only: bool,
+    /// Honor `.gitignore` and a project-local `.vexscanignore` file (at any
+    /// depth) when discovering files to scan, the same way other scanners
+    /// skip build artifacts and vendored junk. Disable to scan everything
+    /// the adapter discovers regardless of ignore files.
+    pub respect_ignore_files: bool,
+    /// Resume a scan interrupted by a crash or Ctrl-C, for multi-hour scans
+    /// of huge trees like `node_modules`. Forces `enable_cache` on for this
+    /// run (result caching is what actually lets already-analyzed files be
+    /// skipped on the next attempt) and has no effect while `enable_ai` is
+    /// set, since AI results aren't cached either. Files that changed
+    /// between the interrupted attempt and this one are re-analyzed
+    /// normally — caching is content-hash-keyed, not path-keyed.
+    pub resume: bool,
+    /// Cap how many files may be analyzed at once, on top of `max_threads`'s
+    /// pool size — the effective concurrency is
+    /// `min(resolve_thread_count(max_threads), max_concurrent_files)`.
+    /// `None` (default) means no additional cap. Useful for keeping a scan
+    /// from crowding out other work on a developer laptop or a shared CI
+    /// runner without having to reason about thread-pool sizing directly.
+    pub max_concurrent_files: Option<usize>,
+    /// Cap how many AI backend requests may be in flight at once. `None`
+    /// (default) falls back to a conservative default (see
+    /// `DEFAULT_MAX_CONCURRENT_AI_REQUESTS`) rather than firing one request
+    /// per file, which would overwhelm rate-limited APIs and shared CI
+    /// egress. Only meaningful when `enable_ai` is set.
+    pub max_concurrent_ai_requests: Option<usize>,
+    /// Cap disk read throughput during file discovery/analysis to roughly
+    /// this many bytes per second. `None` (default) means unlimited. A
+    /// coarse token-bucket limiter, not a precise rate guarantee — meant to
+    /// keep a huge scan from saturating IO on shared or resource-constrained
+    /// machines, not to hit an exact number.
+    pub max_io_bytes_per_sec: Option<u64>,
+    /// Stop submitting new files for AI analysis once the estimated cost of
+    /// AI backend calls made so far in this scan reaches this many US
+    /// dollars. `None` (default) means unlimited. Cost is estimated from
+    /// each backend's reported token usage against `ai::pricing`'s
+    /// per-model rate table; files skipped this way are recorded in
+    /// `LimitsReport::skipped_files` with `LimitReason::AiBudgetExceeded` —
+    /// their static/AST findings are unaffected. Only meaningful when
+    /// `enable_ai` is set.
+    pub max_ai_cost_usd: Option<f64>,
+    /// Switch the AI phase from independently re-scanning each file's raw
+    /// content for new findings to triaging the static/AST findings already
+    /// detected for it — the AI sees each finding plus surrounding context
+    /// and classifies it as a true positive, a likely false positive, or
+    /// needing human review, recording the verdict in the finding's
+    /// metadata (`ai_triage_verdict`, `ai_triage_reasoning`) and demoting
+    /// likely false positives to `Severity::Low` rather than dropping them.
+    /// Files with no static/AST findings are never submitted, since there's
+    /// nothing to triage. Only meaningful when `enable_ai` is set.
+    #[cfg(feature = "native")]
+    pub ai_triage: bool,
+    /// Restrict the AI phase to a targeted prompt-injection scan over
+    /// `ComponentType::Prompt` files and MCP server config content (the
+    /// closest static proxy vexscan has to a tool's description text), using
+    /// an injection-specialized prompt that returns the specific
+    /// manipulative sentences it found with byte offsets into the file, so
+    /// they can be highlighted rather than just flagging the whole file.
+    /// Other files are left untouched by the AI phase entirely. If
+    /// `ai_triage` is also set, `ai_triage` takes priority. Only meaningful
+    /// when `enable_ai` is set.
+    #[cfg(feature = "native")]
+    pub ai_injection_scan: bool,
 }
 
 impl Default for ScanConfig {
@@ -113,7 +264,9 @@ impl Default for ScanConfig {
         let filter_config = Config::load_default();
         let extra_rules_dirs = filter_config.resolved_extra_rules_dirs();
         Self {
+            #[cfg(feature = "native")]
             enable_ai: false,
+            #[cfg(feature = "native")]
             ai_config: None,
             enable_ast: false,
             ast_config: None,
@@ -121,13 +274,34 @@ impl Default for ScanConfig {
             deps_config: None,
             static_config: AnalyzerConfig::default(),
             min_severity: Severity::High,
+            min_confidence: Confidence::Low,
             platform: None,
             filter_config,
             enable_cache: true,
             installed_only: false,
+            skip_dev_only: false,
             include_dev: false,
             extra_rules_dirs,
             max_threads: 0,
+            baseline: None,
+            redact_snippets: false,
+            changed_paths: None,
+            collect_stats: false,
+            max_file_size: None,
+            max_total_files: None,
+            max_scan_duration: None,
+            max_findings_per_file: None,
+            node_modules_scripts_only: false,
+            respect_ignore_files: true,
+            resume: false,
+            max_concurrent_files: None,
+            max_concurrent_ai_requests: None,
+            max_io_bytes_per_sec: None,
+            max_ai_cost_usd: None,
+            #[cfg(feature = "native")]
+            ai_triage: false,
+            #[cfg(feature = "native")]
+            ai_injection_scan: false,
         }
     }
 }
@@ -150,6 +324,53 @@ fn resolve_thread_count(max_threads: usize) -> usize {
     }
 }
 
+/// Default cap on in-flight AI backend requests when `enable_ai` is set but
+/// `max_concurrent_ai_requests` isn't, chosen to stay well under typical
+/// per-account rate limits without making huge scans painfully slow.
+const DEFAULT_MAX_CONCURRENT_AI_REQUESTS: usize = 4;
+
+/// Files at or under this size are eligible to be grouped with other small
+/// files into a single batched AI request (see `AiAnalyzer::analyze_batch`),
+/// rather than each getting its own request. Larger files always go through
+/// individually so one big file can't dominate a batch's prompt.
+const AI_BATCH_MAX_FILE_BYTES: usize = 2_000;
+
+/// Cap on how many small files share one batched AI request.
+const AI_BATCH_MAX_FILES: usize = 10;
+
+/// A coarse token-bucket limiter shared across scanning threads, capping
+/// disk read throughput to roughly `ScanConfig::max_io_bytes_per_sec`. Not a
+/// precise rate guarantee (bursts ahead of the check are possible) — good
+/// enough to keep a huge scan from saturating IO on shared or
+/// resource-constrained machines.
+struct IoThrottle {
+    bytes_per_sec: u64,
+    start: Instant,
+    bytes_read: AtomicUsize,
+}
+
+impl IoThrottle {
+    fn new(bytes_per_sec: u64) -> Self {
+        Self {
+            bytes_per_sec,
+            start: Instant::now(),
+            bytes_read: AtomicUsize::new(0),
+        }
+    }
+
+    /// Record `n` more bytes read, blocking the calling thread first if
+    /// doing so would exceed the configured rate.
+    fn throttle(&self, n: usize) {
+        let total = self.bytes_read.fetch_add(n, Ordering::Relaxed) + n;
+        let elapsed = self.start.elapsed().as_secs_f64();
+        let allowed = self.bytes_per_sec as f64 * elapsed;
+        if total as f64 > allowed {
+            let excess_secs = (total as f64 - allowed) / self.bytes_per_sec as f64;
+            std::thread::sleep(Duration::from_secs_f64(excess_secs));
+        }
+    }
+}
+
 /// Progress tracker for large scans. Shows progress on stderr when it's a TTY.
 struct ScanProgress {
     total: usize,
@@ -234,19 +455,118 @@ pub struct Scanner {
     static_analyzer: StaticAnalyzer,
     ast_analyzer: Option<AstAnalyzer>,
     deps_analyzer: Option<DependencyAnalyzer>,
+    #[cfg(feature = "native")]
     ai_analyzer: Option<AiAnalyzer>,
     cache: Option<ScanCache>,
     trusted_domains: domains::TrustedDomainDb,
+    on_event: Option<Arc<dyn Fn(ScanEvent) + Send + Sync>>,
+    cancel: Arc<AtomicBool>,
+}
+
+/// A notable moment during a scan, for embedders that want progress feedback
+/// without polling. Delivered via `ScannerBuilder::on_event`.
+pub enum ScanEvent {
+    /// Scanning has begun; `total_files` is the number of files that will be scanned.
+    Started { total_files: usize },
+    /// A single file finished scanning.
+    FileScanned { path: PathBuf, findings: usize },
+    /// Scanning has finished; `total_findings` is the report's grand total.
+    Completed { total_findings: usize },
+}
+
+/// A cheap, cloneable handle that lets an embedding application (an IDE, a
+/// server) cancel an in-flight `Scanner::scan_path`/`scan_platform` call from
+/// another thread or task. Obtained via `Scanner::abort_handle`. Cancelling
+/// doesn't kill the scan outright — files already dispatched to a worker
+/// finish, remaining files are recorded as skipped with
+/// `LimitReason::Cancelled`, and the scan returns its partial `ScanReport`
+/// as `Ok` rather than an error, the same way `max_scan_duration` does.
+#[derive(Debug, Clone, Default)]
+pub struct AbortHandle(Arc<AtomicBool>);
+
+impl AbortHandle {
+    /// Request cancellation of the scan this handle was obtained from.
+    pub fn abort(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    /// Whether cancellation has been requested.
+    pub fn is_aborted(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// Fluent entry point for embedding vexscan in another program. Wraps the
+/// same `ScanConfig` that `Scanner::with_config` takes, so callers who
+/// already build a `ScanConfig` by hand can keep doing so — this just gives
+/// embedders a more discoverable, chainable alternative.
+#[derive(Default)]
+pub struct ScannerBuilder {
+    config: ScanConfig,
+    on_event: Option<Arc<dyn Fn(ScanEvent) + Send + Sync>>,
+}
+
+impl ScannerBuilder {
+    /// Start from default configuration.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add directories to load additional JSON rules from.
+    pub fn with_rules(mut self, dirs: impl IntoIterator<Item = PathBuf>) -> Self {
+        self.config.extra_rules_dirs.extend(dirs);
+        self
+    }
+
+    /// Enable or disable AST-based obfuscation detectors.
+    pub fn with_detectors(mut self, enable_ast: bool) -> Self {
+        self.config.enable_ast = enable_ast;
+        self
+    }
+
+    /// Select which platform adapter discovers components to scan.
+    pub fn with_adapters(mut self, platform: Platform) -> Self {
+        self.config.platform = Some(platform);
+        self
+    }
+
+    /// Enable AI-powered analysis with the given backend configuration.
+    /// Only available with the `native` feature.
+    #[cfg(feature = "native")]
+    pub fn with_ai(mut self, ai_config: AiAnalyzerConfig) -> Self {
+        self.config.enable_ai = true;
+        self.config.ai_config = Some(ai_config);
+        self
+    }
+
+    /// Enable or disable result caching.
+    pub fn with_cache(mut self, enable: bool) -> Self {
+        self.config.enable_cache = enable;
+        self
+    }
+
+    /// Receive `ScanEvent`s as scanning progresses.
+    pub fn on_event(mut self, callback: impl Fn(ScanEvent) + Send + Sync + 'static) -> Self {
+        self.on_event = Some(Arc::new(callback));
+        self
+    }
+
+    /// Build the configured `Scanner`.
+    pub fn build(self) -> std::result::Result<Scanner, VexscanError> {
+        let mut scanner = Scanner::with_config(self.config)?;
+        scanner.on_event = self.on_event;
+        Ok(scanner)
+    }
 }
 
 impl Scanner {
     /// Create a new scanner with default configuration.
-    pub fn new() -> Result<Self> {
+    pub fn new() -> std::result::Result<Self, VexscanError> {
         Self::with_config(ScanConfig::default())
     }
 
     /// Create a scanner with custom configuration.
-    pub fn with_config(config: ScanConfig) -> Result<Self> {
+    pub fn with_config(config: ScanConfig) -> std::result::Result<Self, VexscanError> {
         let mut static_analyzer = StaticAnalyzer::with_config(config.static_config.clone())?;
 
         // Load external rules from configured directories
@@ -290,13 +610,27 @@ impl Scanner {
             None
         };
 
+        #[cfg(feature = "native")]
         let ai_analyzer = if config.enable_ai {
             config.ai_config.clone().map(AiAnalyzer::new)
         } else {
             None
         };
 
-        let cache = if config.enable_cache && !config.enable_ai {
+        // AI results aren't cached (they're non-deterministic / cost money to
+        // recompute deliberately), so disable the cache whenever AI is on.
+        #[cfg(feature = "native")]
+        let cache_disabled_by_ai = config.enable_ai;
+        #[cfg(not(feature = "native"))]
+        let cache_disabled_by_ai = false;
+
+        if config.resume && cache_disabled_by_ai {
+            tracing::warn!(
+                "--resume has no effect while AI analysis is enabled, since AI results aren't cached either"
+            );
+        }
+
+        let cache = if (config.enable_cache || config.resume) && !cache_disabled_by_ai {
             let profile = ScanProfile::from_config(
                 config.enable_ast,
                 config.enable_deps,
@@ -321,12 +655,34 @@ impl Scanner {
             static_analyzer,
             ast_analyzer,
             deps_analyzer,
+            #[cfg(feature = "native")]
             ai_analyzer,
             cache,
             trusted_domains,
+            on_event: None,
+            cancel: Arc::new(AtomicBool::new(false)),
         })
     }
 
+    /// The scanner's effective configuration.
+    pub fn config(&self) -> &ScanConfig {
+        &self.config
+    }
+
+    /// Number of loaded static-analysis rules (built-in plus any
+    /// `extra_rules_dirs`).
+    pub fn rule_count(&self) -> usize {
+        self.static_analyzer.rule_count()
+    }
+
+    /// Get a handle that can cancel a `scan_path`/`scan_platform` call
+    /// running on this scanner from another thread or task. Cloning the
+    /// scanner does not clone cancellation state independently — grab a
+    /// fresh handle from the scanner you're actually running the scan on.
+    pub fn abort_handle(&self) -> AbortHandle {
+        AbortHandle(Arc::clone(&self.cancel))
+    }
+
     /// Scan a specific path (file or directory).
     pub async fn scan_path(&self, path: &Path) -> Result<ScanReport> {
         let start = Instant::now();
@@ -365,7 +721,46 @@ impl Scanner {
         }
 
         // Discover files to scan
-        let components = adapter.discover_at(path)?;
+        let mut components = adapter.discover_at(path)?;
+
+        // Drop anything excluded by the project's own .gitignore/
+        // .vexscanignore before any other filtering, so build artifacts and
+        // vendored junk never enter the pipeline in the first place.
+        if self.config.respect_ignore_files && path.is_dir() {
+            let ignore_aware = adapters::ignore_aware_files(path);
+            components.retain(|c| ignore_aware.contains(&c.path));
+        }
+
+        // Instead of the adapter's normal full node_modules coverage, keep
+        // only the packages that declare an install script (and whatever
+        // local file it invokes) — where real npm supply-chain payloads run.
+        if self.config.node_modules_scripts_only {
+            let node_modules_root = path.join("node_modules");
+            components.retain(|c| !c.path.starts_with(&node_modules_root));
+
+            let install_script_files = deps::install_scripts::discover_install_script_files(path);
+            tracing::info!(
+                "node_modules scripts-only mode: found {} file(s) from packages with install scripts",
+                install_script_files.len()
+            );
+            components.extend(install_script_files.into_iter().map(|p| {
+                let name = p
+                    .file_name()
+                    .and_then(|n| n.to_str())
+                    .unwrap_or("unknown")
+                    .to_string();
+                let component_type = if name == "package.json" {
+                    adapters::ComponentType::Config
+                } else {
+                    adapters::ComponentType::Plugin
+                };
+                adapters::DiscoveredComponent {
+                    path: p,
+                    component_type,
+                    name,
+                }
+            }));
+        }
 
         tracing::info!("Discovered {} components to scan", components.len());
 
@@ -387,8 +782,15 @@ impl Scanner {
             );
         }
 
+        // Build the cross-file module graph of dangerous-function re-exports
+        // before per-file AST analysis, so CrossFileAliasDetector can trace a
+        // local import back to the file that aliased it.
+        if let Some(ref ast) = self.ast_analyzer {
+            ast.set_module_graph(ast.build_module_graph(&components));
+        }
+
         // Filter components and classify scope once (avoids re-classifying in Phase 2)
-        let installed_only = self.config.installed_only;
+        let skip_dev_only = self.config.skip_dev_only;
         let scannable: Vec<_> = components
             .into_iter()
             .filter_map(|c| {
@@ -396,6 +798,24 @@ impl Scanner {
                     tracing::debug!("Skipping (allowlisted): {}", c.path.display());
                     return None;
                 }
+                if !self.config.filter_config.is_in_scope(&c.path) {
+                    tracing::debug!(
+                        "Skipping (out of include/exclude scope): {}",
+                        c.path.display()
+                    );
+                    return None;
+                }
+                if let Some(ref changed) = self.config.changed_paths {
+                    let matches = c
+                        .path
+                        .canonicalize()
+                        .map(|p| changed.contains(&p))
+                        .unwrap_or(false);
+                    if !matches {
+                        tracing::debug!("Skipping (not changed): {}", c.path.display());
+                        return None;
+                    }
+                }
                 if self.config.filter_config.third_party_only
                     && self.config.filter_config.is_trusted_source(&c.path)
                 {
@@ -403,9 +823,9 @@ impl Scanner {
                     return None;
                 }
                 let file_scope = scope_map.classify(&c.path, path);
-                // Skip dev-only files when --installed-only is set
+                // Skip dev-only files when --skip-dev-only is set
                 // (but keep agent-reachable files even if dev-only)
-                if installed_only
+                if skip_dev_only
                     && file_scope == scope::InstallScope::DevOnly
                     && !ref_graph.is_agent_reachable(&c.path)
                 {
@@ -416,125 +836,513 @@ impl Scanner {
             })
             .collect();
 
+        // Enforce max_total_files by truncating the scannable set up front;
+        // anything beyond the cap is recorded as skipped rather than analyzed.
+        let mut limits_report = LimitsReport::default();
+        let mut scannable = scannable;
+        if let Some(max_total) = self.config.max_total_files {
+            if scannable.len() > max_total {
+                for (component, _) in scannable.drain(max_total..) {
+                    limits_report.skipped_files.push(SkippedFile {
+                        path: component.path,
+                        reason: LimitReason::TotalFileLimit,
+                    });
+                }
+            }
+        }
+
+        let discovery_ms = start.elapsed().as_millis() as u64;
+
         // Phase 1: Parallel static + AST analysis (CPU-bound, read file once per component)
         // On cache hit, skip analysis entirely and return cached findings.
         let static_analyzer = &self.static_analyzer;
         let ast_analyzer = &self.ast_analyzer;
         let min_severity = self.config.min_severity;
+        let min_confidence = self.config.min_confidence;
         let filter_config = &self.config.filter_config;
         let cache = &self.cache;
 
         // Tuple: (component, content, result, cache_hit, file_scope)
-        let num_threads = resolve_thread_count(self.config.max_threads);
+        let num_threads = self
+            .config
+            .max_concurrent_files
+            .map(|cap| resolve_thread_count(self.config.max_threads).min(cap.max(1)))
+            .unwrap_or_else(|| resolve_thread_count(self.config.max_threads));
         tracing::info!("Scanning with {} threads", num_threads);
 
+        let io_throttle = self
+            .config
+            .max_io_bytes_per_sec
+            .map(|bytes_per_sec| IoThrottle::new(bytes_per_sec.max(1)));
+
+        if let Some(ref on_event) = self.on_event {
+            on_event(ScanEvent::Started {
+                total_files: scannable.len(),
+            });
+        }
+
         let progress = Arc::new(ScanProgress::new(scannable.len()));
+        let max_file_size = self.config.max_file_size;
+        let max_scan_duration = self.config.max_scan_duration;
+        let cancel = &self.cancel;
+        let resumed_count = AtomicUsize::new(0);
+        let io_throttle = io_throttle.as_ref();
+
+        let (static_results, mut phase1_skipped): (Vec<_>, Vec<_>) = {
+            let raw: Vec<(Vec<_>, Vec<SkippedFile>)> = std::thread::scope(|s| {
+                // Chunk work across a fixed number of threads instead of one-per-file
+                let chunks: Vec<&[(_, _)]> = scannable
+                    .chunks((scannable.len() / num_threads).max(1))
+                    .collect();
+                let resumed_count = &resumed_count;
+                let handles: Vec<_> = chunks
+                    .into_iter()
+                    .map(|chunk| {
+                        let progress = Arc::clone(&progress);
+                        s.spawn(move || {
+                            let mut results = Vec::with_capacity(chunk.len());
+                            let mut skipped = Vec::new();
+                            for (component, file_scope) in chunk {
+                                if cancel.load(Ordering::Relaxed) {
+                                    skipped.push(SkippedFile {
+                                        path: component.path.clone(),
+                                        reason: LimitReason::Cancelled,
+                                    });
+                                    continue;
+                                }
+                                if let Some(max_duration) = max_scan_duration {
+                                    if start.elapsed() > max_duration {
+                                        skipped.push(SkippedFile {
+                                            path: component.path.clone(),
+                                            reason: LimitReason::DurationExceeded,
+                                        });
+                                        continue;
+                                    }
+                                }
+                                tracing::debug!("Scanning: {}", component.path.display());
+                                progress.increment();
+                                let raw_bytes = match std::fs::read(&component.path) {
+                                    Ok(b) => b,
+                                    Err(e) => {
+                                        tracing::warn!(
+                                            "Failed to read {}: {}",
+                                            component.path.display(),
+                                            e
+                                        );
+                                        continue;
+                                    }
+                                };
+                                if let Some(throttle) = io_throttle {
+                                    throttle.throttle(raw_bytes.len());
+                                }
+                                if let Some(max_size) = max_file_size {
+                                    if raw_bytes.len() as u64 > max_size {
+                                        tracing::debug!(
+                                            "Skipping (too large): {}",
+                                            component.path.display()
+                                        );
+                                        skipped.push(SkippedFile {
+                                            path: component.path.clone(),
+                                            reason: LimitReason::FileTooLarge,
+                                        });
+                                        continue;
+                                    }
+                                }
+                                let content = if binary::is_binary(&component.path, &raw_bytes) {
+                                    binary::extract_strings(&raw_bytes)
+                                } else {
+                                    String::from_utf8_lossy(&raw_bytes).into_owned()
+                                };
+
+                                // Compute content hash for cache lookup
+                                let content_hash = {
+                                    use sha2::{Digest, Sha256};
+                                    let mut hasher = Sha256::new();
+                                    hasher.update(content.as_bytes());
+                                    format!("{:x}", hasher.finalize())
+                                };
+
+                                // Check cache
+                                if let Some(ref cache) = cache {
+                                    if let Some(mut cached_findings) = cache.get(&content_hash) {
+                                        tracing::debug!(
+                                            "Cache hit: {} ({} findings)",
+                                            component.path.display(),
+                                            cached_findings.len()
+                                        );
+                                        for finding in &mut cached_findings {
+                                            finding.location.file = component.path.clone();
+                                        }
+                                        let mut result = ScanResult::new(component.path.clone());
+                                        result.content_hash = Some(content_hash);
+                                        result.findings = cached_findings;
+                                        resumed_count.fetch_add(1, Ordering::Relaxed);
+                                        results.push((
+                                            component,
+                                            content,
+                                            result,
+                                            true,
+                                            *file_scope,
+                                            0u64,
+                                        ));
+                                        continue;
+                                    }
+                                }
 
-        let static_results: Vec<_> = std::thread::scope(|s| {
-            // Chunk work across a fixed number of threads instead of one-per-file
-            let chunks: Vec<&[(_, _)]> = scannable
-                .chunks((scannable.len() / num_threads).max(1))
-                .collect();
-            let handles: Vec<_> = chunks
-                .into_iter()
-                .map(|chunk| {
-                    let progress = Arc::clone(&progress);
-                    s.spawn(move || {
-                        let mut results = Vec::with_capacity(chunk.len());
-                        for (component, file_scope) in chunk {
-                            tracing::debug!("Scanning: {}", component.path.display());
-                            progress.increment();
-                            let content = match std::fs::read_to_string(&component.path) {
-                                Ok(c) => c,
+                                // Cache miss — run static analysis (pass pre-computed hash)
+                                let mut result = match static_analyzer.scan_content(
+                                    &content,
+                                    &component.path,
+                                    Some(content_hash),
+                                    Some(component.component_type),
+                                ) {
+                                    Ok(result) => result,
+                                    Err(e) => {
+                                        tracing::warn!(
+                                            "Failed to scan {}: {}",
+                                            component.path.display(),
+                                            e
+                                        );
+                                        continue;
+                                    }
+                                };
+
+                                // AST analysis runs in the same thread (per-call parser, no Mutex)
+                                let ast_start = Instant::now();
+                                if let Some(ref ast) = ast_analyzer {
+                                    match ast.analyze_content_str(&content, &component.path) {
+                                        Ok(ast_result) => {
+                                            result.findings.extend(ast_result.findings);
+                                        }
+                                        Err(e) => {
+                                            tracing::warn!(
+                                                "AST analysis failed for {}: {}",
+                                                component.path.display(),
+                                                e
+                                            );
+                                        }
+                                    }
+                                }
+                                let ast_time_ms = ast_start.elapsed().as_millis() as u64;
+
+                                results.push((
+                                    component,
+                                    content,
+                                    result,
+                                    false,
+                                    *file_scope,
+                                    ast_time_ms,
+                                ));
+                            }
+                            (results, skipped)
+                        })
+                    })
+                    .collect();
+
+                handles
+                    .into_iter()
+                    .map(|h| h.join().unwrap_or_default())
+                    .collect()
+            });
+
+            let mut results = Vec::new();
+            let mut skipped = Vec::new();
+            for (r, s) in raw {
+                results.extend(r);
+                skipped.extend(s);
+            }
+            (results, skipped)
+        };
+        limits_report.skipped_files.append(&mut phase1_skipped);
+
+        progress.finish();
+
+        if self.config.resume {
+            let resumed = resumed_count.load(Ordering::Relaxed);
+            tracing::info!(
+                "Resumed scan: {} of {} files already covered by cache entries from a previous attempt",
+                resumed,
+                scannable.len()
+            );
+        }
+
+        // AI analysis runs concurrently ahead of Phase 2, bounded by
+        // `max_concurrent_ai_requests`, so slow/rate-limited network calls
+        // for different files overlap instead of running one at a time.
+        // Phase 2 itself stays sequential (deterministic ordering, shared
+        // mutable stats) and just looks up each file's already-computed
+        // findings here instead of awaiting inline.
+        #[cfg(feature = "native")]
+        let ai_findings_by_path: std::collections::HashMap<PathBuf, Vec<Finding>> =
+            if let Some(ref ai_analyzer) = self.ai_analyzer {
+                let ai_analyzer = Arc::new(ai_analyzer.clone());
+                let max_concurrent = self
+                    .config
+                    .max_concurrent_ai_requests
+                    .unwrap_or(DEFAULT_MAX_CONCURRENT_AI_REQUESTS)
+                    .max(1);
+                let semaphore = Arc::new(tokio::sync::Semaphore::new(max_concurrent));
+                // Cost is tracked in micro-dollars so concurrent tasks can
+                // update it lock-free; `max_ai_cost_usd` is a soft cap since
+                // in-flight requests when the budget is crossed still run to
+                // completion, but no new file is submitted after that.
+                let spent_micros = Arc::new(std::sync::atomic::AtomicU64::new(0));
+                let max_ai_cost_usd = self.config.max_ai_cost_usd;
+
+                let handles = if self.config.ai_triage {
+                    // Triage mode: classify findings the static/AST analyzers
+                    // already detected for each file instead of independently
+                    // re-scanning its content. Files with no findings yet are
+                    // skipped — there's nothing to triage, and it avoids
+                    // spending AI budget on them.
+                    let mut handles = Vec::new();
+                    for (component, content, result, ..) in &static_results {
+                        if result.findings.is_empty() {
+                            continue;
+                        }
+                        let ai_analyzer = Arc::clone(&ai_analyzer);
+                        let semaphore = Arc::clone(&semaphore);
+                        let spent_micros = Arc::clone(&spent_micros);
+                        let path = component.path.clone();
+                        let content = content.clone();
+                        let findings = result.findings.clone();
+                        handles.push(tokio::spawn(async move {
+                            let _permit = semaphore.acquire_owned().await.ok();
+                            if let Some(max_cost) = max_ai_cost_usd {
+                                let spent =
+                                    spent_micros.load(Ordering::Relaxed) as f64 / 1_000_000.0;
+                                if spent >= max_cost {
+                                    return vec![(path, Vec::new(), true)];
+                                }
+                            }
+                            // On failure, keep the original findings rather
+                            // than dropping them — unlike a rescan, these
+                            // findings already existed and weren't created by
+                            // this call.
+                            let triaged = match ai_analyzer
+                                .triage_findings(&findings, &content, &path)
+                                .await
+                            {
+                                Ok((triaged, cost_usd)) => {
+                                    spent_micros.fetch_add(
+                                        (cost_usd * 1_000_000.0).round() as u64,
+                                        Ordering::Relaxed,
+                                    );
+                                    triaged
+                                }
                                 Err(e) => {
                                     tracing::warn!(
-                                        "Failed to read {}: {}",
-                                        component.path.display(),
+                                        "AI triage failed for {}: {}",
+                                        path.display(),
                                         e
                                     );
-                                    continue;
+                                    findings
                                 }
                             };
-
-                            // Compute content hash for cache lookup
-                            let content_hash = {
-                                use sha2::{Digest, Sha256};
-                                let mut hasher = Sha256::new();
-                                hasher.update(content.as_bytes());
-                                format!("{:x}", hasher.finalize())
-                            };
-
-                            // Check cache
-                            if let Some(ref cache) = cache {
-                                if let Some(mut cached_findings) = cache.get(&content_hash) {
-                                    tracing::debug!(
-                                        "Cache hit: {} ({} findings)",
-                                        component.path.display(),
-                                        cached_findings.len()
+                            vec![(path, triaged, false)]
+                        }));
+                    }
+                    handles
+                } else if self.config.ai_injection_scan {
+                    // Targeted pass: only `ComponentType::Prompt` files and
+                    // MCP server config content go through the
+                    // injection-specialized prompt; everything else is left
+                    // untouched by the AI phase for this scan.
+                    let mut handles = Vec::new();
+                    for (component, content, ..) in &static_results {
+                        if !matches!(
+                            component.component_type,
+                            adapters::ComponentType::Prompt | adapters::ComponentType::McpServer
+                        ) {
+                            continue;
+                        }
+                        let ai_analyzer = Arc::clone(&ai_analyzer);
+                        let semaphore = Arc::clone(&semaphore);
+                        let spent_micros = Arc::clone(&spent_micros);
+                        let path = component.path.clone();
+                        let content = content.clone();
+                        handles.push(tokio::spawn(async move {
+                            let _permit = semaphore.acquire_owned().await.ok();
+                            if let Some(max_cost) = max_ai_cost_usd {
+                                let spent =
+                                    spent_micros.load(Ordering::Relaxed) as f64 / 1_000_000.0;
+                                if spent >= max_cost {
+                                    return vec![(path, Vec::new(), true)];
+                                }
+                            }
+                            let findings = match ai_analyzer
+                                .analyze_for_prompt_injection(&content, &path)
+                                .await
+                            {
+                                Ok((findings, cost_usd)) => {
+                                    spent_micros.fetch_add(
+                                        (cost_usd * 1_000_000.0).round() as u64,
+                                        Ordering::Relaxed,
                                     );
-                                    for finding in &mut cached_findings {
-                                        finding.location.file = component.path.clone();
-                                    }
-                                    let mut result = ScanResult::new(component.path.clone());
-                                    result.content_hash = Some(content_hash);
-                                    result.findings = cached_findings;
-                                    results.push((component, content, result, true, *file_scope));
-                                    continue;
+                                    findings
+                                }
+                                Err(e) => {
+                                    tracing::warn!(
+                                        "AI prompt-injection scan failed for {}: {}",
+                                        path.display(),
+                                        e
+                                    );
+                                    Vec::new()
+                                }
+                            };
+                            vec![(path, findings, false)]
+                        }));
+                    }
+                    handles
+                } else {
+                    // Small files (prompts, tiny configs, etc.) are grouped into
+                    // batched requests so a tree with thousands of them doesn't
+                    // burn one AI request per file; larger files still get their
+                    // own request so a single file's content can't crowd out the
+                    // rest of a batch's prompt. Both kinds of task report back
+                    // through the same `(path, findings, budget_exceeded)` shape.
+                    let (batchable, individual): (Vec<_>, Vec<_>) = static_results
+                        .iter()
+                        .partition(|(_, content, ..)| content.len() <= AI_BATCH_MAX_FILE_BYTES);
+
+                    let mut handles = Vec::with_capacity(
+                        individual.len() + batchable.len().div_ceil(AI_BATCH_MAX_FILES),
+                    );
+                    for (component, content, ..) in individual {
+                        let ai_analyzer = Arc::clone(&ai_analyzer);
+                        let semaphore = Arc::clone(&semaphore);
+                        let spent_micros = Arc::clone(&spent_micros);
+                        let path = component.path.clone();
+                        let content = content.clone();
+                        handles.push(tokio::spawn(async move {
+                            let _permit = semaphore.acquire_owned().await.ok();
+                            if let Some(max_cost) = max_ai_cost_usd {
+                                let spent =
+                                    spent_micros.load(Ordering::Relaxed) as f64 / 1_000_000.0;
+                                if spent >= max_cost {
+                                    return vec![(path, Vec::new(), true)];
                                 }
                             }
-
-                            // Cache miss — run static analysis (pass pre-computed hash)
-                            let mut result = match static_analyzer.scan_content(
-                                &content,
-                                &component.path,
-                                Some(content_hash),
-                            ) {
-                                Ok(result) => result,
+                            let findings = match ai_analyzer
+                                .analyze_content(&content, &path, analyzers::ContentType::Code)
+                                .await
+                            {
+                                Ok((findings, cost_usd)) => {
+                                    spent_micros.fetch_add(
+                                        (cost_usd * 1_000_000.0).round() as u64,
+                                        Ordering::Relaxed,
+                                    );
+                                    findings
+                                }
                                 Err(e) => {
                                     tracing::warn!(
-                                        "Failed to scan {}: {}",
-                                        component.path.display(),
+                                        "AI analysis failed for {}: {}",
+                                        path.display(),
                                         e
                                     );
-                                    continue;
+                                    Vec::new()
                                 }
                             };
-
-                            // AST analysis runs in the same thread (per-call parser, no Mutex)
-                            if let Some(ref ast) = ast_analyzer {
-                                match ast.analyze_content_str(&content, &component.path) {
-                                    Ok(ast_result) => {
-                                        result.findings.extend(ast_result.findings);
-                                    }
-                                    Err(e) => {
-                                        tracing::warn!(
-                                            "AST analysis failed for {}: {}",
-                                            component.path.display(),
-                                            e
-                                        );
-                                    }
+                            vec![(path, findings, false)]
+                        }));
+                    }
+                    for chunk in batchable.chunks(AI_BATCH_MAX_FILES) {
+                        let ai_analyzer = Arc::clone(&ai_analyzer);
+                        let semaphore = Arc::clone(&semaphore);
+                        let spent_micros = Arc::clone(&spent_micros);
+                        let files: Vec<(PathBuf, String, analyzers::ContentType)> = chunk
+                            .iter()
+                            .map(|(component, content, ..)| {
+                                (
+                                    component.path.clone(),
+                                    content.clone(),
+                                    analyzers::ContentType::Code,
+                                )
+                            })
+                            .collect();
+                        handles.push(tokio::spawn(async move {
+                            let _permit = semaphore.acquire_owned().await.ok();
+                            let paths: Vec<PathBuf> =
+                                files.iter().map(|(p, ..)| p.clone()).collect();
+                            if let Some(max_cost) = max_ai_cost_usd {
+                                let spent =
+                                    spent_micros.load(Ordering::Relaxed) as f64 / 1_000_000.0;
+                                if spent >= max_cost {
+                                    return paths
+                                        .into_iter()
+                                        .map(|p| (p, Vec::new(), true))
+                                        .collect();
                                 }
                             }
-
-                            results.push((component, content, result, false, *file_scope));
+                            match ai_analyzer.analyze_batch(&files).await {
+                                Ok((mut findings_by_path, cost_usd)) => {
+                                    spent_micros.fetch_add(
+                                        (cost_usd * 1_000_000.0).round() as u64,
+                                        Ordering::Relaxed,
+                                    );
+                                    paths
+                                        .into_iter()
+                                        .map(|p| {
+                                            let findings =
+                                                findings_by_path.remove(&p).unwrap_or_default();
+                                            (p, findings, false)
+                                        })
+                                        .collect()
+                                }
+                                Err(e) => {
+                                    tracing::warn!(
+                                        "Batched AI analysis failed for {} files: {}",
+                                        paths.len(),
+                                        e
+                                    );
+                                    paths.into_iter().map(|p| (p, Vec::new(), false)).collect()
+                                }
+                            }
+                        }));
+                    }
+                    handles
+                };
+
+                let mut map = std::collections::HashMap::new();
+                for handle in handles {
+                    if let Ok(results) = handle.await {
+                        for (path, findings, budget_exceeded) in results {
+                            if budget_exceeded {
+                                limits_report.skipped_files.push(SkippedFile {
+                                    path,
+                                    reason: LimitReason::AiBudgetExceeded,
+                                });
+                            } else {
+                                map.insert(path, findings);
+                            }
                         }
-                        results
-                    })
-                })
-                .collect();
-
-            handles
-                .into_iter()
-                .flat_map(|h| h.join().unwrap_or_default())
-                .collect()
-        });
-
-        progress.finish();
+                    }
+                }
+                map
+            } else {
+                std::collections::HashMap::new()
+            };
 
         // Phase 2: Sequential post-processing (reuses already-read content + pre-computed scope)
         let include_dev = self.config.include_dev;
+        let installed_only = self.config.installed_only;
         let component_index = components::ComponentIndex::new(&report.components);
-        for (component, content, mut result, cache_hit, mut file_scope) in static_results {
+        let collect_stats = self.config.collect_stats;
+        let max_findings_per_file = self.config.max_findings_per_file;
+        let mut static_ms_total: u64 = 0;
+        let mut ast_ms_total: u64 = 0;
+        let mut ai_ms_total: u64 = 0;
+        let mut deps_ms_total: u64 = 0;
+        let mut bytes_scanned: u64 = 0;
+        let mut slow_files: Vec<types::SlowFile> = Vec::new();
+        for (component, content, mut result, cache_hit, mut file_scope, ast_time_ms) in
+            static_results
+        {
+            if collect_stats {
+                bytes_scanned += content.len() as u64;
+                static_ms_total += result.scan_time_ms;
+                ast_ms_total += ast_time_ms;
+            }
             // Assign file to nearest AI component (O(path depth) via HashMap)
             result.component_idx = component_index.assign(&component.path);
             // Elevate agent-reachable dev-only files
@@ -543,6 +1351,7 @@ impl Scanner {
                 file_scope = scope::InstallScope::Installed;
             }
             result.install_scope = Some(file_scope);
+            result.component_type = Some(component.component_type);
 
             // Track scope counts
             if is_agent_reachable {
@@ -640,34 +1449,100 @@ impl Scanner {
                 }
             }
 
+            // Suppression (inline comment, config allowlist, baseline) — move
+            // accepted-risk findings into `result.suppressed` before scope/severity
+            // filtering runs in either branch below, so review history is kept even
+            // for findings that would otherwise be dropped as noise.
+            if !result.findings.is_empty() {
+                let lines: Vec<&str> = content.lines().collect();
+                let mut kept = Vec::with_capacity(result.findings.len());
+                for finding in result.findings.drain(..) {
+                    let matched = suppression::inline_suppression(&finding, &lines)
+                        .or_else(|| {
+                            suppression::allowlist_suppression(
+                                filter_config,
+                                &finding,
+                                &component.path,
+                                static_analyzer.ruleset(),
+                            )
+                        })
+                        .or_else(|| {
+                            self.config.baseline.as_ref().and_then(|b| {
+                                b.suppression_for(&finding, path, static_analyzer.ruleset())
+                            })
+                        });
+                    match matched {
+                        Some(suppression) => result.suppressed.push(SuppressedFinding {
+                            finding,
+                            suppression,
+                        }),
+                        None => kept.push(finding),
+                    }
+                }
+                result.findings = kept;
+            }
+
             if cache_hit {
                 // Apply scope-based severity cap to cached findings
                 if file_scope == scope::InstallScope::DevOnly && !include_dev {
                     for finding in &mut result.findings {
-                        if finding.severity > Severity::Low
-                            && !scope::is_scope_cap_exempt(&finding.rule_id, manifest_based)
-                        {
+                        if scope::is_scope_cap_exempt(&finding.rule_id, manifest_based) {
+                            continue;
+                        }
+                        if finding.severity > Severity::Low {
                             finding.metadata.insert(
                                 "original_severity".to_string(),
                                 format!("{}", finding.severity),
                             );
-                            finding
-                                .metadata
-                                .insert("install_scope".to_string(), "dev_only".to_string());
                             finding.severity = Severity::Low;
                         }
+                        if installed_only && finding.confidence > Confidence::Low {
+                            finding.metadata.insert(
+                                "original_confidence".to_string(),
+                                format!("{}", finding.confidence),
+                            );
+                            finding.confidence = Confidence::Low;
+                        }
+                        finding
+                            .metadata
+                            .insert("install_scope".to_string(), "dev_only".to_string());
                     }
                 }
 
                 // Apply severity/disabled-rule filter to cached findings
                 result.findings.retain(|f| {
-                    f.severity >= min_severity && !filter_config.is_rule_disabled(&f.rule_id)
+                    f.severity >= min_severity
+                        && f.confidence >= min_confidence
+                        && !filter_config.is_rule_disabled(&f.rule_id)
                 });
+                if let Some(max_findings) = max_findings_per_file {
+                    if result.findings.len() > max_findings {
+                        let dropped = result.findings.len() - max_findings;
+                        result.findings.truncate(max_findings);
+                        limits_report.truncated_findings.push(TruncatedFindings {
+                            path: component.path.clone(),
+                            dropped,
+                        });
+                    }
+                }
+                if let Some(ref on_event) = self.on_event {
+                    on_event(ScanEvent::FileScanned {
+                        path: component.path.clone(),
+                        findings: result.findings.len(),
+                    });
+                }
+                if collect_stats {
+                    slow_files.push(types::SlowFile {
+                        path: component.path.clone(),
+                        time_ms: result.scan_time_ms + ast_time_ms,
+                    });
+                }
                 report.results.push(result);
                 continue;
             }
 
             // Cache miss — run remaining analyzers (AST already done in Phase 1)
+            let deps_start = Instant::now();
             if let Some(ref deps_analyzer) = self.deps_analyzer {
                 if component
                     .path
@@ -689,28 +1564,36 @@ impl Scanner {
                     }
                 }
             }
-
-            // AI analysis reuses already-read content
-            if let Some(ref ai_analyzer) = self.ai_analyzer {
-                let content_type = analyzers::ContentType::Code;
-
-                match ai_analyzer
-                    .analyze_content(&content, &component.path, content_type)
-                    .await
-                {
-                    Ok(ai_findings) => {
-                        result.findings.extend(ai_findings);
-                    }
-                    Err(e) => {
-                        tracing::warn!(
-                            "AI analysis failed for {}: {}",
-                            component.path.display(),
-                            e
-                        );
+            let deps_time_ms = deps_start.elapsed().as_millis() as u64;
+
+            // AI analysis already ran concurrently in the pre-pass above;
+            // just pick up its findings for this file here. In triage mode
+            // the map holds the SAME findings the file already had, now
+            // annotated/severity-adjusted, so they replace rather than
+            // extend the existing list.
+            #[cfg(feature = "native")]
+            let ai_time_ms = {
+                let ai_start = Instant::now();
+                if let Some(ai_findings) = ai_findings_by_path.get(&component.path) {
+                    if self.config.ai_triage {
+                        result.findings = ai_findings.clone();
+                    } else {
+                        result.findings.extend(ai_findings.iter().cloned());
                     }
                 }
+                ai_start.elapsed().as_millis() as u64
+            };
+            #[cfg(not(feature = "native"))]
+            let ai_time_ms = 0u64;
+            if collect_stats {
+                deps_ms_total += deps_time_ms;
+                ai_ms_total += ai_time_ms;
             }
 
+            // Link related findings (e.g. obfuscation -> eval -> exfil) into
+            // composite chains before caching, so cache hits replay them too.
+            correlation::correlate(&mut result.findings);
+
             // Store unfiltered findings in cache before applying filters
             if let Some(ref cache) = self.cache {
                 if let Some(ref hash) = result.content_hash {
@@ -727,31 +1610,102 @@ impl Scanner {
             // Apply scope-based severity cap (post-cache, like doc-file cap)
             if file_scope == scope::InstallScope::DevOnly && !include_dev {
                 for finding in &mut result.findings {
-                    if finding.severity > Severity::Low
-                        && !scope::is_scope_cap_exempt(&finding.rule_id, manifest_based)
-                    {
+                    if scope::is_scope_cap_exempt(&finding.rule_id, manifest_based) {
+                        continue;
+                    }
+                    if finding.severity > Severity::Low {
                         finding
                             .metadata
                             .entry("original_severity".to_string())
                             .or_insert_with(|| format!("{}", finding.severity));
+                        finding.severity = Severity::Low;
+                    }
+                    if installed_only && finding.confidence > Confidence::Low {
                         finding
                             .metadata
-                            .insert("install_scope".to_string(), "dev_only".to_string());
-                        finding.severity = Severity::Low;
+                            .entry("original_confidence".to_string())
+                            .or_insert_with(|| format!("{}", finding.confidence));
+                        finding.confidence = Confidence::Low;
                     }
+                    finding
+                        .metadata
+                        .insert("install_scope".to_string(), "dev_only".to_string());
                 }
             }
 
             // Now apply severity/disabled-rule filter
             result.findings.retain(|f| {
-                f.severity >= min_severity && !filter_config.is_rule_disabled(&f.rule_id)
+                f.severity >= min_severity
+                    && f.confidence >= min_confidence
+                    && !filter_config.is_rule_disabled(&f.rule_id)
             });
 
+            if let Some(max_findings) = max_findings_per_file {
+                if result.findings.len() > max_findings {
+                    let dropped = result.findings.len() - max_findings;
+                    result.findings.truncate(max_findings);
+                    limits_report.truncated_findings.push(TruncatedFindings {
+                        path: component.path.clone(),
+                        dropped,
+                    });
+                }
+            }
+
+            if let Some(ref on_event) = self.on_event {
+                on_event(ScanEvent::FileScanned {
+                    path: component.path.clone(),
+                    findings: result.findings.len(),
+                });
+            }
+            if collect_stats {
+                slow_files.push(types::SlowFile {
+                    path: component.path.clone(),
+                    time_ms: result.scan_time_ms + ast_time_ms + ai_time_ms + deps_time_ms,
+                });
+            }
             report.results.push(result);
         }
 
+        // Parallel scanning doesn't guarantee file completion order; sort
+        // before computing scores so reports are stable across runs.
+        report.sort_deterministic();
+
         report.total_time_ms = start.elapsed().as_millis() as u64;
-        report.risk_score = report.compute_risk_score();
+        report.risk_score =
+            report.compute_risk_score_weighted(&filter_config.component_type_weights());
+        report.grade = crate::scoring::grade(report.risk_score);
+        report.component_risk_scores = report.compute_component_risk_scores();
+
+        if collect_stats {
+            slow_files.sort_by_key(|f| std::cmp::Reverse(f.time_ms));
+            slow_files.truncate(10);
+            report.stats = Some(types::ScanStats {
+                discovery_ms,
+                static_ms: static_ms_total,
+                ast_ms: ast_ms_total,
+                ai_ms: ai_ms_total,
+                deps_ms: deps_ms_total,
+                files_scanned: report.results.len(),
+                bytes_scanned,
+                rules_active: report.rule_count,
+                slowest_files: slow_files,
+            });
+        }
+
+        if !limits_report.skipped_files.is_empty() || !limits_report.truncated_findings.is_empty() {
+            report.limits = Some(limits_report);
+        }
+
+        if self.config.redact_snippets {
+            crate::redaction::redact_report(&mut report);
+        }
+
+        if let Some(ref on_event) = self.on_event {
+            on_event(ScanEvent::Completed {
+                total_findings: report.total_findings(),
+            });
+        }
+
         Ok(report)
     }
 
@@ -784,3 +1738,131 @@ impl Default for Scanner {
         Self::new().expect("Failed to create default scanner")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    /// `Config::extra_rules_dirs` (and `ScanConfig::extra_rules_dirs`, which
+    /// it feeds) is how team/local rule directories get merged with
+    /// built-ins at scanner construction — no need to call
+    /// `RuleSet::with_rules_from_directory` from application code.
+    #[tokio::test]
+    async fn test_extra_rules_dirs_merge_with_builtins() {
+        let rules_dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            rules_dir.path().join("custom.json"),
+            r#"{
+                "category": "Shell Execution",
+                "rules": [{
+                    "id": "CUSTOM-TEAM-001",
+                    "title": "Team-specific canary string",
+                    "description": "Flags a string only this team's rule knows about",
+                    "severity": "high",
+                    "patterns": ["team-canary-do-not-ship"]
+                }]
+            }"#,
+        )
+        .unwrap();
+
+        let builtin_only = Scanner::new().unwrap();
+        let builtin_count = builtin_only.rule_count();
+
+        let config = ScanConfig {
+            extra_rules_dirs: vec![rules_dir.path().to_path_buf()],
+            min_severity: Severity::Low,
+            ..Default::default()
+        };
+        let scanner = Scanner::with_config(config).unwrap();
+        assert_eq!(scanner.rule_count(), builtin_count + 1);
+
+        let mut fixture = tempfile::NamedTempFile::with_suffix(".sh").unwrap();
+        writeln!(fixture, "echo team-canary-do-not-ship").unwrap();
+        let report = scanner.scan_path(fixture.path()).await.unwrap();
+        assert!(
+            report
+                .results
+                .iter()
+                .flat_map(|r| &r.findings)
+                .any(|f| f.rule_id == "CUSTOM-TEAM-001"),
+            "expected the merged custom rule to fire on its own fixture"
+        );
+    }
+
+    /// `max_concurrent_ai_requests` is supposed to bound how many AI
+    /// requests are in flight at once while still letting them overlap
+    /// (the whole point of pipelining AI analysis ahead of Phase 2, rather
+    /// than awaiting each file's request in turn). Spins up a tiny local
+    /// Ollama-shaped HTTP server that tracks concurrency itself, so this
+    /// exercises the real semaphore-bounded `tokio::spawn` fan-out in
+    /// `Scanner::scan` instead of just asserting on the config value.
+    #[cfg(feature = "native")]
+    #[tokio::test]
+    async fn test_max_concurrent_ai_requests_bounds_and_allows_overlap() {
+        use std::sync::atomic::AtomicUsize as ConcurrencyCounter;
+
+        let in_flight = Arc::new(ConcurrencyCounter::new(0));
+        let max_observed = Arc::new(ConcurrencyCounter::new(0));
+
+        let in_flight_for_handler = Arc::clone(&in_flight);
+        let max_observed_for_handler = Arc::clone(&max_observed);
+        let app = axum::Router::new().route(
+            "/api/generate",
+            axum::routing::post(move || {
+                let in_flight = Arc::clone(&in_flight_for_handler);
+                let max_observed = Arc::clone(&max_observed_for_handler);
+                async move {
+                    let current = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                    max_observed.fetch_max(current, Ordering::SeqCst);
+                    tokio::time::sleep(Duration::from_millis(50)).await;
+                    in_flight.fetch_sub(1, Ordering::SeqCst);
+                    axum::Json(serde_json::json!({"response": "{\"findings\": []}"}))
+                }
+            }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+
+        let scan_dir = tempfile::tempdir().unwrap();
+        // Each file must exceed `AI_BATCH_MAX_FILE_BYTES` so it gets its own
+        // request instead of being folded into one shared batch request.
+        for i in 0..6 {
+            std::fs::write(
+                scan_dir.path().join(format!("file{i}.js")),
+                format!(
+                    "// {}\nconsole.log('padding');\n",
+                    "x".repeat(AI_BATCH_MAX_FILE_BYTES)
+                ),
+            )
+            .unwrap();
+        }
+
+        let config = ScanConfig {
+            enable_ai: true,
+            ai_config: Some(analyzers::AiAnalyzerConfig {
+                backend: analyzers::AiBackend::Ollama,
+                base_url: Some(base_url),
+                ..Default::default()
+            }),
+            max_concurrent_ai_requests: Some(2),
+            min_severity: Severity::Low,
+            ..Default::default()
+        };
+        let scanner = Scanner::with_config(config).unwrap();
+        scanner.scan_path(scan_dir.path()).await.unwrap();
+
+        let observed = max_observed.load(Ordering::SeqCst);
+        assert!(
+            observed > 1,
+            "expected AI requests to overlap, but max concurrency observed was {observed}"
+        );
+        assert!(
+            observed <= 2,
+            "max_concurrent_ai_requests: Some(2) should cap concurrency at 2, but observed {observed}"
+        );
+    }
+}
@@ -4,7 +4,7 @@ use anyhow::Result;
 use clap::Parser;
 use colored::Colorize;
 use std::io;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicBool, Ordering};
 use tracing_subscriber::EnvFilter;
 
@@ -21,13 +21,19 @@ macro_rules! info {
     };
 }
 use vexscan::{
-    cli::{CacheSubcommand, Cli, Commands, RulesSubcommand},
+    cli::{
+        BaselineSubcommand, CacheSubcommand, Cli, Commands, HistorySubcommand, HookSubcommand,
+        RulesSubcommand,
+    },
+    compare::diff_reports,
     config::{generate_default_config, Config},
     decoders::Decoder,
-    filter_rules_by_author, filter_rules_by_source, filter_rules_by_tag, load_builtin_json_rules,
-    reporters::{report, OutputFormat},
+    filter_rules_by_author, filter_rules_by_source, filter_rules_by_tag, fixer,
+    load_builtin_json_rules,
+    reporters::{report, report_template, GroupBy, OutputFormat},
     test_all_rules, test_rules_from_file, truncate, AiAnalyzerConfig, AiBackend, AnalyzerConfig,
-    Platform, RuleSource, ScanCache, ScanConfig, ScanProfile, Scanner, Severity,
+    Confidence, Platform, RuleSource, ScanCache, ScanConfig, ScanProfile, ScanReport, Scanner,
+    Severity,
 };
 
 #[tokio::main]
@@ -63,26 +69,61 @@ async fn run() -> Result<()> {
         Config::load_default()
     };
 
+    // --lang overrides the config file's language, which defaults to "en"
+    let lang = cli.lang.clone().unwrap_or_else(|| base_config.lang.clone());
+
     match cli.command {
         Commands::Scan {
             path,
             platform,
             ai,
             ai_backend,
+            ai_model_path,
+            ai_triage,
+            ai_injection_scan,
             output,
             min_severity,
             fail_on,
+            min_confidence,
             skip_deps,
+            node_modules_scripts_only,
+            no_ignore_files,
             enable_entropy,
             trusted_packages,
             third_party_only,
             ast,
             deps,
             no_cache,
+            resume,
             installed_only,
+            skip_dev_only,
             include_dev,
+            attack_matrix,
+            group_by,
+            redact_snippets,
+            fix,
+            fix_dry_run,
+            baseline,
             jobs,
+            changed_since,
+            stats,
+            max_file_size,
+            max_total_files,
+            max_scan_duration,
+            max_findings_per_file,
+            max_concurrent_files,
+            max_concurrent_ai_requests,
+            max_io_bytes_per_sec,
+            max_ai_cost_usd,
         } => {
+            // Keep the original source string (e.g. an `npm:` source) as the
+            // history label — after resolution below it'd just be a temp dir.
+            let target_label = path.display().to_string();
+
+            // Resolve an `npm:package@version` source to a downloaded and
+            // extracted tarball before scanning like a normal local path.
+            let (path, _npm_temp_dir) = resolve_npm_source(&path).await?;
+
             // Parse platform
             let platform: Option<Platform> = platform
                 .map(|p| p.parse())
@@ -92,6 +133,7 @@ async fn run() -> Result<()> {
             // Parse severity
             let min_severity = parse_severity(&min_severity)?;
             let fail_on_severity = parse_severity(&fail_on)?;
+            let min_confidence = parse_confidence(&min_confidence)?;
 
             // Build filter config from base + CLI overrides
             let mut filter_config = base_config;
@@ -112,24 +154,54 @@ async fn run() -> Result<()> {
             if enable_entropy {
                 static_config.enable_entropy = true;
             }
+            static_config.lang = lang.clone();
 
             // Resolve extra rules directories
             let extra_rules_dirs = filter_config.resolved_extra_rules_dirs();
 
+            // Load baseline of previously-accepted findings, if given
+            let baseline = baseline
+                .map(|path| vexscan::suppression::Baseline::load(&path))
+                .transpose()?;
+
+            // Restrict to files changed since a git ref, if requested
+            let changed_paths = changed_since
+                .map(|since| changed_files_since(&path, &since))
+                .transpose()?;
+
             // Build scan config
             let mut config = ScanConfig {
                 enable_ai: ai,
                 enable_ast: ast,
                 enable_deps: deps,
                 enable_cache: !no_cache,
+                resume,
                 platform,
                 min_severity,
+                min_confidence,
                 filter_config,
                 static_config,
                 installed_only,
+                skip_dev_only,
                 include_dev,
                 extra_rules_dirs,
                 max_threads: jobs.unwrap_or(0),
+                baseline,
+                redact_snippets,
+                changed_paths,
+                collect_stats: stats,
+                max_file_size,
+                max_total_files,
+                max_scan_duration: max_scan_duration.map(std::time::Duration::from_secs),
+                max_findings_per_file,
+                max_concurrent_files,
+                max_concurrent_ai_requests,
+                max_io_bytes_per_sec,
+                max_ai_cost_usd,
+                ai_triage,
+                ai_injection_scan,
+                node_modules_scripts_only,
+                respect_ignore_files: !no_ignore_files,
                 ..Default::default()
             };
 
@@ -138,7 +210,9 @@ async fn run() -> Result<()> {
                 let backend = match ai_backend.to_lowercase().as_str() {
                     "claude" => AiBackend::Claude,
                     "openai" => AiBackend::OpenAi,
+                    "gemini" => AiBackend::Gemini,
                     "ollama" => AiBackend::Ollama,
+                    "local" => AiBackend::Local,
                     _ => {
                         return Err(anyhow::anyhow!("Unknown AI backend: {}", ai_backend));
                     }
@@ -147,13 +221,21 @@ async fn run() -> Result<()> {
                 let api_key = match backend {
                     AiBackend::Claude => std::env::var("ANTHROPIC_API_KEY").ok(),
                     AiBackend::OpenAi => std::env::var("OPENAI_API_KEY").ok(),
+                    AiBackend::Gemini => std::env::var("GEMINI_API_KEY").ok(),
                     AiBackend::Ollama => None,
                     AiBackend::Local => None,
                 };
 
+                let model = match backend {
+                    AiBackend::Gemini => "gemini-2.0-flash".to_string(),
+                    _ => AiAnalyzerConfig::default().model,
+                };
+
                 config.ai_config = Some(AiAnalyzerConfig {
                     backend,
                     api_key,
+                    model,
+                    model_path: ai_model_path,
                     ..Default::default()
                 });
             }
@@ -161,18 +243,62 @@ async fn run() -> Result<()> {
             // Run scanner
             let scanner = Scanner::with_config(config)?;
             let scan_report = scanner.scan_path(&path).await?;
+            vexscan::history::record_scan(&scan_report, &target_label)?;
 
             // Output format
             let format: OutputFormat = cli.format.parse().map_err(|e| anyhow::anyhow!("{}", e))?;
+            let group_by: GroupBy = group_by.parse().map_err(|e| anyhow::anyhow!("{}", e))?;
 
             // Write output
             if let Some(output_path) = output {
                 let mut file = std::fs::File::create(&output_path)?;
-                report(&scan_report, format, &mut file)?;
+                match &cli.template {
+                    Some(template_path) => report_template(&scan_report, template_path, &mut file)?,
+                    None => report(&scan_report, format, attack_matrix, group_by, &mut file)?,
+                }
                 info!("Report written to: {}", output_path.display());
             } else {
                 let mut stdout = io::stdout().lock();
-                report(&scan_report, format, &mut stdout)?;
+                match &cli.template {
+                    Some(template_path) => {
+                        report_template(&scan_report, template_path, &mut stdout)?
+                    }
+                    None => report(&scan_report, format, attack_matrix, group_by, &mut stdout)?,
+                }
+            }
+
+            // Per-phase profiling summary
+            if let Some(ref stats) = scan_report.stats {
+                print_stats(stats);
+            }
+
+            // What resource limits skipped or truncated, if any were configured
+            if let Some(ref limits) = scan_report.limits {
+                print_limits(limits);
+            }
+
+            // Apply (or preview) automatic fixes
+            if fix || fix_dry_run {
+                let dry_run = fix_dry_run && !fix;
+                let applied = fixer::apply_fixes(&scan_report, dry_run)?;
+                if applied.is_empty() {
+                    info!("\n{}", "No fixable findings.".dimmed());
+                } else {
+                    let verb = if dry_run { "Would fix" } else { "Fixed" };
+                    info!(
+                        "\n{}",
+                        format!("{} {} finding(s):", verb, applied.len()).bold()
+                    );
+                    for applied_fix in &applied {
+                        info!(
+                            "  {}:{} [{}] {}",
+                            applied_fix.file.display(),
+                            applied_fix.start_line,
+                            applied_fix.rule_id,
+                            applied_fix.description
+                        );
+                    }
+                }
             }
 
             // Hint about additional analyzers
@@ -197,10 +323,12 @@ async fn run() -> Result<()> {
                 );
             }
 
-            // Check fail condition
-            if let Some(max_sev) = scan_report.max_severity() {
+            // Check fail condition (component-type weighting can escalate
+            // a finding's effective severity, e.g. a hook or MCP config)
+            let weights = scanner.config().filter_config.component_type_weights();
+            if let Some(max_sev) = scan_report.max_severity_weighted(&weights) {
                 if max_sev >= fail_on_severity {
-                    std::process::exit(1);
+                    std::process::exit(scanner.config().filter_config.exit_code_for(max_sev));
                 }
             }
         }
@@ -210,8 +338,10 @@ async fn run() -> Result<()> {
             notify: send_notifications,
             third_party_only,
             min_severity,
+            min_confidence,
             watch_paths,
             installed_only,
+            skip_dev_only,
             include_dev,
         } => {
             use notify::{Config as NotifyConfig, RecommendedWatcher, RecursiveMode, Watcher};
@@ -224,6 +354,7 @@ async fn run() -> Result<()> {
                 .map_err(|e| anyhow::anyhow!("{}", e))?;
 
             let min_severity = parse_severity(&min_severity)?;
+            let min_confidence = parse_confidence(&min_confidence)?;
 
             // Build filter config
             let mut filter_config = base_config;
@@ -278,15 +409,20 @@ async fn run() -> Result<()> {
                 enable_ai: false,
                 platform,
                 min_severity,
+                min_confidence,
                 filter_config: filter_config.clone(),
-                static_config: AnalyzerConfig::default(),
+                static_config: AnalyzerConfig {
+                    lang: lang.clone(),
+                    ..Default::default()
+                },
                 installed_only,
+                skip_dev_only,
                 include_dev,
-                extra_rules_dirs,
+                extra_rules_dirs: extra_rules_dirs.clone(),
                 ..Default::default()
             };
 
-            let scanner = Scanner::with_config(scan_config)?;
+            let mut scanner = Scanner::with_config(scan_config.clone())?;
 
             // Set up file watcher
             let (tx, rx) = channel();
@@ -300,6 +436,15 @@ async fn run() -> Result<()> {
                 NotifyConfig::default().with_poll_interval(Duration::from_secs(2)),
             )?;
 
+            // Also watch the custom rules directories, so rule authors get a
+            // tight edit/scan loop without restarting `vexscan watch`.
+            for dir in &extra_rules_dirs {
+                if dir.exists() {
+                    watcher.watch(dir, RecursiveMode::Recursive)?;
+                    info!("  {} watching rules dir: {}", "→".dimmed(), dir.display());
+                }
+            }
+
             // Watch all paths
             for path in &paths_to_watch {
                 if path.exists() {
@@ -313,16 +458,25 @@ async fn run() -> Result<()> {
                 }
             }
 
-            // Track seen files to avoid duplicate scans (capped to prevent unbounded growth)
-            let mut seen_files: std::collections::HashSet<PathBuf> =
-                std::collections::HashSet::new();
-            const MAX_SEEN_FILES: usize = 10_000;
+            // Debounce window: editors and package managers commonly fire
+            // several Create/Modify events per save (temp file + rename,
+            // multiple writes, ...). Coalesce bursts for the same path into
+            // a single rescan, fired once no further event for that path has
+            // arrived for `DEBOUNCE`, capped to prevent unbounded growth.
+            const DEBOUNCE: Duration = Duration::from_millis(500);
+            const MAX_PENDING_FILES: usize = 10_000;
+            let mut pending: std::collections::HashMap<PathBuf, std::time::Instant> =
+                std::collections::HashMap::new();
+            // Separate debounce for rule-dir edits, so a rule-file save
+            // triggers one `RuleSet` rebuild rather than being scanned as a
+            // regular component.
+            let mut rules_dirty_since: Option<std::time::Instant> = None;
 
             // Event loop
             loop {
-                match rx.recv() {
+                match rx.recv_timeout(DEBOUNCE) {
                     Ok(event) => {
-                        // Only process Create events
+                        // Only process Create/Modify events
                         if !matches!(
                             event.kind,
                             notify::EventKind::Create(_) | notify::EventKind::Modify(_)
@@ -331,25 +485,13 @@ async fn run() -> Result<()> {
                         }
 
                         for path in event.paths {
-                            // Skip if we've already seen this file
-                            if seen_files.contains(&path) {
+                            // Skip non-files
+                            if !path.is_file() {
                                 continue;
                             }
-                            if seen_files.len() >= MAX_SEEN_FILES {
-                                // Evict half instead of clearing everything to reduce duplicate scans
-                                let to_remove: Vec<_> = seen_files
-                                    .iter()
-                                    .take(MAX_SEEN_FILES / 2)
-                                    .cloned()
-                                    .collect();
-                                for key in &to_remove {
-                                    seen_files.remove(key);
-                                }
-                            }
-                            seen_files.insert(path.clone());
 
-                            // Skip non-files
-                            if !path.is_file() {
+                            if extra_rules_dirs.iter().any(|dir| path.starts_with(dir)) {
+                                rules_dirty_since = Some(std::time::Instant::now());
                                 continue;
                             }
 
@@ -363,79 +505,119 @@ async fn run() -> Result<()> {
                                 continue;
                             }
 
-                            info!("\n{} New file detected: {}", "📄".cyan(), path.display());
+                            if pending.len() >= MAX_PENDING_FILES {
+                                // Evict half instead of clearing everything to reduce duplicate scans
+                                let to_remove: Vec<_> = pending
+                                    .keys()
+                                    .take(MAX_PENDING_FILES / 2)
+                                    .cloned()
+                                    .collect();
+                                for key in &to_remove {
+                                    pending.remove(key);
+                                }
+                            }
+                            pending.insert(path, std::time::Instant::now());
+                        }
+                    }
+                    Err(std::sync::mpsc::RecvTimeoutError::Timeout) => {}
+                    Err(std::sync::mpsc::RecvTimeoutError::Disconnected) => {
+                        eprintln!("Watch error: file watcher channel disconnected");
+                        break;
+                    }
+                }
 
-                            // Scan the file
-                            match scanner.scan_path(&path).await {
-                                Ok(scan_report) => {
-                                    let findings_count = scan_report.total_findings();
+                // Recompile the RuleSet once rule-dir edits have been quiet
+                // for `DEBOUNCE`, without restarting the whole process.
+                if let Some(seen_at) = rules_dirty_since {
+                    if seen_at.elapsed() >= DEBOUNCE {
+                        rules_dirty_since = None;
+                        info!("\n{} Rules changed, reloading...", "🔄".cyan());
+                        match Scanner::with_config(scan_config.clone()) {
+                            Ok(reloaded) => {
+                                scanner = reloaded;
+                                info!("   {} {} rule(s) loaded", "✓".green(), scanner.rule_count());
+                            }
+                            Err(e) => {
+                                eprintln!("   {} Failed to reload rules: {}", "⚠".yellow(), e);
+                            }
+                        }
+                    }
+                }
 
-                                    if findings_count > 0 {
-                                        let max_sev = scan_report.max_severity();
+                // Rescan any path that's been quiet for at least `DEBOUNCE`
+                let ready: Vec<PathBuf> = pending
+                    .iter()
+                    .filter(|(_, seen_at)| seen_at.elapsed() >= DEBOUNCE)
+                    .map(|(path, _)| path.clone())
+                    .collect();
+
+                for path in ready {
+                    pending.remove(&path);
+
+                    info!("\n{} Change detected: {}", "📄".cyan(), path.display());
+
+                    // Scan the file
+                    match scanner.scan_path(&path).await {
+                        Ok(scan_report) => {
+                            let findings_count = scan_report.total_findings();
+
+                            if findings_count > 0 {
+                                let max_sev = scan_report.max_severity();
+
+                                // Print alert
+                                info!(
+                                    "{} {} finding(s) in {}",
+                                    "🚨".bright_red(),
+                                    findings_count.to_string().bright_red(),
+                                    path.file_name()
+                                        .map(|n| n.to_string_lossy().to_string())
+                                        .unwrap_or_else(|| path.display().to_string())
+                                );
 
-                                        // Print alert
+                                // Show brief summary
+                                for result in &scan_report.results {
+                                    for finding in &result.findings {
+                                        let sev_icon = match finding.severity {
+                                            Severity::Critical => "▲".bright_red(),
+                                            Severity::High => "▲".red(),
+                                            Severity::Medium => "●".yellow(),
+                                            Severity::Low => "●".blue(),
+                                            Severity::Info => "○".white(),
+                                        };
                                         info!(
-                                            "{} {} finding(s) in {}",
-                                            "🚨".bright_red(),
-                                            findings_count.to_string().bright_red(),
-                                            path.file_name()
-                                                .map(|n| n.to_string_lossy().to_string())
-                                                .unwrap_or_else(|| path.display().to_string())
+                                            "   {} [{}] {}",
+                                            sev_icon,
+                                            finding.rule_id.dimmed(),
+                                            finding.title
                                         );
-
-                                        // Show brief summary
-                                        for result in &scan_report.results {
-                                            for finding in &result.findings {
-                                                let sev_icon = match finding.severity {
-                                                    Severity::Critical => "▲".bright_red(),
-                                                    Severity::High => "▲".red(),
-                                                    Severity::Medium => "●".yellow(),
-                                                    Severity::Low => "●".blue(),
-                                                    Severity::Info => "○".white(),
-                                                };
-                                                info!(
-                                                    "   {} [{}] {}",
-                                                    sev_icon,
-                                                    finding.rule_id.dimmed(),
-                                                    finding.title
-                                                );
-                                            }
-                                        }
-
-                                        // Desktop notification
-                                        if send_notifications {
-                                            let severity_text = max_sev
-                                                .map(|s| format!("{:?}", s))
-                                                .unwrap_or_else(|| "Unknown".to_string());
-
-                                            send_desktop_notification(
-                                                &format!(
-                                                    "Vexscan: {} issue(s) found",
-                                                    findings_count
-                                                ),
-                                                &format!(
-                                                    "{} in {}\nMax severity: {}",
-                                                    findings_count,
-                                                    path.file_name()
-                                                        .map(|n| n.to_string_lossy().to_string())
-                                                        .unwrap_or_default(),
-                                                    severity_text
-                                                ),
-                                            );
-                                        }
-                                    } else {
-                                        info!("   {} No issues found", "✓".green());
                                     }
                                 }
-                                Err(e) => {
-                                    eprintln!("   {} Failed to scan: {}", "⚠".yellow(), e);
+
+                                // Desktop notification
+                                if send_notifications {
+                                    let severity_text = max_sev
+                                        .map(|s| format!("{:?}", s))
+                                        .unwrap_or_else(|| "Unknown".to_string());
+
+                                    send_desktop_notification(
+                                        &format!("Vexscan: {} issue(s) found", findings_count),
+                                        &format!(
+                                            "{} in {}\nMax severity: {}",
+                                            findings_count,
+                                            path.file_name()
+                                                .map(|n| n.to_string_lossy().to_string())
+                                                .unwrap_or_default(),
+                                            severity_text
+                                        ),
+                                    );
                                 }
+                            } else {
+                                info!("   {} No issues found", "✓".green());
                             }
                         }
-                    }
-                    Err(e) => {
-                        eprintln!("Watch error: {}", e);
-                        break;
+                        Err(e) => {
+                            eprintln!("   {} Failed to scan: {}", "⚠".yellow(), e);
+                        }
                     }
                 }
             }
@@ -618,6 +800,53 @@ async fn run() -> Result<()> {
                             std::process::exit(1);
                         }
                     }
+
+                    RulesSubcommand::Validate { path } => {
+                        let errors = vexscan::rules::loader::validate_rules_directory(&path);
+                        if errors.is_empty() {
+                            println!("{} No problems found.", "✓".green());
+                        } else {
+                            for error in &errors {
+                                println!("{} {}", "✗".red(), error);
+                            }
+                            println!(
+                                "\n{} {} problem(s) found.",
+                                "✗".red(),
+                                errors.len().to_string().red()
+                            );
+                            std::process::exit(1);
+                        }
+                    }
+
+                    RulesSubcommand::Lint { path } => {
+                        let warnings = vexscan::rules::loader::lint_rules_directory(&path);
+                        if warnings.is_empty() {
+                            println!("{} No problems found.", "✓".green());
+                        } else {
+                            for warning in &warnings {
+                                println!("{} {}", "⚠".yellow(), warning);
+                            }
+                            println!(
+                                "\n{} {} warning(s) found.",
+                                "⚠".yellow(),
+                                warnings.len().to_string().yellow()
+                            );
+                        }
+                    }
+
+                    RulesSubcommand::Update {
+                        source,
+                        branch,
+                        dry_run,
+                        allow_failing_rules,
+                    } => {
+                        update_community_rules(
+                            &source,
+                            branch.as_deref(),
+                            dry_run,
+                            allow_failing_rules,
+                        )?;
+                    }
                 }
                 return Ok(());
             }
@@ -678,6 +907,15 @@ async fn run() -> Result<()> {
                         println!("Category:    {}", r.category);
                         println!("Source:      {}", r.source);
                         println!("Description: {}", r.description);
+                        if r.deprecated {
+                            match &r.replaced_by {
+                                Some(new_id) => println!(
+                                    "{}",
+                                    format!("Deprecated:  replaced by {}", new_id).yellow()
+                                ),
+                                None => println!("{}", "Deprecated:  yes".yellow()),
+                            }
+                        }
                         if r.patterns.len() == 1 {
                             println!("Pattern:     {}", r.patterns[0]);
                         } else {
@@ -779,13 +1017,23 @@ async fn run() -> Result<()> {
                             "".normal()
                         };
 
+                        let deprecated_badge = if r.deprecated {
+                            match &r.replaced_by {
+                                Some(new_id) => format!(" [deprecated, use {}]", new_id).yellow(),
+                                None => " [deprecated]".yellow(),
+                            }
+                        } else {
+                            "".normal()
+                        };
+
                         println!(
-                            "  {} [{}] - {}{}{}",
+                            "  {} [{}] - {}{}{}{}",
                             r.id.bright_cyan(),
                             severity_color,
                             r.title,
                             source_badge,
-                            file_constraint
+                            file_constraint,
+                            deprecated_badge
                         );
                     }
                     println!();
@@ -859,6 +1107,125 @@ async fn run() -> Result<()> {
             println!("Edit this file to customize allowlists and trusted packages.");
         }
 
+        Commands::Explain { id, report } => {
+            // Load built-in rules + external rules from ~/.vexscan/rules/
+            let mut all_rules = load_builtin_json_rules();
+            for dir in &base_config.resolved_extra_rules_dirs() {
+                if dir.is_dir() {
+                    if let Ok(ext_rules) =
+                        vexscan::rules::loader::load_rules_from_directory_with_source(
+                            dir,
+                            Some(RuleSource::External),
+                        )
+                    {
+                        all_rules.extend(ext_rules);
+                    }
+                }
+            }
+
+            let rule = if let Some(r) = all_rules.iter().find(|r| r.id.eq_ignore_ascii_case(&id)) {
+                r.clone()
+            } else if let Ok(fingerprint) = id.parse::<u64>() {
+                let report_path = report.ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "`{}` isn't a known rule ID; pass --report <path> to look it up as a finding fingerprint",
+                        id
+                    )
+                })?;
+                let scan_report: ScanReport =
+                    serde_json::from_str(&std::fs::read_to_string(&report_path)?)?;
+                let finding = scan_report
+                    .results
+                    .iter()
+                    .flat_map(|r| {
+                        r.findings
+                            .iter()
+                            .chain(r.suppressed.iter().map(|s| &s.finding))
+                    })
+                    .find(|f| f.fingerprint() == fingerprint)
+                    .ok_or_else(|| {
+                        anyhow::anyhow!(
+                            "No finding with fingerprint {} in {}",
+                            fingerprint,
+                            report_path.display()
+                        )
+                    })?;
+                all_rules
+                    .iter()
+                    .find(|r| r.id == finding.rule_id)
+                    .ok_or_else(|| {
+                        anyhow::anyhow!("Finding references unknown rule ID: {}", finding.rule_id)
+                    })?
+                    .clone()
+            } else {
+                return Err(anyhow::anyhow!(
+                    "`{}` isn't a known rule ID or a valid finding fingerprint",
+                    id
+                ));
+            };
+
+            let cwe = if rule.cwe.is_empty() {
+                vexscan::compliance::default_cwe(&rule.category)
+            } else {
+                rule.cwe.clone()
+            };
+            let owasp_llm = if rule.owasp_llm.is_empty() {
+                vexscan::compliance::default_owasp_llm(&rule.category)
+            } else {
+                rule.owasp_llm.clone()
+            };
+            let attack_technique = if rule.attack_technique.is_empty() {
+                vexscan::compliance::default_attack_technique(&rule.category)
+            } else {
+                rule.attack_technique.clone()
+            };
+
+            println!("{}", format!("{} — {}", rule.id, rule.title).bold());
+            println!("Severity:    {}", rule.severity);
+            println!("Confidence:  {}", rule.confidence);
+            println!("Category:    {}", rule.category);
+            println!();
+            println!("{}", rule.description);
+            if !cwe.is_empty() {
+                println!("\nCWE:         {}", cwe.join(", "));
+            }
+            if !owasp_llm.is_empty() {
+                println!("OWASP LLM:   {}", owasp_llm.join(", "));
+            }
+            if !attack_technique.is_empty() {
+                println!("ATT&CK/ATLAS: {}", attack_technique.join(", "));
+            }
+
+            if let Some(ref meta) = rule.metadata {
+                if !meta.references.is_empty() {
+                    println!("\n{}", "References:".bold());
+                    for reference in &meta.references {
+                        println!("  - {}", reference);
+                    }
+                }
+            }
+
+            if let Some(ref tc) = rule.metadata.as_ref().and_then(|m| m.test_cases.as_ref()) {
+                if !tc.should_match.is_empty() {
+                    println!("\n{}", "Example malicious code:".red().bold());
+                    for case in &tc.should_match {
+                        println!("  {} {}", "-".red(), case);
+                    }
+                }
+                if !tc.should_not_match.is_empty() {
+                    println!("\n{}", "Example benign code:".green().bold());
+                    for case in &tc.should_not_match {
+                        println!("  {} {}", "-".green(), case);
+                    }
+                }
+            }
+
+            if let Some(ref remediation) = rule.remediation {
+                println!("\n{}", "Remediation:".bold());
+                println!("  {}", remediation);
+            }
+        }
+
         Commands::Install {
             source,
             install_type,
@@ -873,7 +1240,9 @@ async fn run() -> Result<()> {
             deps,
             no_cache,
             installed_only,
+            skip_dev_only,
             include_dev,
+            redact_snippets,
             jobs,
         } => {
             // Validate platform
@@ -939,11 +1308,16 @@ async fn run() -> Result<()> {
                 platform: None,
                 min_severity: Severity::Low,
                 filter_config,
-                static_config: AnalyzerConfig::default(),
+                static_config: AnalyzerConfig {
+                    lang: lang.clone(),
+                    ..Default::default()
+                },
                 installed_only,
+                skip_dev_only,
                 include_dev,
                 extra_rules_dirs,
                 max_threads: jobs.unwrap_or(0),
+                redact_snippets,
                 ..Default::default()
             };
 
@@ -959,7 +1333,12 @@ async fn run() -> Result<()> {
                 let format: OutputFormat =
                     cli.format.parse().map_err(|e| anyhow::anyhow!("{}", e))?;
                 let mut stdout = io::stdout().lock();
-                report(&scan_report, format, &mut stdout)?;
+                match &cli.template {
+                    Some(template_path) => {
+                        report_template(&scan_report, template_path, &mut stdout)?
+                    }
+                    None => report(&scan_report, format, false, GroupBy::default(), &mut stdout)?,
+                }
                 drop(stdout);
                 info!();
             }
@@ -1075,6 +1454,7 @@ async fn run() -> Result<()> {
             output,
             min_severity,
             fail_on,
+            min_confidence,
             skip_deps,
             enable_entropy,
             keep,
@@ -1083,12 +1463,17 @@ async fn run() -> Result<()> {
             deps,
             no_cache,
             installed_only,
+            skip_dev_only,
             include_dev,
+            attack_matrix,
+            group_by,
+            redact_snippets,
             jobs,
         } => {
             // Parse severities
             let min_severity = parse_severity(&min_severity)?;
             let fail_on_severity = parse_severity(&fail_on)?;
+            let min_confidence = parse_confidence(&min_confidence)?;
 
             // Determine if source is a URL or local path
             let (scan_path, temp_dir) = if is_github_url(&source) {
@@ -1118,6 +1503,7 @@ async fn run() -> Result<()> {
             if enable_entropy {
                 static_config.enable_entropy = true;
             }
+            static_config.lang = lang.clone();
 
             // Resolve extra rules directories
             let extra_rules_dirs = filter_config.resolved_extra_rules_dirs();
@@ -1130,12 +1516,15 @@ async fn run() -> Result<()> {
                 enable_cache: !no_cache,
                 platform: None,
                 min_severity,
+                min_confidence,
                 filter_config,
                 static_config,
                 installed_only,
+                skip_dev_only,
                 include_dev,
                 extra_rules_dirs,
                 max_threads: jobs.unwrap_or(0),
+                redact_snippets,
                 ..Default::default()
             };
 
@@ -1145,14 +1534,23 @@ async fn run() -> Result<()> {
 
             // Output results
             let format: OutputFormat = cli.format.parse().map_err(|e| anyhow::anyhow!("{}", e))?;
+            let group_by: GroupBy = group_by.parse().map_err(|e| anyhow::anyhow!("{}", e))?;
 
             if let Some(output_path) = output {
                 let mut file = std::fs::File::create(&output_path)?;
-                report(&scan_report, format, &mut file)?;
+                match &cli.template {
+                    Some(template_path) => report_template(&scan_report, template_path, &mut file)?,
+                    None => report(&scan_report, format, attack_matrix, group_by, &mut file)?,
+                }
                 info!("Report written to: {}", output_path.display());
             } else {
                 let mut stdout = io::stdout().lock();
-                report(&scan_report, format, &mut stdout)?;
+                match &cli.template {
+                    Some(template_path) => {
+                        report_template(&scan_report, template_path, &mut stdout)?
+                    }
+                    None => report(&scan_report, format, attack_matrix, group_by, &mut stdout)?,
+                }
             }
 
             // Print verdict
@@ -1174,10 +1572,12 @@ async fn run() -> Result<()> {
                 // If not keep, temp_dir drops and cleans up automatically
             }
 
-            // Exit with appropriate code
-            if let Some(max_sev) = scan_report.max_severity() {
+            // Exit with appropriate code (component-type weighting can
+            // escalate a finding's effective severity, e.g. a hook or MCP config)
+            let weights = scanner.config().filter_config.component_type_weights();
+            if let Some(max_sev) = scan_report.max_severity_weighted(&weights) {
                 if max_sev >= fail_on_severity {
-                    std::process::exit(1);
+                    std::process::exit(scanner.config().filter_config.exit_code_for(max_sev));
                 }
             }
         }
@@ -1207,11 +1607,469 @@ async fn run() -> Result<()> {
                 }
             }
         }
+
+        Commands::Compare { old, new, json } => {
+            let old_report: ScanReport = serde_json::from_str(&std::fs::read_to_string(&old)?)?;
+            let new_report: ScanReport = serde_json::from_str(&std::fs::read_to_string(&new)?)?;
+            let diff = diff_reports(&old_report, &new_report);
+
+            if json {
+                println!("{}", serde_json::to_string_pretty(&diff)?);
+            } else {
+                println!("{}", "Report Diff".bold().underline());
+                println!(
+                    "  {} new, {} fixed, {} persisting",
+                    diff.new_findings.len().to_string().red(),
+                    diff.fixed_findings.len().to_string().green(),
+                    diff.persisting_findings.len()
+                );
+
+                if !diff.new_findings.is_empty() {
+                    println!("\n{}", "New Findings".bold());
+                    for f in &diff.new_findings {
+                        println!(
+                            "  {} {}:{} [{}] {}",
+                            "+".red(),
+                            f.location.file.display(),
+                            f.location.start_line,
+                            f.rule_id,
+                            f.title
+                        );
+                    }
+                }
+
+                if !diff.fixed_findings.is_empty() {
+                    println!("\n{}", "Fixed Findings".bold());
+                    for f in &diff.fixed_findings {
+                        println!(
+                            "  {} {}:{} [{}] {}",
+                            "-".green(),
+                            f.location.file.display(),
+                            f.location.start_line,
+                            f.rule_id,
+                            f.title
+                        );
+                    }
+                }
+            }
+
+            if !diff.new_findings.is_empty() {
+                std::process::exit(1);
+            }
+        }
+
+        Commands::Fix {
+            path,
+            platform,
+            ast,
+            deps,
+            skip_deps,
+            dry_run,
+        } => {
+            let platform: Option<Platform> = platform
+                .map(|p| p.parse())
+                .transpose()
+                .map_err(|e| anyhow::anyhow!("{}", e))?;
+
+            let mut filter_config = base_config;
+            if skip_deps {
+                filter_config.skip_node_modules = true;
+            }
+            let extra_rules_dirs = filter_config.resolved_extra_rules_dirs();
+
+            let config = ScanConfig {
+                enable_ast: ast,
+                enable_deps: deps,
+                platform,
+                min_severity: Severity::Low,
+                filter_config,
+                static_config: AnalyzerConfig {
+                    lang: lang.clone(),
+                    ..Default::default()
+                },
+                extra_rules_dirs,
+                ..Default::default()
+            };
+
+            let scanner = Scanner::with_config(config)?;
+            let scan_report = scanner.scan_path(&path).await?;
+
+            let applied = fixer::apply_fixes(&scan_report, dry_run)?;
+            if applied.is_empty() {
+                println!("{}", "No fixable findings.".dimmed());
+            } else {
+                let verb = if dry_run { "Would fix" } else { "Fixed" };
+                println!(
+                    "{}",
+                    format!("{} {} finding(s):", verb, applied.len()).bold()
+                );
+                for applied_fix in &applied {
+                    println!(
+                        "\n{}:{} [{}] {}",
+                        applied_fix.file.display(),
+                        applied_fix.start_line,
+                        applied_fix.rule_id,
+                        applied_fix.description
+                    );
+                    for line in applied_fix.before.lines() {
+                        println!("  {} {}", "-".red(), line);
+                    }
+                    for line in applied_fix.after.lines() {
+                        println!("  {} {}", "+".green(), line);
+                    }
+                }
+            }
+        }
+
+        Commands::Baseline { subcommand } => match subcommand {
+            BaselineSubcommand::Create {
+                path,
+                output,
+                platform,
+                ast,
+                deps,
+                skip_deps,
+            } => {
+                let platform: Option<Platform> = platform
+                    .map(|p| p.parse())
+                    .transpose()
+                    .map_err(|e| anyhow::anyhow!("{}", e))?;
+
+                let mut filter_config = base_config;
+                if skip_deps {
+                    filter_config.skip_node_modules = true;
+                }
+                let extra_rules_dirs = filter_config.resolved_extra_rules_dirs();
+
+                let config = ScanConfig {
+                    enable_ast: ast,
+                    enable_deps: deps,
+                    platform,
+                    filter_config,
+                    static_config: AnalyzerConfig {
+                        lang: lang.clone(),
+                        ..Default::default()
+                    },
+                    extra_rules_dirs,
+                    ..Default::default()
+                };
+
+                let scanner = Scanner::with_config(config)?;
+                let scan_report = scanner.scan_path(&path).await?;
+
+                let scan_root = &scan_report.scan_root;
+                let entries: Vec<vexscan::suppression::BaselineEntry> = scan_report
+                    .results
+                    .iter()
+                    .flat_map(|result| {
+                        result.findings.iter().map(|finding| {
+                            let file = finding
+                                .location
+                                .file
+                                .strip_prefix(scan_root)
+                                .unwrap_or(&finding.location.file)
+                                .to_path_buf();
+                            vexscan::suppression::BaselineEntry {
+                                rule_id: finding.rule_id.clone(),
+                                file,
+                                start_line: finding.location.start_line,
+                                reason: Some("baselined on adoption".to_string()),
+                                by: None,
+                                at: None,
+                            }
+                        })
+                    })
+                    .collect();
+
+                let count = entries.len();
+                let baseline = vexscan::suppression::Baseline { entries };
+                std::fs::write(&output, serde_json::to_string_pretty(&baseline)?)?;
+                println!(
+                    "{} Wrote {} finding(s) to baseline: {}",
+                    "✓".green(),
+                    count,
+                    output.display()
+                );
+            }
+
+            BaselineSubcommand::Apply {
+                path,
+                baseline,
+                platform,
+                ast,
+                deps,
+                skip_deps,
+            } => {
+                let platform: Option<Platform> = platform
+                    .map(|p| p.parse())
+                    .transpose()
+                    .map_err(|e| anyhow::anyhow!("{}", e))?;
+
+                let mut filter_config = base_config;
+                if skip_deps {
+                    filter_config.skip_node_modules = true;
+                }
+                let extra_rules_dirs = filter_config.resolved_extra_rules_dirs();
+                let baseline = Some(vexscan::suppression::Baseline::load(&baseline)?);
+
+                let config = ScanConfig {
+                    enable_ast: ast,
+                    enable_deps: deps,
+                    platform,
+                    filter_config,
+                    static_config: AnalyzerConfig {
+                        lang: lang.clone(),
+                        ..Default::default()
+                    },
+                    extra_rules_dirs,
+                    baseline,
+                    ..Default::default()
+                };
+
+                let scanner = Scanner::with_config(config)?;
+                let scan_report = scanner.scan_path(&path).await?;
+
+                let format: OutputFormat =
+                    cli.format.parse().map_err(|e| anyhow::anyhow!("{}", e))?;
+                let mut stdout = io::stdout().lock();
+                report(&scan_report, format, false, GroupBy::File, &mut stdout)?;
+            }
+        },
+
+        Commands::Review {
+            path,
+            platform,
+            ast,
+            deps,
+            skip_deps,
+            baseline,
+        } => {
+            let platform: Option<Platform> = platform
+                .map(|p| p.parse())
+                .transpose()
+                .map_err(|e| anyhow::anyhow!("{}", e))?;
+
+            let mut filter_config = base_config.clone();
+            if skip_deps {
+                filter_config.skip_node_modules = true;
+            }
+            let extra_rules_dirs = filter_config.resolved_extra_rules_dirs();
+
+            let config = ScanConfig {
+                enable_ast: ast,
+                enable_deps: deps,
+                platform,
+                filter_config,
+                static_config: AnalyzerConfig {
+                    lang: lang.clone(),
+                    ..Default::default()
+                },
+                extra_rules_dirs,
+                ..Default::default()
+            };
+
+            let scanner = Scanner::with_config(config)?;
+            let scan_report = scanner.scan_path(&path).await?;
+
+            if scan_report.total_findings() == 0 {
+                println!("{}", "No findings to review.".dimmed());
+                return Ok(());
+            }
+
+            let mut config = base_config;
+            let target = match baseline {
+                Some(ref path) => vexscan::review::SuppressTarget::Baseline(path),
+                None => vexscan::review::SuppressTarget::Config(
+                    cli.config
+                        .as_deref()
+                        .unwrap_or_else(|| Path::new("vexscan.toml")),
+                ),
+            };
+            let outcome = vexscan::review::run(&scan_report, &mut config, target)?;
+
+            println!(
+                "{} {} accepted, {} suppressed, {} fixed, {} skipped",
+                "Review complete:".bold(),
+                outcome.accepted,
+                outcome.suppressed,
+                outcome.fixed,
+                outcome.skipped
+            );
+        }
+
+        Commands::History { subcommand } => match subcommand {
+            HistorySubcommand::Show { target, limit } => {
+                let mut records = vexscan::history::load_history()?;
+                if let Some(ref target) = target {
+                    records.retain(|r| r.target.contains(target.as_str()));
+                }
+
+                if records.is_empty() {
+                    println!("{}", "No scan history recorded yet.".dimmed());
+                    return Ok(());
+                }
+
+                if records.len() > limit {
+                    records.drain(0..records.len() - limit);
+                }
+
+                println!("{}", "Scan History".bold().underline());
+                for record in &records {
+                    let when = chrono::DateTime::from_timestamp(record.timestamp, 0)
+                        .map(|dt| dt.format("%Y-%m-%d %H:%M:%S UTC").to_string())
+                        .unwrap_or_else(|| record.timestamp.to_string());
+                    println!(
+                        "  {}  {}  {} findings",
+                        when.dimmed(),
+                        record.target,
+                        record.total_findings
+                    );
+                    for severity in [
+                        Severity::Critical,
+                        Severity::High,
+                        Severity::Medium,
+                        Severity::Low,
+                        Severity::Info,
+                    ] {
+                        if let Some(count) = record.by_severity.get(&severity) {
+                            if *count > 0 {
+                                print!("    {}: {}  ", severity, count);
+                            }
+                        }
+                    }
+                    println!();
+                }
+
+                println!(
+                    "\n{} {}",
+                    "Trend (total findings):".bold(),
+                    sparkline(&records.iter().map(|r| r.total_findings).collect::<Vec<_>>())
+                );
+            }
+            HistorySubcommand::Clear => {
+                let removed = vexscan::history::clear_history()?;
+                println!("Cleared {} history record(s).", removed);
+            }
+        },
+
+        Commands::Serve { addr } => {
+            let addr: std::net::SocketAddr = addr
+                .parse()
+                .map_err(|e| anyhow::anyhow!("Invalid address {}: {}", addr, e))?;
+            vexscan::server::serve(addr, base_config).await?;
+        }
+
+        Commands::Hook { subcommand } => match subcommand {
+            HookSubcommand::Install { path, force } => {
+                let repo = git2::Repository::discover(&path).map_err(|e| {
+                    anyhow::anyhow!(
+                        "not a git repository (searched from {}): {}",
+                        path.display(),
+                        e
+                    )
+                })?;
+                let git_dir = repo.path();
+                let hooks_dir = git_dir.join("hooks");
+                std::fs::create_dir_all(&hooks_dir)?;
+                let hook_path = hooks_dir.join("pre-commit");
+
+                if hook_path.exists() && !force {
+                    return Err(anyhow::anyhow!(
+                        "pre-commit hook already exists at {} (use --force to overwrite)",
+                        hook_path.display()
+                    ));
+                }
+
+                let script = "#!/bin/sh\n\
+                    # Installed by `vexscan hook install`.\n\
+                    # Fast pre-commit check: only the files changed since HEAD, no AI\n\
+                    # analysis, no dependency scanning.\n\
+                    vexscan scan --changed-since HEAD --fail-on high --skip-deps .\n";
+                std::fs::write(&hook_path, script)?;
+
+                #[cfg(unix)]
+                {
+                    use std::os::unix::fs::PermissionsExt;
+                    let mut perms = std::fs::metadata(&hook_path)?.permissions();
+                    perms.set_mode(0o755);
+                    std::fs::set_permissions(&hook_path, perms)?;
+                }
+
+                println!(
+                    "{}",
+                    format!("Installed pre-commit hook: {}", hook_path.display()).green()
+                );
+            }
+        },
+
+        Commands::AuditMcp { path, json } => {
+            let analyzer = vexscan::analyzers::StaticAnalyzer::new()?;
+            let audits = vexscan::mcp_audit::audit_path(&path, &analyzer)?;
+
+            if json {
+                println!("{}", serde_json::to_string_pretty(&audits)?);
+            } else if audits.is_empty() {
+                println!(
+                    "No MCP server configurations found under {}",
+                    path.display()
+                );
+            } else {
+                for audit in &audits {
+                    let verdict = match audit.verdict() {
+                        Some(sev) => format!("{}", sev).to_uppercase().red(),
+                        None => "CLEAN".green(),
+                    };
+
+                    println!(
+                        "{} [{}]  {}",
+                        audit.name.bold(),
+                        audit.source.display().to_string().dimmed(),
+                        verdict
+                    );
+                    if let Some(ref command) = audit.command {
+                        println!("  command:   {} {}", command, audit.args.join(" "));
+                    }
+                    println!("  transport: {}", audit.transport);
+                    if !audit.env.is_empty() {
+                        println!(
+                            "  env:       {}",
+                            audit.env.keys().cloned().collect::<Vec<_>>().join(", ")
+                        );
+                    }
+                    for finding in &audit.findings {
+                        println!(
+                            "  {} {} - {}",
+                            format!("[{}]", finding.severity).red(),
+                            finding.rule_id.cyan(),
+                            finding.title
+                        );
+                    }
+                    println!();
+                }
+            }
+        }
     }
 
     Ok(())
 }
 
+/// Render a compact Unicode sparkline for a series of counts, e.g. for the
+/// `history show` trendline.
+fn sparkline(values: &[usize]) -> String {
+    const BLOCKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+    let max = values.iter().copied().max().unwrap_or(0);
+    if max == 0 {
+        return values.iter().map(|_| BLOCKS[0]).collect();
+    }
+    values
+        .iter()
+        .map(|&v| {
+            let idx = (v * (BLOCKS.len() - 1)) / max;
+            BLOCKS[idx]
+        })
+        .collect()
+}
+
 /// Check if a string looks like a GitHub URL.
 fn is_github_url(s: &str) -> bool {
     s.starts_with("https://github.com/")
@@ -1220,7 +2078,18 @@ fn is_github_url(s: &str) -> bool {
         || s.starts_with("github.com/")
 }
 
+/// Clone a GitHub repository to a temporary directory. Returns an error
+/// without making any network calls when the `no-network` feature is
+/// enabled.
+#[cfg(feature = "no-network")]
+fn clone_github_repo(_url: &str, _branch: Option<&str>) -> Result<tempfile::TempDir> {
+    Err(anyhow::anyhow!(
+        "cloning a repository requires network access, which is disabled by the `no-network` feature"
+    ))
+}
+
 /// Clone a GitHub repository to a temporary directory.
+#[cfg(not(feature = "no-network"))]
 fn clone_github_repo(url: &str, branch: Option<&str>) -> Result<tempfile::TempDir> {
     // Normalize URL
     let normalized_url = if url.starts_with("github.com/") {
@@ -1266,6 +2135,215 @@ fn clone_github_repo(url: &str, branch: Option<&str>) -> Result<tempfile::TempDi
     Ok(temp_dir)
 }
 
+/// Resolve `--changed-since <since>` to the absolute, canonicalized set of
+/// files changed in the git repository containing `scan_root`, relative to
+/// `since` (a commit-ish like `HEAD` or `main`). Includes uncommitted
+/// changes (staged or not), since that's what a pre-commit hook needs to
+/// check.
+fn changed_files_since(
+    scan_root: &Path,
+    since: &str,
+) -> Result<std::collections::HashSet<PathBuf>> {
+    let repo = git2::Repository::discover(scan_root).map_err(|e| {
+        anyhow::anyhow!(
+            "--changed-since requires a git repository (searched from {}): {}",
+            scan_root.display(),
+            e
+        )
+    })?;
+    let tree = repo
+        .revparse_single(since)
+        .map_err(|e| anyhow::anyhow!("failed to resolve git ref '{}': {}", since, e))?
+        .peel_to_tree()?;
+    let mut diff_opts = git2::DiffOptions::new();
+    diff_opts
+        .include_untracked(true)
+        .recurse_untracked_dirs(true);
+    let diff = repo.diff_tree_to_workdir_with_index(Some(&tree), Some(&mut diff_opts))?;
+    let repo_workdir = repo.workdir().ok_or_else(|| {
+        anyhow::anyhow!(
+            "git repository at {} has no working directory",
+            scan_root.display()
+        )
+    })?;
+
+    let mut changed = std::collections::HashSet::new();
+    diff.foreach(
+        &mut |delta, _| {
+            if let Some(path) = delta.new_file().path() {
+                if let Ok(abs) = repo_workdir.join(path).canonicalize() {
+                    changed.insert(abs);
+                }
+            }
+            true
+        },
+        None,
+        None,
+        None,
+    )?;
+    Ok(changed)
+}
+
+/// Parse an `npm:package@version` (or `npm:package`, `npm:@scope/package`,
+/// `npm:@scope/package@version`) source into a package name and optional
+/// version. Returns `None` if `source` doesn't have the `npm:` prefix.
+fn parse_npm_source(source: &str) -> Option<(String, Option<String>)> {
+    let spec = source.strip_prefix("npm:")?;
+    if let Some(scoped) = spec.strip_prefix('@') {
+        let slash = scoped.find('/')?;
+        let (name_rest, version) = match scoped[slash + 1..].find('@') {
+            Some(at) => (
+                &scoped[..slash + 1 + at],
+                Some(scoped[slash + 1 + at + 1..].to_string()),
+            ),
+            None => (scoped, None),
+        };
+        Some((format!("@{}", name_rest), version))
+    } else {
+        match spec.find('@') {
+            Some(at) => Some((spec[..at].to_string(), Some(spec[at + 1..].to_string()))),
+            None => Some((spec.to_string(), None)),
+        }
+    }
+}
+
+/// If `path` is an `npm:package@version` source, download and extract the
+/// published tarball and return the extracted directory in place of `path`.
+/// Otherwise `path` is returned unchanged. The returned `TempDir` (when
+/// present) must be kept alive for as long as the extracted directory is
+/// needed — it's deleted on drop.
+async fn resolve_npm_source(path: &Path) -> Result<(PathBuf, Option<tempfile::TempDir>)> {
+    let Some((name, version)) = parse_npm_source(&path.to_string_lossy()) else {
+        return Ok((path.to_path_buf(), None));
+    };
+
+    info!(
+        "{} {}",
+        "Fetching from npm:".cyan(),
+        match &version {
+            Some(v) => format!("{}@{}", name, v),
+            None => format!("{}@latest", name),
+        }
+    );
+    let temp_dir = fetch_npm_package(&name, version.as_deref()).await?;
+    let package_dir = temp_dir.path().join("package");
+    Ok((package_dir, Some(temp_dir)))
+}
+
+/// Download and extract an npm package's published tarball to a temporary
+/// directory. Returns an error without making any network calls when the
+/// `no-network` feature is enabled.
+#[cfg(feature = "no-network")]
+async fn fetch_npm_package(_name: &str, _version: Option<&str>) -> Result<tempfile::TempDir> {
+    Err(anyhow::anyhow!(
+        "fetching an npm package requires network access, which is disabled by the `no-network` feature"
+    ))
+}
+
+/// Download and extract an npm package's published tarball to a temporary
+/// directory, matching exactly what `npm install` would place on disk (the
+/// same `files`-whitelisted contents `scope::npm` already knows how to
+/// classify).
+#[cfg(not(feature = "no-network"))]
+async fn fetch_npm_package(name: &str, version: Option<&str>) -> Result<tempfile::TempDir> {
+    let client = reqwest::Client::new();
+    let metadata_url = format!(
+        "https://registry.npmjs.org/{}/{}",
+        name,
+        version.unwrap_or("latest")
+    );
+
+    let metadata: serde_json::Value = client
+        .get(&metadata_url)
+        .send()
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to reach npm registry: {}", e))?
+        .error_for_status()
+        .map_err(|e| anyhow::anyhow!("npm registry lookup for {} failed: {}", name, e))?
+        .json()
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to parse npm registry response: {}", e))?;
+
+    let tarball_url = metadata
+        .get("dist")
+        .and_then(|d| d.get("tarball"))
+        .and_then(|t| t.as_str())
+        .ok_or_else(|| anyhow::anyhow!("npm registry response for {} has no dist.tarball", name))?;
+
+    info!("  {} {}", "Downloading".dimmed(), tarball_url.dimmed());
+    let tarball = client
+        .get(tarball_url)
+        .send()
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to download tarball: {}", e))?
+        .bytes()
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to read tarball: {}", e))?;
+
+    let temp_dir = tempfile::tempdir()?;
+    let decoder = flate2::read::GzDecoder::new(&tarball[..]);
+    let mut archive = tar::Archive::new(decoder);
+    archive
+        .unpack(temp_dir.path())
+        .map_err(|e| anyhow::anyhow!("Failed to extract tarball: {}", e))?;
+
+    info!(
+        "  {} {}",
+        "Extracted to".dimmed(),
+        temp_dir.path().display()
+    );
+
+    Ok(temp_dir)
+}
+
+/// Print per-phase timing, throughput, and the slowest files from a scan's
+/// `--stats` profiling data.
+fn print_stats(stats: &vexscan::ScanStats) {
+    println!();
+    println!("{}", "Scan Profile".bold());
+    println!("  Discovery:  {}ms", stats.discovery_ms);
+    println!("  Static:     {}ms", stats.static_ms);
+    println!("  AST:        {}ms", stats.ast_ms);
+    println!("  AI:         {}ms", stats.ai_ms);
+    println!("  Deps:       {}ms", stats.deps_ms);
+    println!(
+        "  Files:      {} ({} bytes)",
+        stats.files_scanned, stats.bytes_scanned
+    );
+    println!("  Rules:      {}", stats.rules_active);
+
+    if !stats.slowest_files.is_empty() {
+        println!("  Slowest files:");
+        for slow in &stats.slowest_files {
+            println!("    {}ms  {}", slow.time_ms, slow.path.display());
+        }
+    }
+}
+
+/// Print what a configured resource limit (`--max-file-size`,
+/// `--max-total-files`, `--max-scan-duration`, `--max-findings-per-file`)
+/// caused to be skipped or truncated.
+fn print_limits(limits: &vexscan::LimitsReport) {
+    println!();
+    println!("{}", "Resource Limits".bold());
+    if !limits.skipped_files.is_empty() {
+        println!("  Skipped files:");
+        for skipped in &limits.skipped_files {
+            println!("    {}  ({})", skipped.path.display(), skipped.reason);
+        }
+    }
+    if !limits.truncated_findings.is_empty() {
+        println!("  Truncated findings:");
+        for truncated in &limits.truncated_findings {
+            println!(
+                "    {}  ({} finding(s) dropped)",
+                truncated.path.display(),
+                truncated.dropped
+            );
+        }
+    }
+}
+
 /// Print the verdict based on scan results.
 fn print_verdict(report: &vexscan::ScanReport, threshold: Severity) {
     let max_sev = report.max_severity();
@@ -1433,6 +2511,15 @@ fn parse_severity(s: &str) -> Result<Severity> {
     }
 }
 
+fn parse_confidence(s: &str) -> Result<Confidence> {
+    match s.to_lowercase().as_str() {
+        "low" => Ok(Confidence::Low),
+        "medium" | "med" => Ok(Confidence::Medium),
+        "high" => Ok(Confidence::High),
+        _ => Err(anyhow::anyhow!("Unknown confidence: {}", s)),
+    }
+}
+
 /// Send a desktop notification (platform-specific).
 fn send_desktop_notification(title: &str, body: &str) {
     #[cfg(target_os = "macos")]
@@ -1628,3 +2715,144 @@ fn copy_dir_recursive(src: &std::path::Path, dst: &std::path::Path) -> Result<()
 
     Ok(())
 }
+
+/// Sync `~/.vexscan/rules/community/` from an upstream repository's
+/// `rules/community/` directory: clones the repo, validates the fetched
+/// rules, prints a changelog of what would change, and only then replaces
+/// the locally installed community rules. Leaves the existing installation
+/// untouched if the upstream rules fail validation.
+fn update_community_rules(
+    source: &str,
+    branch: Option<&str>,
+    dry_run: bool,
+    allow_failing_rules: bool,
+) -> Result<()> {
+    info!("{} {}", "Fetching rules from:".cyan(), source);
+    let temp_dir = clone_github_repo(source, branch)?;
+
+    let upstream_dir = temp_dir.path().join("rules").join("community");
+    if !upstream_dir.is_dir() {
+        return Err(anyhow::anyhow!(
+            "{} has no rules/community/ directory",
+            source
+        ));
+    }
+
+    let errors = vexscan::rules::loader::validate_rules_directory(&upstream_dir);
+    if !errors.is_empty() {
+        for error in &errors {
+            println!("{} {}", "✗".red(), error);
+        }
+        return Err(anyhow::anyhow!(
+            "upstream community rules failed validation ({} problem(s)); local rules left unchanged",
+            errors.len()
+        ));
+    }
+
+    let new_rules =
+        vexscan::rules::loader::load_rules_from_directory_with_source(&upstream_dir, None)
+            .map_err(|e| anyhow::anyhow!("failed to load fetched rules: {}", e))?;
+
+    // A rule that compiles but fails its own should_match/should_not_match
+    // cases is worse than one that fails to load: it silently produces no
+    // (or wrong) detections instead of erroring loudly.
+    let failing: Vec<_> = test_all_rules(&new_rules)
+        .into_iter()
+        .filter(|r| !r.passed)
+        .collect();
+    if !failing.is_empty() {
+        for result in &failing {
+            println!(
+                "{} {} [{}] failed {}/{} test case(s)",
+                "✗".red(),
+                result.rule_title,
+                result.rule_id,
+                result.failed_tests(),
+                result.total_tests()
+            );
+            if let Some(ref err) = result.error {
+                println!("    {}", err);
+            }
+        }
+        if allow_failing_rules {
+            println!("{} installing anyway (--allow-failing-rules)", "⚠".yellow());
+        } else {
+            return Err(anyhow::anyhow!(
+                "{} upstream rule(s) failed their test cases; local rules left unchanged (use --allow-failing-rules to install anyway)",
+                failing.len()
+            ));
+        }
+    }
+
+    let home = dirs::home_dir().ok_or_else(|| anyhow::anyhow!("Could not find home directory"))?;
+    let local_dir = home.join(".vexscan").join("rules").join("community");
+    let old_rules = if local_dir.is_dir() {
+        vexscan::rules::loader::load_rules_from_directory_with_source(&local_dir, None)
+            .unwrap_or_default()
+    } else {
+        Vec::new()
+    };
+
+    let old_by_id: std::collections::HashMap<_, _> =
+        old_rules.iter().map(|r| (r.id.as_str(), r)).collect();
+    let new_by_id: std::collections::HashMap<_, _> =
+        new_rules.iter().map(|r| (r.id.as_str(), r)).collect();
+
+    let mut added: Vec<&str> = new_by_id
+        .keys()
+        .filter(|id| !old_by_id.contains_key(*id))
+        .copied()
+        .collect();
+    let mut modified: Vec<&str> = new_by_id
+        .iter()
+        .filter_map(|(id, new_rule)| {
+            let old_rule = old_by_id.get(id)?;
+            let changed = old_rule.title != new_rule.title
+                || old_rule.description != new_rule.description
+                || old_rule.severity != new_rule.severity
+                || old_rule.patterns != new_rule.patterns;
+            changed.then_some(*id)
+        })
+        .collect();
+    let mut removed: Vec<&str> = old_by_id
+        .keys()
+        .filter(|id| !new_by_id.contains_key(*id))
+        .copied()
+        .collect();
+    added.sort_unstable();
+    modified.sort_unstable();
+    removed.sort_unstable();
+
+    println!("{}", "Changelog".bold());
+    if added.is_empty() && modified.is_empty() && removed.is_empty() {
+        println!("  No changes.");
+    } else {
+        for id in &added {
+            println!("  {} {}", "+".green(), id);
+        }
+        for id in &modified {
+            println!("  {} {}", "~".yellow(), id);
+        }
+        for id in &removed {
+            println!("  {} {}", "-".red(), id);
+        }
+    }
+
+    if dry_run {
+        println!("\nDry run: no changes applied.");
+        return Ok(());
+    }
+
+    if local_dir.is_dir() {
+        std::fs::remove_dir_all(&local_dir)?;
+    }
+    copy_dir_recursive(&upstream_dir, &local_dir)?;
+    println!(
+        "\n{} Installed {} community rules to {}",
+        "✓".green(),
+        new_rules.len(),
+        local_dir.display()
+    );
+
+    Ok(())
+}
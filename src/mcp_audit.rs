@@ -0,0 +1,207 @@
+//! Dedicated audit of MCP server configurations across a scan target.
+//!
+//! Discovers every MCP config file (settings.json, .claude.json, .mcp.json,
+//! and the other filenames the MCP-* rules already know about), parses each
+//! server entry's command/args/env/transport, and attributes the file's
+//! MCP-* rule findings back to the specific server that triggered them.
+
+use crate::analyzers::static_analysis::StaticAnalyzer;
+use crate::types::{Finding, Severity};
+use anyhow::Result;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+/// Filenames known to hold MCP server configuration, matching the
+/// `file_names` lists in `rules/official/mcp-configuration.json`.
+const MCP_CONFIG_FILENAMES: &[&str] = &[
+    "mcp.json",
+    ".mcp.json",
+    "mcp-config.json",
+    "claude_desktop_config.json",
+    "cline_mcp_settings.json",
+    "mcp_settings.json",
+    "settings.json",
+    ".claude.json",
+];
+
+/// One MCP server entry parsed out of a config file, with any MCP-specific
+/// findings attributed to it.
+#[derive(Debug, Clone, Serialize)]
+pub struct McpServerAudit {
+    pub name: String,
+    pub source: PathBuf,
+    pub command: Option<String>,
+    pub args: Vec<String>,
+    pub env: BTreeMap<String, String>,
+    pub transport: String,
+    pub findings: Vec<Finding>,
+}
+
+impl McpServerAudit {
+    /// Highest severity among this server's findings, or `None` if clean.
+    pub fn verdict(&self) -> Option<Severity> {
+        self.findings.iter().map(|f| f.severity).max()
+    }
+}
+
+/// Discover MCP config files under `path` and audit every server entry
+/// they define against the MCP-* rules.
+pub fn audit_path(path: &Path, analyzer: &StaticAnalyzer) -> Result<Vec<McpServerAudit>> {
+    let mut audits = Vec::new();
+    for config_path in discover_mcp_configs(path) {
+        audits.extend(audit_config_file(&config_path, analyzer)?);
+    }
+    Ok(audits)
+}
+
+fn discover_mcp_configs(path: &Path) -> Vec<PathBuf> {
+    if path.is_file() {
+        return if is_mcp_config_filename(path) {
+            vec![path.to_path_buf()]
+        } else {
+            Vec::new()
+        };
+    }
+
+    WalkDir::new(path)
+        .follow_links(true)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .map(|e| e.into_path())
+        .filter(|p| p.is_file() && is_mcp_config_filename(p))
+        .collect()
+}
+
+fn is_mcp_config_filename(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|n| n.to_str())
+        .map(|n| MCP_CONFIG_FILENAMES.contains(&n))
+        .unwrap_or(false)
+}
+
+/// Parse a single config file's `mcpServers` block and run the MCP-* rules
+/// against it. Returns no audits if the file isn't valid JSON or has no
+/// `mcpServers` table (e.g. a `settings.json` with only hook config).
+fn audit_config_file(path: &Path, analyzer: &StaticAnalyzer) -> Result<Vec<McpServerAudit>> {
+    let content = std::fs::read_to_string(path)?;
+    let value: serde_json::Value = match serde_json::from_str(&content) {
+        Ok(v) => v,
+        Err(_) => return Ok(Vec::new()),
+    };
+
+    let servers = match value.get("mcpServers").and_then(|v| v.as_object()) {
+        Some(s) => s,
+        None => return Ok(Vec::new()),
+    };
+
+    let scan_result = analyzer.scan_file(path)?;
+    let mcp_servers_pos = content.find("\"mcpServers\"").unwrap_or(0);
+
+    let mut audits = Vec::with_capacity(servers.len());
+    for (name, entry) in servers {
+        let command = entry
+            .get("command")
+            .and_then(|v| v.as_str())
+            .map(String::from);
+        let args: Vec<String> = entry
+            .get("args")
+            .and_then(|v| v.as_array())
+            .map(|a| {
+                a.iter()
+                    .filter_map(|v| v.as_str())
+                    .map(String::from)
+                    .collect()
+            })
+            .unwrap_or_default();
+        let env: BTreeMap<String, String> = entry
+            .get("env")
+            .and_then(|v| v.as_object())
+            .map(|m| {
+                m.iter()
+                    .filter_map(|(k, v)| v.as_str().map(|s| (k.clone(), s.to_string())))
+                    .collect()
+            })
+            .unwrap_or_default();
+        let transport = entry
+            .get("transport")
+            .or_else(|| entry.get("type"))
+            .and_then(|v| v.as_str())
+            .map(String::from)
+            .unwrap_or_else(|| {
+                if entry.get("url").is_some() {
+                    "sse".to_string()
+                } else {
+                    "stdio".to_string()
+                }
+            });
+
+        let server_lines = find_server_line_range(&content, mcp_servers_pos, name);
+        let findings = scan_result
+            .findings
+            .iter()
+            .filter(|f| match server_lines {
+                Some((start, end)) => {
+                    f.location.start_line >= start && f.location.start_line <= end
+                }
+                None => false,
+            })
+            .cloned()
+            .collect();
+
+        audits.push(McpServerAudit {
+            name: name.clone(),
+            source: path.to_path_buf(),
+            command,
+            args,
+            env,
+            transport,
+            findings,
+        });
+    }
+
+    Ok(audits)
+}
+
+/// Locate the `(start_line, end_line)` span (1-indexed, inclusive) of a
+/// server's `{ ... }` block within the raw file text, searched from
+/// `search_from` (the byte offset of the `"mcpServers"` key) so a server
+/// name that happens to also appear as a value elsewhere in the file isn't
+/// mistaken for its key. Rules match on raw JSON text rather than parsed
+/// structure, so mapping a finding back to its server requires this rather
+/// than trusting `serde_json::Value`'s (unordered) map.
+fn find_server_line_range(content: &str, search_from: usize, name: &str) -> Option<(usize, usize)> {
+    let haystack = &content[search_from..];
+    let key = format!("\"{}\"", name);
+    let key_pos = haystack.find(&key)?;
+    let after_key = &haystack[key_pos + key.len()..];
+    let colon_pos = after_key.find(':')?;
+    let after_colon = &after_key[colon_pos + 1..];
+    let brace_rel = after_colon.find('{')?;
+    let brace_start = search_from + key_pos + key.len() + colon_pos + 1 + brace_rel;
+
+    let mut depth = 0i32;
+    for (i, ch) in content[brace_start..].char_indices() {
+        match ch {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    let brace_end = brace_start + i;
+                    return Some((
+                        line_of_offset(content, brace_start),
+                        line_of_offset(content, brace_end),
+                    ));
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// 1-indexed line number containing byte offset `pos`.
+fn line_of_offset(content: &str, pos: usize) -> usize {
+    content[..pos].matches('\n').count() + 1
+}
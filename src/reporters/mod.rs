@@ -15,6 +15,11 @@ pub enum OutputFormat {
     Json,
     Sarif,
     Markdown,
+    Html,
+    CycloneDx,
+    Jsonl,
+    GitHub,
+    Summary,
 }
 
 impl std::str::FromStr for OutputFormat {
@@ -26,23 +31,98 @@ impl std::str::FromStr for OutputFormat {
             "json" => Ok(OutputFormat::Json),
             "sarif" => Ok(OutputFormat::Sarif),
             "md" | "markdown" => Ok(OutputFormat::Markdown),
+            "html" => Ok(OutputFormat::Html),
+            "cyclonedx" | "cyclone-dx" | "sbom" => Ok(OutputFormat::CycloneDx),
+            "jsonl" | "ndjson" => Ok(OutputFormat::Jsonl),
+            "github" | "github-actions" | "gha" => Ok(OutputFormat::GitHub),
+            "summary" | "exec-summary" => Ok(OutputFormat::Summary),
             _ => Err(format!("Unknown output format: {}", s)),
         }
     }
 }
 
+/// How the CLI text reporter's "Detailed Findings" section aggregates
+/// findings. Only consumed by `OutputFormat::Cli` — other formats always
+/// carry every finding in full (JSON/SARIF/etc.) so grouping would just
+/// throw information away.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GroupBy {
+    /// One section per file/component (original behavior).
+    #[default]
+    File,
+    /// One section per rule, with a single occurrence count and a list of
+    /// affected locations instead of a full finding block per hit.
+    Rule,
+    /// One section per severity level.
+    Severity,
+}
+
+impl std::str::FromStr for GroupBy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "file" => Ok(GroupBy::File),
+            "rule" => Ok(GroupBy::Rule),
+            "severity" => Ok(GroupBy::Severity),
+            _ => Err(format!("Unknown group-by mode: {}", s)),
+        }
+    }
+}
+
 /// Report the scan results in the specified format.
-pub fn report<W: Write>(report: &ScanReport, format: OutputFormat, writer: &mut W) -> Result<()> {
+///
+/// `show_attack_matrix` opts into an extra MITRE ATT&CK/ATLAS technique
+/// coverage matrix section (CLI and Markdown only — JSON/SARIF already
+/// carry per-finding technique IDs unconditionally). `group_by` controls how
+/// the CLI text reporter's detailed findings are aggregated; other formats
+/// ignore it.
+pub fn report<W: Write>(
+    report: &ScanReport,
+    format: OutputFormat,
+    show_attack_matrix: bool,
+    group_by: GroupBy,
+    writer: &mut W,
+) -> Result<()> {
     match format {
-        OutputFormat::Cli => report_cli(report, writer),
+        OutputFormat::Cli => report_cli(report, show_attack_matrix, group_by, writer),
         OutputFormat::Json => report_json(report, writer),
         OutputFormat::Sarif => report_sarif(report, writer),
-        OutputFormat::Markdown => report_markdown(report, writer),
+        OutputFormat::Markdown => report_markdown(report, show_attack_matrix, writer),
+        OutputFormat::Html => report_html(report, writer),
+        OutputFormat::CycloneDx => report_cyclonedx(report, writer),
+        OutputFormat::Jsonl => report_jsonl(report, writer),
+        OutputFormat::GitHub => report_github(report, writer),
+        OutputFormat::Summary => report_summary(report, writer),
     }
 }
 
+/// Render the scan report through a user-supplied Tera template (`--template
+/// path.tmpl`), bypassing `-f`/`OutputFormat` entirely. The full
+/// `ScanReport` — same shape as `-f json` — is exposed as the template
+/// context, so a template can loop over `results`, `results[].findings`,
+/// etc. without vexscan needing to know the desired layout up front.
+pub fn report_template<W: Write>(
+    report: &ScanReport,
+    template_path: &std::path::Path,
+    writer: &mut W,
+) -> Result<()> {
+    let template_source = std::fs::read_to_string(template_path).map_err(|e| {
+        anyhow::anyhow!("failed to read template {}: {}", template_path.display(), e)
+    })?;
+    let context = tera::Context::from_serialize(report)?;
+    let rendered = tera::Tera::one_off(&template_source, &context, false)?;
+    write!(writer, "{}", rendered)?;
+    Ok(())
+}
+
 /// CLI-formatted output with colors.
-fn report_cli<W: Write>(report: &ScanReport, writer: &mut W) -> Result<()> {
+fn report_cli<W: Write>(
+    report: &ScanReport,
+    show_attack_matrix: bool,
+    group_by: GroupBy,
+    writer: &mut W,
+) -> Result<()> {
     writeln!(writer)?;
     writeln!(
         writer,
@@ -113,6 +193,7 @@ fn report_cli<W: Write>(report: &ScanReport, writer: &mut W) -> Result<()> {
             .to_string(),
     };
     writeln!(writer, "  Risk score:   {}", risk_colored)?;
+    writeln!(writer, "  Grade:        {}", report.grade)?;
     writeln!(writer, "  Scan time:    {}ms", report.total_time_ms)?;
 
     // Component summary
@@ -148,6 +229,22 @@ fn report_cli<W: Write>(report: &ScanReport, writer: &mut W) -> Result<()> {
     }
     writeln!(writer)?;
 
+    // Per-component risk scores
+    if !report.components.is_empty() && !report.component_risk_scores.is_empty() {
+        writeln!(writer, "{}", "Component Risk Scores".bold().underline())?;
+        for (comp, score) in report.components.iter().zip(&report.component_risk_scores) {
+            writeln!(
+                writer,
+                "  {} ({}): {}/100 ({})",
+                comp.name,
+                comp.kind,
+                score,
+                ScanReport::risk_label(*score)
+            )?;
+        }
+        writeln!(writer)?;
+    }
+
     // Findings by severity
     let counts = report.findings_count_by_severity();
     writeln!(writer, "{}", "Findings by Severity".bold().underline())?;
@@ -183,79 +280,165 @@ fn report_cli<W: Write>(report: &ScanReport, writer: &mut W) -> Result<()> {
     )?;
     writeln!(writer)?;
 
+    // Findings by CWE / OWASP LLM Top 10, for compliance-oriented consumers
+    let cwe_counts = report.findings_count_by_cwe();
+    if !cwe_counts.is_empty() {
+        writeln!(writer, "{}", "Findings by CWE".bold().underline())?;
+        let mut cwe_counts: Vec<_> = cwe_counts.into_iter().collect();
+        cwe_counts.sort_by(|a, b| a.0.cmp(&b.0));
+        for (cwe, count) in cwe_counts {
+            writeln!(writer, "  {}: {}", cwe, count)?;
+        }
+        writeln!(writer)?;
+    }
+
+    let owasp_counts = report.findings_count_by_owasp_llm();
+    if !owasp_counts.is_empty() {
+        writeln!(
+            writer,
+            "{}",
+            "Findings by OWASP LLM Top 10".bold().underline()
+        )?;
+        let mut owasp_counts: Vec<_> = owasp_counts.into_iter().collect();
+        owasp_counts.sort_by(|a, b| a.0.cmp(&b.0));
+        for (category, count) in owasp_counts {
+            writeln!(writer, "  {}: {}", category, count)?;
+        }
+        writeln!(writer)?;
+    }
+
+    if show_attack_matrix {
+        let attack_counts = report.findings_count_by_attack_technique();
+        writeln!(
+            writer,
+            "{}",
+            "ATT&CK/ATLAS Coverage Matrix".bold().underline()
+        )?;
+        if attack_counts.is_empty() {
+            writeln!(writer, "  (no findings map to a known technique)")?;
+        } else {
+            let mut attack_counts: Vec<_> = attack_counts.into_iter().collect();
+            attack_counts.sort_by(|a, b| a.0.cmp(&b.0));
+            for (technique, count) in attack_counts {
+                writeln!(writer, "  {}: {}", technique, count)?;
+            }
+        }
+        writeln!(writer)?;
+    }
+
     // Detailed findings
     if report.total_findings() > 0 {
         writeln!(writer, "{}", "Detailed Findings".bold().underline())?;
         writeln!(writer)?;
 
-        if report.components.is_empty() {
-            // No components detected — flat output (original behavior)
-            for result in &report.results {
-                if result.findings.is_empty() {
-                    continue;
-                }
-                write_result_findings(result, &report.scan_root, writer)?;
-            }
-        } else {
-            // Group findings by component
-            for (comp_idx, comp) in report.components.iter().enumerate() {
-                let comp_results: Vec<&ScanResult> = report
-                    .results
-                    .iter()
-                    .filter(|r| r.component_idx == Some(comp_idx) && !r.findings.is_empty())
-                    .collect();
-
-                if comp_results.is_empty() {
-                    continue;
-                }
+        match group_by {
+            GroupBy::Rule => write_findings_grouped_by_rule(report, writer)?,
+            GroupBy::Severity => write_findings_grouped_by_severity(report, writer)?,
+            GroupBy::File => {
+                if report.components.is_empty() {
+                    // No components detected — flat output (original behavior)
+                    for result in &report.results {
+                        if result.findings.is_empty() {
+                            continue;
+                        }
+                        write_result_findings(result, &report.scan_root, writer)?;
+                    }
+                } else {
+                    // Group findings by component
+                    for (comp_idx, comp) in report.components.iter().enumerate() {
+                        let comp_results: Vec<&ScanResult> = report
+                            .results
+                            .iter()
+                            .filter(|r| r.component_idx == Some(comp_idx) && !r.findings.is_empty())
+                            .collect();
 
-                let file_count = report
-                    .results
-                    .iter()
-                    .filter(|r| r.component_idx == Some(comp_idx))
-                    .count();
-                let finding_count: usize = comp_results.iter().map(|r| r.findings.len()).sum();
-                let comp_max_sev = comp_results.iter().filter_map(|r| r.max_severity()).max();
-                let risk_tag = comp_max_sev
-                    .map(|s| format!("{}", s).to_uppercase())
-                    .unwrap_or_else(|| "CLEAN".to_string());
-
-                write_component_header(comp, file_count, finding_count, &risk_tag, writer)?;
-
-                let rel_root = comp
-                    .root
-                    .strip_prefix(&report.scan_root)
-                    .unwrap_or(&comp.root);
-                writeln!(writer, "   Path: {}/", rel_root.display())?;
-                writeln!(writer)?;
+                        if comp_results.is_empty() {
+                            continue;
+                        }
 
-                for result in comp_results {
-                    write_result_findings(result, &comp.root, writer)?;
-                }
-            }
+                        let file_count = report
+                            .results
+                            .iter()
+                            .filter(|r| r.component_idx == Some(comp_idx))
+                            .count();
+                        let finding_count: usize =
+                            comp_results.iter().map(|r| r.findings.len()).sum();
+                        let comp_max_sev =
+                            comp_results.iter().filter_map(|r| r.max_severity()).max();
+                        let risk_tag = comp_max_sev
+                            .map(|s| format!("{}", s).to_uppercase())
+                            .unwrap_or_else(|| "CLEAN".to_string());
 
-            // "Other files" bucket
-            let other_results: Vec<&ScanResult> = report
-                .results
-                .iter()
-                .filter(|r| r.component_idx.is_none() && !r.findings.is_empty())
-                .collect();
+                        write_component_header(comp, file_count, finding_count, &risk_tag, writer)?;
 
-            if !other_results.is_empty() {
-                writeln!(
-                    writer,
-                    "{}",
-                    "── Other files ─────────────────────────────────".bright_black()
-                )?;
-                writeln!(writer)?;
+                        let rel_root = comp
+                            .root
+                            .strip_prefix(&report.scan_root)
+                            .unwrap_or(&comp.root);
+                        writeln!(writer, "   Path: {}/", rel_root.display())?;
+                        writeln!(writer)?;
+
+                        for result in comp_results {
+                            write_result_findings(result, &comp.root, writer)?;
+                        }
+                    }
+
+                    // "Other files" bucket
+                    let other_results: Vec<&ScanResult> = report
+                        .results
+                        .iter()
+                        .filter(|r| r.component_idx.is_none() && !r.findings.is_empty())
+                        .collect();
 
-                for result in other_results {
-                    write_result_findings(result, &report.scan_root, writer)?;
+                    if !other_results.is_empty() {
+                        writeln!(
+                            writer,
+                            "{}",
+                            "── Other files ─────────────────────────────────".bright_black()
+                        )?;
+                        writeln!(writer)?;
+
+                        for result in other_results {
+                            write_result_findings(result, &report.scan_root, writer)?;
+                        }
+                    }
                 }
             }
         }
     }
 
+    // Suppressed findings — accepted risk, kept visible for audit
+    if report.total_suppressed() > 0 {
+        writeln!(writer, "{}", "Suppressed Findings".bold().underline())?;
+        writeln!(writer)?;
+        for (path, suppressed) in report.suppressed_findings() {
+            let rel = path.strip_prefix(&report.scan_root).unwrap_or(path);
+            let reason = suppressed
+                .suppression
+                .reason
+                .as_deref()
+                .unwrap_or("no reason given");
+            let by = suppressed
+                .suppression
+                .by
+                .as_deref()
+                .map(|by| format!(" by {}", by))
+                .unwrap_or_default();
+            writeln!(
+                writer,
+                "  {} {}:{} [{}] via {}{} — {}",
+                "◌".bright_black(),
+                rel.display(),
+                suppressed.finding.location.start_line,
+                suppressed.finding.rule_id,
+                suppressed.suppression.mechanism,
+                by,
+                reason
+            )?;
+        }
+        writeln!(writer)?;
+    }
+
     // Exit status indicator
     writeln!(writer)?;
     if let Some(max_sev) = report.max_severity() {
@@ -371,8 +554,8 @@ fn write_result_findings<W: Write>(
         writeln!(writer)?;
         writeln!(
             writer,
-            "     {} [{}]{}",
-            severity_indicator, finding.rule_id, scope_tag
+            "     {} [{}] ({} confidence){}",
+            severity_indicator, finding.rule_id, finding.confidence, scope_tag
         )?;
         writeln!(writer, "     {}", finding.title.bold())?;
         writeln!(
@@ -393,17 +576,327 @@ fn write_result_findings<W: Write>(
         if let Some(ref remediation) = finding.remediation {
             writeln!(writer, "     Fix: {}", remediation.green())?;
         }
+
+        if !finding.cwe.is_empty()
+            || !finding.owasp_llm.is_empty()
+            || !finding.attack_technique.is_empty()
+        {
+            let mut tags = finding.cwe.clone();
+            tags.extend(finding.owasp_llm.clone());
+            tags.extend(finding.attack_technique.clone());
+            writeln!(writer, "     {}", tags.join(", ").dimmed())?;
+        }
     }
     writeln!(writer)?;
     Ok(())
 }
 
+/// Write detailed findings collapsed into one section per rule, with an
+/// occurrence count instead of a full finding block per hit — useful when
+/// the same rule fires hundreds of times across a tree.
+struct RuleGroup<'a> {
+    rule_id: &'a str,
+    title: &'a str,
+    severity: Severity,
+    locations: Vec<(&'a std::path::Path, usize)>,
+}
+
+/// Group every finding in `report` by rule ID, sorted by severity (highest
+/// first) then occurrence count (most first). Shared by `--group-by rule`
+/// and the `summary` report's "Top Findings" section.
+fn compute_rule_groups(report: &ScanReport) -> Vec<RuleGroup<'_>> {
+    let mut groups: std::collections::HashMap<&str, RuleGroup> = std::collections::HashMap::new();
+    for result in &report.results {
+        for finding in &result.findings {
+            let group = groups
+                .entry(finding.rule_id.as_str())
+                .or_insert_with(|| RuleGroup {
+                    rule_id: &finding.rule_id,
+                    title: &finding.title,
+                    severity: finding.severity,
+                    locations: Vec::new(),
+                });
+            group
+                .locations
+                .push((&result.path, finding.location.start_line));
+        }
+    }
+
+    let mut groups: Vec<RuleGroup> = groups.into_values().collect();
+    groups.sort_by(|a, b| {
+        b.severity
+            .cmp(&a.severity)
+            .then_with(|| b.locations.len().cmp(&a.locations.len()))
+            .then_with(|| a.rule_id.cmp(b.rule_id))
+    });
+    groups
+}
+
+fn write_findings_grouped_by_rule<W: Write>(report: &ScanReport, writer: &mut W) -> Result<()> {
+    for group in compute_rule_groups(report) {
+        let severity_indicator = match group.severity {
+            Severity::Critical => "▲ CRITICAL".bright_red().bold(),
+            Severity::High => "▲ HIGH".red().bold(),
+            Severity::Medium => "● MEDIUM".yellow().bold(),
+            Severity::Low => "● LOW".blue(),
+            Severity::Info => "○ INFO".white(),
+        };
+        writeln!(
+            writer,
+            "{} [{}] {} ({} occurrence{})",
+            severity_indicator,
+            group.rule_id,
+            group.title.bold(),
+            group.locations.len(),
+            if group.locations.len() == 1 { "" } else { "s" }
+        )?;
+        for (path, line) in &group.locations {
+            let display_path = path.strip_prefix(&report.scan_root).unwrap_or(path);
+            writeln!(writer, "     {}:{}", display_path.display(), line)?;
+        }
+        writeln!(writer)?;
+    }
+    Ok(())
+}
+
+/// Write detailed findings bucketed by severity level instead of by file.
+fn write_findings_grouped_by_severity<W: Write>(report: &ScanReport, writer: &mut W) -> Result<()> {
+    for severity in [
+        Severity::Critical,
+        Severity::High,
+        Severity::Medium,
+        Severity::Low,
+        Severity::Info,
+    ] {
+        let findings: Vec<(&std::path::Path, &crate::types::Finding)> = report
+            .results
+            .iter()
+            .flat_map(|r| r.findings.iter().map(move |f| (r.path.as_path(), f)))
+            .filter(|(_, f)| f.severity == severity)
+            .collect();
+
+        if findings.is_empty() {
+            continue;
+        }
+
+        let severity_indicator = match severity {
+            Severity::Critical => "▲ CRITICAL".bright_red().bold(),
+            Severity::High => "▲ HIGH".red().bold(),
+            Severity::Medium => "● MEDIUM".yellow().bold(),
+            Severity::Low => "● LOW".blue(),
+            Severity::Info => "○ INFO".white(),
+        };
+        writeln!(
+            writer,
+            "{} ({} finding{})",
+            severity_indicator,
+            findings.len(),
+            if findings.len() == 1 { "" } else { "s" }
+        )?;
+        for (path, finding) in findings {
+            let display_path = path.strip_prefix(&report.scan_root).unwrap_or(path);
+            writeln!(
+                writer,
+                "     {}:{} [{}] {}",
+                display_path.display(),
+                finding.location.start_line,
+                finding.rule_id,
+                finding.title
+            )?;
+        }
+        writeln!(writer)?;
+    }
+    Ok(())
+}
+
 /// JSON output format.
 fn report_json<W: Write>(report: &ScanReport, writer: &mut W) -> Result<()> {
     serde_json::to_writer_pretty(writer, report)?;
     Ok(())
 }
 
+/// JSON Lines: one finding per line, prefixed with the file path it was
+/// found in. Suited for piping into log collectors that ingest newline-
+/// delimited JSON, and for very large scans where a reader may want to
+/// start processing findings before the whole report has arrived.
+fn report_jsonl<W: Write>(report: &ScanReport, writer: &mut W) -> Result<()> {
+    for result in &report.results {
+        for finding in &result.findings {
+            serde_json::to_writer(&mut *writer, finding)?;
+            writeln!(writer)?;
+        }
+    }
+    Ok(())
+}
+
+/// GitHub Actions workflow commands (`::error file=...,line=...::message`),
+/// one per finding, so results show up as inline PR annotations without any
+/// extra tooling on the Actions side. See
+/// <https://docs.github.com/actions/using-workflows/workflow-commands-for-github-actions#setting-an-error-message>.
+fn report_github<W: Write>(report: &ScanReport, writer: &mut W) -> Result<()> {
+    for result in &report.results {
+        let display_path = result
+            .path
+            .strip_prefix(&report.scan_root)
+            .unwrap_or(&result.path);
+        for finding in &result.findings {
+            let level = match finding.severity {
+                Severity::Critical | Severity::High => "error",
+                Severity::Medium | Severity::Low => "warning",
+                Severity::Info => "notice",
+            };
+            writeln!(
+                writer,
+                "::{} file={},line={},endLine={},title={}::{}",
+                level,
+                github_escape_property(&display_path.display().to_string()),
+                finding.location.start_line,
+                finding.location.end_line,
+                github_escape_property(&format!("[{}] {}", finding.rule_id, finding.title)),
+                github_escape_data(&finding.description),
+            )?;
+        }
+    }
+    Ok(())
+}
+
+/// Escape a GitHub Actions workflow command's free-text data (the part
+/// after the final `::`).
+fn github_escape_data(s: &str) -> String {
+    s.replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+/// Escape a GitHub Actions workflow command property value (`key=value`
+/// pairs before the final `::`) — same as data, plus `:` and `,` since
+/// those delimit properties.
+fn github_escape_property(s: &str) -> String {
+    github_escape_data(s)
+        .replace(':', "%3A")
+        .replace(',', "%2C")
+}
+
+/// One-page, print-friendly executive summary: overall risk score, a
+/// severity-weighted breakdown by component type, and the top findings by
+/// occurrence count — no per-location listings, so it stays a single page
+/// for security leads who don't want to wade through thousands of raw hits.
+fn report_summary<W: Write>(report: &ScanReport, writer: &mut W) -> Result<()> {
+    writeln!(writer)?;
+    writeln!(writer, "{}", "Executive Summary".bold().underline())?;
+    writeln!(writer)?;
+    writeln!(writer, "  Scan root:    {}", report.scan_root.display())?;
+    if let Some(platform) = &report.platform {
+        writeln!(writer, "  Platform:     {}", platform)?;
+    }
+    writeln!(writer, "  Files scanned: {}", report.results.len())?;
+    writeln!(writer, "  Total findings: {}", report.total_findings())?;
+
+    let risk_label = ScanReport::risk_label(report.risk_score);
+    let risk_colored = match report.risk_score {
+        0 => format!("{}/100 ({})", report.risk_score, risk_label)
+            .green()
+            .bold()
+            .to_string(),
+        1..=25 => format!("{}/100 ({})", report.risk_score, risk_label)
+            .blue()
+            .to_string(),
+        26..=50 => format!("{}/100 ({})", report.risk_score, risk_label)
+            .yellow()
+            .to_string(),
+        51..=75 => format!("{}/100 ({})", report.risk_score, risk_label)
+            .red()
+            .to_string(),
+        _ => format!("{}/100 ({})", report.risk_score, risk_label)
+            .bright_red()
+            .bold()
+            .to_string(),
+    };
+    writeln!(
+        writer,
+        "  Risk score:   {} (Grade {})",
+        risk_colored, report.grade
+    )?;
+    writeln!(writer)?;
+
+    writeln!(writer, "{}", "Findings by Severity".bold().underline())?;
+    let counts = report.findings_count_by_severity();
+    for severity in [
+        Severity::Critical,
+        Severity::High,
+        Severity::Medium,
+        Severity::Low,
+        Severity::Info,
+    ] {
+        let count = counts.get(&severity).copied().unwrap_or(0);
+        if count == 0 {
+            continue;
+        }
+        let label = match severity {
+            Severity::Critical => "CRITICAL".bright_red().bold(),
+            Severity::High => "HIGH".red().bold(),
+            Severity::Medium => "MEDIUM".yellow().bold(),
+            Severity::Low => "LOW".blue(),
+            Severity::Info => "INFO".white(),
+        };
+        writeln!(writer, "  {:<10} {}", label, count)?;
+    }
+    writeln!(writer)?;
+
+    if !report.components.is_empty() && !report.component_risk_scores.is_empty() {
+        writeln!(writer, "{}", "Risk by Component Type".bold().underline())?;
+        let mut by_kind: std::collections::HashMap<ComponentKind, Vec<u8>> =
+            std::collections::HashMap::new();
+        for (component, score) in report.components.iter().zip(&report.component_risk_scores) {
+            by_kind.entry(component.kind).or_default().push(*score);
+        }
+        let mut kinds: Vec<(ComponentKind, Vec<u8>)> = by_kind.into_iter().collect();
+        kinds.sort_by(|a, b| {
+            let max_a = a.1.iter().copied().max().unwrap_or(0);
+            let max_b = b.1.iter().copied().max().unwrap_or(0);
+            max_b
+                .cmp(&max_a)
+                .then_with(|| a.0.to_string().cmp(&b.0.to_string()))
+        });
+        for (kind, scores) in kinds {
+            let max = scores.iter().copied().max().unwrap_or(0);
+            let avg = scores.iter().map(|&s| s as u32).sum::<u32>() / scores.len() as u32;
+            writeln!(
+                writer,
+                "  {:<12} {} component{} — max {}/100, avg {}/100",
+                kind.to_string(),
+                scores.len(),
+                if scores.len() == 1 { "" } else { "s" },
+                max,
+                avg
+            )?;
+        }
+        writeln!(writer)?;
+    }
+
+    writeln!(writer, "{}", "Top Findings".bold().underline())?;
+    for group in compute_rule_groups(report).into_iter().take(10) {
+        let severity_indicator = match group.severity {
+            Severity::Critical => "▲ CRITICAL".bright_red().bold(),
+            Severity::High => "▲ HIGH".red().bold(),
+            Severity::Medium => "● MEDIUM".yellow().bold(),
+            Severity::Low => "● LOW".blue(),
+            Severity::Info => "○ INFO".white(),
+        };
+        writeln!(
+            writer,
+            "  {} [{}] {} ({} occurrence{})",
+            severity_indicator,
+            group.rule_id,
+            group.title.bold(),
+            group.locations.len(),
+            if group.locations.len() == 1 { "" } else { "s" }
+        )?;
+    }
+
+    Ok(())
+}
+
 /// SARIF format for GitHub integration.
 fn report_sarif<W: Write>(report: &ScanReport, writer: &mut W) -> Result<()> {
     let sarif = serde_json::json!({
@@ -468,6 +961,14 @@ fn collect_sarif_results(report: &ScanReport) -> Vec<serde_json::Value> {
                 region["endColumn"] = serde_json::json!(col);
             }
 
+            let mut tags: Vec<String> = finding
+                .cwe
+                .iter()
+                .map(|cwe| format!("external/cwe/{}", cwe.to_lowercase()))
+                .collect();
+            tags.extend(finding.owasp_llm.iter().cloned());
+            tags.extend(finding.attack_technique.iter().cloned());
+
             results.push(serde_json::json!({
                 "ruleId": finding.rule_id,
                 "level": severity_to_sarif_level(finding.severity),
@@ -485,7 +986,10 @@ fn collect_sarif_results(report: &ScanReport) -> Vec<serde_json::Value> {
                         },
                         "region": region
                     }
-                }]
+                }],
+                "properties": {
+                    "tags": tags
+                }
             }));
         }
     }
@@ -501,8 +1005,83 @@ fn severity_to_sarif_level(severity: Severity) -> &'static str {
     }
 }
 
+/// CycloneDX 1.5 SBOM listing every scanned file with an adapter-detected
+/// `ComponentType` (plugins, MCP server configs, hooks, prompts, memory,
+/// config) — the agent's attack surface, for inventory/compliance tooling
+/// that consumes CycloneDX.
+fn report_cyclonedx<W: Write>(report: &ScanReport, writer: &mut W) -> Result<()> {
+    let components: Vec<serde_json::Value> = report
+        .results
+        .iter()
+        .filter(|r| r.component_type.is_some())
+        .map(|r| {
+            let display_path = r
+                .path
+                .strip_prefix(&report.scan_root)
+                .unwrap_or(&r.path)
+                .display()
+                .to_string();
+
+            let mut properties = vec![serde_json::json!({
+                "name": "vexscan:componentType",
+                "value": r.component_type.unwrap().to_string()
+            })];
+            if let Some(scope) = r.install_scope {
+                properties.push(serde_json::json!({
+                    "name": "vexscan:installScope",
+                    "value": scope.to_string()
+                }));
+            }
+            properties.push(serde_json::json!({
+                "name": "vexscan:findingCount",
+                "value": r.findings.len().to_string()
+            }));
+
+            let mut component = serde_json::json!({
+                "type": "file",
+                "bom-ref": display_path,
+                "name": display_path,
+                "properties": properties,
+            });
+            if let Some(ref hash) = r.content_hash {
+                component["hashes"] = serde_json::json!([{
+                    "alg": "SHA-256",
+                    "content": hash
+                }]);
+            }
+            component
+        })
+        .collect();
+
+    let sbom = serde_json::json!({
+        "bomFormat": "CycloneDX",
+        "specVersion": "1.5",
+        "version": 1,
+        "metadata": {
+            "timestamp": report.timestamp.to_rfc3339(),
+            "tools": [{
+                "vendor": "vexscan",
+                "name": "vexscan",
+                "version": env!("CARGO_PKG_VERSION")
+            }],
+            "component": {
+                "type": "application",
+                "name": report.scan_root.display().to_string()
+            }
+        },
+        "components": components
+    });
+
+    serde_json::to_writer_pretty(writer, &sbom)?;
+    Ok(())
+}
+
 /// Markdown output format.
-fn report_markdown<W: Write>(report: &ScanReport, writer: &mut W) -> Result<()> {
+fn report_markdown<W: Write>(
+    report: &ScanReport,
+    show_attack_matrix: bool,
+    writer: &mut W,
+) -> Result<()> {
     writeln!(writer, "# Agent Security Scan Report")?;
     writeln!(writer)?;
     writeln!(writer, "## Summary")?;
@@ -537,9 +1116,28 @@ fn report_markdown<W: Write>(report: &ScanReport, writer: &mut W) -> Result<()>
         report.risk_score,
         ScanReport::risk_label(report.risk_score)
     )?;
+    writeln!(writer, "| Grade | {} |", report.grade)?;
     writeln!(writer, "| Scan Time | {}ms |", report.total_time_ms)?;
     writeln!(writer)?;
 
+    if !report.components.is_empty() && !report.component_risk_scores.is_empty() {
+        writeln!(writer, "## Component Risk Scores")?;
+        writeln!(writer)?;
+        writeln!(writer, "| Component | Kind | Score | Risk |")?;
+        writeln!(writer, "|-----------|------|-------|------|")?;
+        for (comp, score) in report.components.iter().zip(&report.component_risk_scores) {
+            writeln!(
+                writer,
+                "| {} | {} | {}/100 | {} |",
+                comp.name,
+                comp.kind,
+                score,
+                ScanReport::risk_label(*score)
+            )?;
+        }
+        writeln!(writer)?;
+    }
+
     let counts = report.findings_count_by_severity();
     writeln!(writer, "## Findings by Severity")?;
     writeln!(writer)?;
@@ -570,6 +1168,46 @@ fn report_markdown<W: Write>(report: &ScanReport, writer: &mut W) -> Result<()>
     )?;
     writeln!(writer)?;
 
+    let cwe_counts = report.findings_count_by_cwe();
+    if !cwe_counts.is_empty() {
+        writeln!(writer, "## Findings by CWE")?;
+        writeln!(writer)?;
+        let mut cwe_counts: Vec<_> = cwe_counts.into_iter().collect();
+        cwe_counts.sort_by(|a, b| a.0.cmp(&b.0));
+        for (cwe, count) in cwe_counts {
+            writeln!(writer, "- {}: {}", cwe, count)?;
+        }
+        writeln!(writer)?;
+    }
+
+    let owasp_counts = report.findings_count_by_owasp_llm();
+    if !owasp_counts.is_empty() {
+        writeln!(writer, "## Findings by OWASP LLM Top 10")?;
+        writeln!(writer)?;
+        let mut owasp_counts: Vec<_> = owasp_counts.into_iter().collect();
+        owasp_counts.sort_by(|a, b| a.0.cmp(&b.0));
+        for (category, count) in owasp_counts {
+            writeln!(writer, "- {}: {}", category, count)?;
+        }
+        writeln!(writer)?;
+    }
+
+    if show_attack_matrix {
+        writeln!(writer, "## ATT&CK/ATLAS Coverage Matrix")?;
+        writeln!(writer)?;
+        let attack_counts = report.findings_count_by_attack_technique();
+        if attack_counts.is_empty() {
+            writeln!(writer, "No findings map to a known technique.")?;
+        } else {
+            let mut attack_counts: Vec<_> = attack_counts.into_iter().collect();
+            attack_counts.sort_by(|a, b| a.0.cmp(&b.0));
+            for (technique, count) in attack_counts {
+                writeln!(writer, "- {}: {}", technique, count)?;
+            }
+        }
+        writeln!(writer)?;
+    }
+
     if report.total_findings() > 0 {
         writeln!(writer, "## Detailed Findings")?;
         writeln!(writer)?;
@@ -602,6 +1240,7 @@ fn report_markdown<W: Write>(report: &ScanReport, writer: &mut W) -> Result<()>
                     "**Location:** Line {}-{}",
                     finding.location.start_line, finding.location.end_line
                 )?;
+                writeln!(writer, "**Confidence:** {}", finding.confidence)?;
                 writeln!(writer)?;
                 writeln!(writer, "{}", finding.description)?;
                 writeln!(writer)?;
@@ -614,9 +1253,309 @@ fn report_markdown<W: Write>(report: &ScanReport, writer: &mut W) -> Result<()>
                     writeln!(writer, "**Remediation:** {}", remediation)?;
                     writeln!(writer)?;
                 }
+
+                if !finding.cwe.is_empty() {
+                    writeln!(writer, "**CWE:** {}", finding.cwe.join(", "))?;
+                    writeln!(writer)?;
+                }
+                if !finding.owasp_llm.is_empty() {
+                    writeln!(
+                        writer,
+                        "**OWASP LLM Top 10:** {}",
+                        finding.owasp_llm.join(", ")
+                    )?;
+                    writeln!(writer)?;
+                }
+                if !finding.attack_technique.is_empty() {
+                    writeln!(
+                        writer,
+                        "**ATT&CK/ATLAS:** {}",
+                        finding.attack_technique.join(", ")
+                    )?;
+                    writeln!(writer)?;
+                }
             }
         }
     }
 
+    if report.total_suppressed() > 0 {
+        writeln!(writer, "## Suppressed Findings")?;
+        writeln!(writer)?;
+        writeln!(writer, "| File | Line | Rule | Mechanism | By | Reason |")?;
+        writeln!(writer, "|------|------|------|-----------|----|---|")?;
+        for (path, suppressed) in report.suppressed_findings() {
+            let rel = path.strip_prefix(&report.scan_root).unwrap_or(path);
+            writeln!(
+                writer,
+                "| `{}` | {} | {} | {} | {} | {} |",
+                rel.display(),
+                suppressed.finding.location.start_line,
+                suppressed.finding.rule_id,
+                suppressed.suppression.mechanism,
+                suppressed.suppression.by.as_deref().unwrap_or("-"),
+                suppressed.suppression.reason.as_deref().unwrap_or("-")
+            )?;
+        }
+        writeln!(writer)?;
+    }
+
     Ok(())
 }
+
+/// Self-contained HTML report with client-side filtering by severity,
+/// category, rule ID, and file path, plus expandable code snippets. Rows
+/// are rendered server-side with `data-*` attributes; a small inline
+/// script toggles visibility, so the file works offline with no build
+/// step or external assets.
+fn report_html<W: Write>(report: &ScanReport, writer: &mut W) -> Result<()> {
+    writeln!(writer, "<!DOCTYPE html>")?;
+    writeln!(writer, "<html lang=\"en\">")?;
+    writeln!(writer, "<head>")?;
+    writeln!(writer, "<meta charset=\"utf-8\">")?;
+    writeln!(
+        writer,
+        "<meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">"
+    )?;
+    writeln!(writer, "<title>Vexscan Security Scan Report</title>")?;
+    writeln!(writer, "<style>{}</style>", HTML_REPORT_STYLE)?;
+    writeln!(writer, "</head>")?;
+    writeln!(writer, "<body>")?;
+    writeln!(writer, "<h1>🔒 Vexscan Security Scan Report</h1>")?;
+
+    writeln!(writer, "<h2>Summary</h2>")?;
+    writeln!(writer, "<table class=\"summary\">")?;
+    writeln!(
+        writer,
+        "<tr><th>Scan root</th><td>{}</td></tr>",
+        html_escape(&report.scan_root.display().to_string())
+    )?;
+    if let Some(ref platform) = report.platform {
+        writeln!(
+            writer,
+            "<tr><th>Platform</th><td>{}</td></tr>",
+            html_escape(&platform.to_string())
+        )?;
+    }
+    writeln!(
+        writer,
+        "<tr><th>Files scanned</th><td>{}</td></tr>",
+        report.results.len()
+    )?;
+    writeln!(
+        writer,
+        "<tr><th>Total findings</th><td>{}</td></tr>",
+        report.total_findings()
+    )?;
+    writeln!(
+        writer,
+        "<tr><th>Risk score</th><td>{}/100 ({})</td></tr>",
+        report.risk_score,
+        ScanReport::risk_label(report.risk_score)
+    )?;
+    writeln!(writer, "<tr><th>Grade</th><td>{}</td></tr>", report.grade)?;
+    writeln!(
+        writer,
+        "<tr><th>Scan time</th><td>{}ms</td></tr>",
+        report.total_time_ms
+    )?;
+    writeln!(writer, "</table>")?;
+
+    writeln!(writer, "<h2>Findings</h2>")?;
+    writeln!(writer, "<div class=\"filters\">")?;
+    writeln!(
+        writer,
+        "<label>Severity <select id=\"filter-severity\"><option value=\"\">All</option><option>critical</option><option>high</option><option>medium</option><option>low</option><option>info</option></select></label>"
+    )?;
+    writeln!(
+        writer,
+        "<label>Category <input id=\"filter-category\" type=\"text\" placeholder=\"e.g. Code Execution\"></label>"
+    )?;
+    writeln!(
+        writer,
+        "<label>Rule ID <input id=\"filter-rule\" type=\"text\" placeholder=\"e.g. EXEC-001\"></label>"
+    )?;
+    writeln!(
+        writer,
+        "<label>File path <input id=\"filter-path\" type=\"text\" placeholder=\"substring match\"></label>"
+    )?;
+    writeln!(writer, "<span id=\"filter-count\"></span>")?;
+    writeln!(writer, "</div>")?;
+
+    writeln!(writer, "<table id=\"findings-table\">")?;
+    writeln!(
+        writer,
+        "<thead><tr><th></th><th>Severity</th><th>Rule</th><th>Category</th><th>File</th><th>Line</th><th>Title</th></tr></thead>"
+    )?;
+    writeln!(writer, "<tbody>")?;
+
+    for result in &report.results {
+        if result.findings.is_empty() {
+            continue;
+        }
+        let display_path = result
+            .path
+            .strip_prefix(&report.scan_root)
+            .unwrap_or(&result.path)
+            .display()
+            .to_string();
+
+        for finding in &result.findings {
+            let severity = finding.severity.to_string();
+            let category = finding.category.to_string();
+            writeln!(
+                writer,
+                "<tr class=\"finding-row sev-{severity}\" data-severity=\"{severity}\" data-category=\"{category}\" data-rule=\"{rule}\" data-path=\"{path}\">",
+                severity = severity,
+                category = html_escape(&category),
+                rule = html_escape(&finding.rule_id),
+                path = html_escape(&display_path),
+            )?;
+            writeln!(writer, "<td><button class=\"toggle\">+</button></td>")?;
+            writeln!(
+                writer,
+                "<td class=\"badge sev-{}\">{}</td>",
+                severity, severity
+            )?;
+            writeln!(writer, "<td>{}</td>", html_escape(&finding.rule_id))?;
+            writeln!(writer, "<td>{}</td>", html_escape(&category))?;
+            writeln!(writer, "<td>{}</td>", html_escape(&display_path))?;
+            writeln!(writer, "<td>{}</td>", finding.location.start_line)?;
+            writeln!(writer, "<td>{}</td>", html_escape(&finding.title))?;
+            writeln!(writer, "</tr>")?;
+
+            writeln!(
+                writer,
+                "<tr class=\"finding-detail sev-{severity}\" data-severity=\"{severity}\" data-category=\"{category}\" data-rule=\"{rule}\" data-path=\"{path}\" hidden>",
+                severity = severity,
+                category = html_escape(&category),
+                rule = html_escape(&finding.rule_id),
+                path = html_escape(&display_path),
+            )?;
+            writeln!(writer, "<td></td>")?;
+            writeln!(writer, "<td colspan=\"6\">")?;
+            writeln!(writer, "<p>{}</p>", html_escape(&finding.description))?;
+            writeln!(
+                writer,
+                "<details><summary>Code</summary><pre>{}</pre></details>",
+                html_escape(&finding.snippet)
+            )?;
+            if let Some(ref remediation) = finding.remediation {
+                writeln!(
+                    writer,
+                    "<p><strong>Fix:</strong> {}</p>",
+                    html_escape(remediation)
+                )?;
+            }
+            if !finding.cwe.is_empty()
+                || !finding.owasp_llm.is_empty()
+                || !finding.attack_technique.is_empty()
+            {
+                let mut tags = finding.cwe.clone();
+                tags.extend(finding.owasp_llm.clone());
+                tags.extend(finding.attack_technique.clone());
+                writeln!(
+                    writer,
+                    "<p class=\"tags\">{}</p>",
+                    html_escape(&tags.join(", "))
+                )?;
+            }
+            writeln!(writer, "</td>")?;
+            writeln!(writer, "</tr>")?;
+        }
+    }
+    writeln!(writer, "</tbody>")?;
+    writeln!(writer, "</table>")?;
+    writeln!(writer, "<script>{}</script>", HTML_REPORT_SCRIPT)?;
+    writeln!(writer, "</body>")?;
+    writeln!(writer, "</html>")?;
+
+    Ok(())
+}
+
+/// Inline stylesheet for `report_html` — no external assets.
+const HTML_REPORT_STYLE: &str = r#"
+body { font-family: -apple-system, Segoe UI, Roboto, sans-serif; margin: 2rem; color: #1a1a1a; }
+h1 { font-size: 1.5rem; }
+table { border-collapse: collapse; width: 100%; margin-bottom: 1rem; }
+table.summary td, table.summary th { text-align: left; padding: 0.25rem 1rem 0.25rem 0; }
+#findings-table { font-size: 0.9rem; }
+#findings-table th, #findings-table td { text-align: left; padding: 0.4rem 0.6rem; border-bottom: 1px solid #ddd; vertical-align: top; }
+.finding-row:hover { background: #f7f7f7; }
+.filters { display: flex; flex-wrap: wrap; gap: 1rem; align-items: center; margin-bottom: 1rem; }
+.filters label { display: flex; flex-direction: column; font-size: 0.85rem; gap: 0.2rem; }
+.badge { font-weight: bold; text-transform: uppercase; font-size: 0.75rem; }
+.sev-critical { color: #b30000; }
+.sev-high { color: #d9534f; }
+.sev-medium { color: #b8860b; }
+.sev-low { color: #337ab7; }
+.sev-info { color: #777; }
+pre { white-space: pre-wrap; word-break: break-word; background: #f4f4f4; padding: 0.5rem; }
+.toggle { cursor: pointer; }
+.tags { color: #555; font-size: 0.85rem; }
+"#;
+
+/// Inline script for `report_html` — filters rows by matching every
+/// non-empty filter against each row's `data-*` attributes, and expands a
+/// finding's detail row on click of its toggle button.
+const HTML_REPORT_SCRIPT: &str = r#"
+(function () {
+  var rows = Array.prototype.slice.call(document.querySelectorAll('.finding-row'));
+  var severity = document.getElementById('filter-severity');
+  var category = document.getElementById('filter-category');
+  var rule = document.getElementById('filter-rule');
+  var path = document.getElementById('filter-path');
+  var count = document.getElementById('filter-count');
+
+  function matches(row) {
+    if (severity.value && row.dataset.severity !== severity.value) return false;
+    if (category.value && row.dataset.category.toLowerCase().indexOf(category.value.toLowerCase()) === -1) return false;
+    if (rule.value && row.dataset.rule.toLowerCase().indexOf(rule.value.toLowerCase()) === -1) return false;
+    if (path.value && row.dataset.path.toLowerCase().indexOf(path.value.toLowerCase()) === -1) return false;
+    return true;
+  }
+
+  function applyFilters() {
+    var visible = 0;
+    rows.forEach(function (row) {
+      var show = matches(row);
+      row.hidden = !show;
+      var detail = row.nextElementSibling;
+      if (detail && detail.classList.contains('finding-detail')) {
+        detail.hidden = !show || detail.dataset.collapsed !== 'false';
+        if (!show) detail.dataset.collapsed = 'true';
+      }
+      if (show) visible++;
+    });
+    count.textContent = visible + ' / ' + rows.length + ' findings';
+  }
+
+  [severity, category, rule, path].forEach(function (el) {
+    el.addEventListener('input', applyFilters);
+    el.addEventListener('change', applyFilters);
+  });
+
+  rows.forEach(function (row) {
+    var detail = row.nextElementSibling;
+    if (!detail) return;
+    detail.dataset.collapsed = 'true';
+    var button = row.querySelector('.toggle');
+    button.addEventListener('click', function () {
+      var collapsed = detail.dataset.collapsed !== 'false';
+      detail.hidden = !collapsed ? true : false;
+      detail.dataset.collapsed = collapsed ? 'false' : 'true';
+      button.textContent = collapsed ? '-' : '+';
+    });
+  });
+
+  applyFilters();
+})();
+"#;
+
+/// Escape text for safe inclusion in HTML output.
+fn html_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
@@ -0,0 +1,284 @@
+//! Interactive terminal review of scan findings, one at a time.
+//!
+//! Each finding is shown with its rule, severity, and a snippet of the
+//! surrounding code. The reviewer marks it accept (leave as-is, just move
+//! on), suppress (record it as accepted risk in the config allowlist or a
+//! baseline file — see `crate::config::SuppressionRule` and
+//! `crate::suppression::Baseline`), or fix (apply its `FixSuggestion` in
+//! place, if it has one). Decisions are written out as they're made, so an
+//! interrupted review session doesn't lose earlier progress.
+
+use crate::config::{Config, SuppressionRule};
+use crate::fixer;
+use crate::suppression::{Baseline, BaselineEntry};
+use crate::types::{Finding, ScanReport};
+use anyhow::Result;
+use crossterm::event::{self, Event, KeyCode};
+use crossterm::terminal::{
+    disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen,
+};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Paragraph, Wrap};
+use std::path::Path;
+use std::time::Duration;
+
+/// Where suppress decisions made during review are recorded.
+pub enum SuppressTarget<'a> {
+    /// Append `[[suppressions]]` entries to the config file at this path.
+    Config(&'a Path),
+    /// Append entries to a baseline file at this path (created if missing).
+    Baseline(&'a Path),
+}
+
+/// Tally of decisions made during a review session, for the closing summary.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ReviewOutcome {
+    pub accepted: usize,
+    pub suppressed: usize,
+    pub fixed: usize,
+    pub skipped: usize,
+}
+
+struct ReviewItem<'a> {
+    path: &'a Path,
+    finding: &'a Finding,
+}
+
+/// Run the interactive review loop over every active (non-suppressed)
+/// finding in `report`, writing suppress decisions to `target` and applying
+/// fix decisions to disk immediately.
+pub fn run(
+    report: &ScanReport,
+    config: &mut Config,
+    target: SuppressTarget<'_>,
+) -> Result<ReviewOutcome> {
+    let items: Vec<ReviewItem> = report
+        .results
+        .iter()
+        .flat_map(|r| {
+            r.findings.iter().map(move |f| ReviewItem {
+                path: &r.path,
+                finding: f,
+            })
+        })
+        .collect();
+
+    if items.is_empty() {
+        return Ok(ReviewOutcome::default());
+    }
+
+    enable_raw_mode()?;
+    let mut stdout = std::io::stdout();
+    crossterm::execute!(stdout, EnterAlternateScreen)?;
+    let backend = ratatui::backend::CrosstermBackend::new(stdout);
+    let mut terminal = ratatui::Terminal::new(backend)?;
+
+    let result = review_loop(&mut terminal, &items, config, target);
+
+    disable_raw_mode()?;
+    crossterm::execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+    terminal.show_cursor()?;
+
+    result
+}
+
+fn review_loop(
+    terminal: &mut ratatui::Terminal<ratatui::backend::CrosstermBackend<std::io::Stdout>>,
+    items: &[ReviewItem<'_>],
+    config: &mut Config,
+    target: SuppressTarget<'_>,
+) -> Result<ReviewOutcome> {
+    let mut outcome = ReviewOutcome::default();
+
+    for (idx, item) in items.iter().enumerate() {
+        loop {
+            terminal.draw(|frame| draw_finding(frame, idx, items.len(), item))?;
+
+            if !event::poll(Duration::from_millis(200))? {
+                continue;
+            }
+            let Event::Key(key) = event::read()? else {
+                continue;
+            };
+            match key.code {
+                KeyCode::Char('q') | KeyCode::Esc => return Ok(outcome),
+                KeyCode::Char('a') => {
+                    outcome.accepted += 1;
+                    break;
+                }
+                KeyCode::Char('s') => {
+                    record_suppression(item, config, &target)?;
+                    outcome.suppressed += 1;
+                    break;
+                }
+                KeyCode::Char('f') => {
+                    if fixer::apply_fix(item.path, item.finding, false)?.is_some() {
+                        outcome.fixed += 1;
+                    } else {
+                        outcome.skipped += 1;
+                    }
+                    break;
+                }
+                KeyCode::Char('n') | KeyCode::Enter => {
+                    outcome.skipped += 1;
+                    break;
+                }
+                _ => {}
+            }
+        }
+    }
+
+    Ok(outcome)
+}
+
+fn record_suppression(
+    item: &ReviewItem<'_>,
+    config: &mut Config,
+    target: &SuppressTarget<'_>,
+) -> Result<()> {
+    match target {
+        SuppressTarget::Config(path) => {
+            let rule = SuppressionRule {
+                rule_id: item.finding.rule_id.clone(),
+                path_glob: Some(item.path.display().to_string()),
+                reason: Some("reviewed via `vexscan review`".to_string()),
+                by: None,
+            };
+            append_config_suppression(path, &rule)?;
+            config.suppressions.push(rule);
+        }
+        SuppressTarget::Baseline(path) => {
+            let mut baseline = Baseline::load(path).unwrap_or_default();
+            baseline.entries.push(BaselineEntry {
+                rule_id: item.finding.rule_id.clone(),
+                file: item.path.to_path_buf(),
+                start_line: item.finding.location.start_line,
+                reason: Some("reviewed via `vexscan review`".to_string()),
+                by: None,
+                at: None,
+            });
+            std::fs::write(path, serde_json::to_string_pretty(&baseline)?)?;
+        }
+    }
+    Ok(())
+}
+
+/// Append a `[[suppressions]]` table to the config file, creating it (with
+/// no other settings) if it doesn't exist yet. Appending text rather than
+/// re-serializing the whole `Config` preserves the user's existing comments
+/// and formatting.
+fn append_config_suppression(path: &Path, rule: &SuppressionRule) -> Result<()> {
+    let mut toml = String::new();
+    toml.push_str("\n[[suppressions]]\n");
+    toml.push_str(&format!("rule_id = {:?}\n", rule.rule_id));
+    if let Some(ref glob) = rule.path_glob {
+        toml.push_str(&format!("path_glob = {:?}\n", glob));
+    }
+    if let Some(ref reason) = rule.reason {
+        toml.push_str(&format!("reason = {:?}\n", reason));
+    }
+
+    use std::io::Write;
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?;
+    file.write_all(toml.as_bytes())?;
+    Ok(())
+}
+
+fn draw_finding(frame: &mut ratatui::Frame, idx: usize, total: usize, item: &ReviewItem<'_>) {
+    let area = frame.area();
+    let [header_area, body_area, footer_area] = Layout::vertical([
+        Constraint::Length(3),
+        Constraint::Min(0),
+        Constraint::Length(3),
+    ])
+    .areas(area);
+
+    let finding = item.finding;
+    let header = Paragraph::new(vec![Line::from(vec![
+        Span::styled(
+            format!("[{}/{}] ", idx + 1, total),
+            Style::default().add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(finding.rule_id.clone(), Style::default().fg(Color::Cyan)),
+        Span::raw(format!(" — {} ", finding.title)),
+        Span::styled(
+            format!("({})", finding.severity),
+            Style::default().fg(severity_color(finding.severity)),
+        ),
+    ])])
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("vexscan review"),
+    );
+    frame.render_widget(header, header_area);
+
+    let mut lines = vec![
+        Line::from(format!(
+            "{}:{}",
+            item.path.display(),
+            finding.location.start_line
+        )),
+        Line::from(""),
+        Line::from(finding.description.clone()),
+        Line::from(""),
+    ];
+    lines.extend(highlight_snippet(&finding.snippet));
+    if let Some(ref remediation) = finding.remediation {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            format!("Remediation: {}", remediation),
+            Style::default().fg(Color::Green),
+        )));
+    }
+    let body = Paragraph::new(lines)
+        .wrap(Wrap { trim: false })
+        .block(Block::default().borders(Borders::ALL).title("Finding"));
+    frame.render_widget(body, body_area);
+
+    let footer = Paragraph::new(Line::from("[a]ccept  [s]uppress  [f]ix  [n]ext  [q]uit"))
+        .block(Block::default().borders(Borders::ALL));
+    frame.render_widget(footer, footer_area);
+}
+
+fn severity_color(severity: crate::types::Severity) -> Color {
+    use crate::types::Severity;
+    match severity {
+        Severity::Critical => Color::Red,
+        Severity::High => Color::LightRed,
+        Severity::Medium => Color::Yellow,
+        Severity::Low => Color::Blue,
+        Severity::Info => Color::Gray,
+    }
+}
+
+/// A lightweight heuristic highlighter for the code snippet shown during
+/// review: string literals and line comments get their own color, everything
+/// else is plain. Not a real tokenizer — good enough for a quick visual scan
+/// across the many languages vexscan targets.
+fn highlight_snippet(snippet: &str) -> Vec<Line<'static>> {
+    snippet
+        .lines()
+        .map(|line| {
+            if let Some(comment_at) = line.find("//").or_else(|| line.find('#')) {
+                Line::from(vec![
+                    Span::raw(line[..comment_at].to_string()),
+                    Span::styled(
+                        line[comment_at..].to_string(),
+                        Style::default().fg(Color::DarkGray),
+                    ),
+                ])
+            } else {
+                Line::from(Span::styled(
+                    line.to_string(),
+                    Style::default().fg(Color::White),
+                ))
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,210 @@
+//! Locating the byte ranges of JSON string values selected by a small
+//! JSONPath-like dotted path, for rules that need to target a specific
+//! field (e.g. an MCP server's `command`) instead of the whole file.
+//!
+//! Like `target`'s content masking, this is a hand-rolled scanner over the
+//! raw text rather than a full JSON parse: it needs exact byte offsets into
+//! the original file, which re-locating values after a `serde_json::Value`
+//! parse wouldn't give for free (and wouldn't survive duplicate keys or
+//! reordering by map type).
+
+/// Byte range (excluding quotes) of every JSON string value whose dotted
+/// path matches `path_pattern`. A leading `$` and/or `.` on the pattern is
+/// stripped, and a literal `*` segment matches any object key or array
+/// index at that level, e.g. `mcpServers.*.command`. Non-string values
+/// (numbers, booleans, null, nested objects/arrays) are walked but never
+/// themselves reported, since a rule only ever matches a value's text.
+/// Malformed JSON simply stops the scan where it breaks, returning
+/// whatever ranges were already found.
+pub fn collect_json_path_value_ranges(content: &str, path_pattern: &str) -> Vec<(usize, usize)> {
+    let segments: Vec<&str> = path_pattern
+        .trim_start_matches('$')
+        .trim_start_matches('.')
+        .split('.')
+        .filter(|s| !s.is_empty())
+        .collect();
+
+    let bytes = content.as_bytes();
+    let mut pos = 0;
+    let mut current_path: Vec<String> = Vec::new();
+    let mut ranges = Vec::new();
+    scan_value(
+        content,
+        bytes,
+        &mut pos,
+        &mut current_path,
+        &segments,
+        &mut ranges,
+    );
+    ranges
+}
+
+fn skip_ws(bytes: &[u8], pos: &mut usize) {
+    while *pos < bytes.len() && (bytes[*pos] as char).is_whitespace() {
+        *pos += 1;
+    }
+}
+
+/// Parse a JSON string literal starting at `bytes[*pos] == b'"'`, advancing
+/// `pos` past the closing quote. Returns the raw (still-escaped) byte range
+/// between the quotes.
+fn parse_string_range(bytes: &[u8], pos: &mut usize) -> (usize, usize) {
+    *pos += 1; // opening quote
+    let start = *pos;
+    while *pos < bytes.len() && bytes[*pos] != b'"' {
+        if bytes[*pos] == b'\\' && *pos + 1 < bytes.len() {
+            *pos += 2;
+        } else {
+            *pos += 1;
+        }
+    }
+    let end = *pos;
+    if *pos < bytes.len() {
+        *pos += 1; // closing quote
+    }
+    (start, end)
+}
+
+fn segment_matches(pattern_segment: &str, actual_segment: &str) -> bool {
+    pattern_segment == "*" || pattern_segment == actual_segment
+}
+
+fn path_matches(current: &[String], pattern: &[&str]) -> bool {
+    current.len() == pattern.len()
+        && current
+            .iter()
+            .zip(pattern.iter())
+            .all(|(actual, wanted)| segment_matches(wanted, actual))
+}
+
+fn scan_value(
+    content: &str,
+    bytes: &[u8],
+    pos: &mut usize,
+    current_path: &mut Vec<String>,
+    pattern: &[&str],
+    ranges: &mut Vec<(usize, usize)>,
+) {
+    skip_ws(bytes, pos);
+    let Some(&byte) = bytes.get(*pos) else {
+        return;
+    };
+    match byte {
+        b'{' => {
+            *pos += 1;
+            loop {
+                skip_ws(bytes, pos);
+                match bytes.get(*pos) {
+                    Some(b'}') => {
+                        *pos += 1;
+                        return;
+                    }
+                    Some(b'"') => {}
+                    _ => return, // malformed: stop scanning
+                }
+                let (key_start, key_end) = parse_string_range(bytes, pos);
+                let key = content[key_start..key_end].to_string();
+                skip_ws(bytes, pos);
+                if bytes.get(*pos) == Some(&b':') {
+                    *pos += 1;
+                }
+                current_path.push(key);
+                scan_value(content, bytes, pos, current_path, pattern, ranges);
+                current_path.pop();
+                skip_ws(bytes, pos);
+                match bytes.get(*pos) {
+                    Some(b',') => *pos += 1,
+                    Some(b'}') => {
+                        *pos += 1;
+                        return;
+                    }
+                    _ => return,
+                }
+            }
+        }
+        b'[' => {
+            *pos += 1;
+            let mut index = 0usize;
+            loop {
+                skip_ws(bytes, pos);
+                match bytes.get(*pos) {
+                    Some(b']') => {
+                        *pos += 1;
+                        return;
+                    }
+                    None => return,
+                    _ => {}
+                }
+                current_path.push(index.to_string());
+                scan_value(content, bytes, pos, current_path, pattern, ranges);
+                current_path.pop();
+                index += 1;
+                skip_ws(bytes, pos);
+                match bytes.get(*pos) {
+                    Some(b',') => *pos += 1,
+                    Some(b']') => {
+                        *pos += 1;
+                        return;
+                    }
+                    _ => return,
+                }
+            }
+        }
+        b'"' => {
+            let (start, end) = parse_string_range(bytes, pos);
+            if path_matches(current_path, pattern) {
+                ranges.push((start, end));
+            }
+        }
+        _ => {
+            // Number, bool, or null: skip to the next structural byte.
+            while *pos < bytes.len() && !matches!(bytes[*pos], b',' | b'}' | b']') {
+                *pos += 1;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_collects_wildcard_object_field() {
+        let content =
+            r#"{"mcpServers": {"a": {"command": "curl evil.sh"}, "b": {"command": "safe"}}}"#;
+        let ranges = collect_json_path_value_ranges(content, "mcpServers.*.command");
+        let values: Vec<&str> = ranges.iter().map(|&(s, e)| &content[s..e]).collect();
+        assert_eq!(values, vec!["curl evil.sh", "safe"]);
+    }
+
+    #[test]
+    fn test_ignores_same_key_at_wrong_depth() {
+        let content = r#"{"command": "top level, should not match", "mcpServers": {"a": {"command": "nested"}}}"#;
+        let ranges = collect_json_path_value_ranges(content, "mcpServers.*.command");
+        let values: Vec<&str> = ranges.iter().map(|&(s, e)| &content[s..e]).collect();
+        assert_eq!(values, vec!["nested"]);
+    }
+
+    #[test]
+    fn test_array_index_wildcard() {
+        let content = r#"{"hooks": [{"command": "one"}, {"command": "two"}]}"#;
+        let ranges = collect_json_path_value_ranges(content, "hooks.*.command");
+        let values: Vec<&str> = ranges.iter().map(|&(s, e)| &content[s..e]).collect();
+        assert_eq!(values, vec!["one", "two"]);
+    }
+
+    #[test]
+    fn test_leading_dollar_and_dot_are_stripped() {
+        let content = r#"{"mcpServers": {"a": {"command": "hit"}}}"#;
+        let ranges = collect_json_path_value_ranges(content, "$.mcpServers.*.command");
+        assert_eq!(ranges.len(), 1);
+    }
+
+    #[test]
+    fn test_no_matches_returns_empty() {
+        let content = r#"{"mcpServers": {"a": {"args": ["x"]}}}"#;
+        let ranges = collect_json_path_value_ranges(content, "mcpServers.*.command");
+        assert!(ranges.is_empty());
+    }
+}
@@ -2,10 +2,16 @@
 //!
 //! Loads rules from JSON files in the rules/ directory and subdirectories.
 
-use super::{Rule, RuleMetadata, RuleSource, TestCases};
-use crate::types::{FindingCategory, Severity};
+use super::{
+    CompositeMatch, JsonPathMatch, RegexFlag, Rule, RuleContext, RuleMetadata, RuleScoring,
+    RuleSource, RuleTarget, RuleTranslation, TestCases,
+};
+use crate::adapters::ComponentType;
+use crate::types::{Confidence, FindingCategory, Severity};
+use regex::Regex;
 use serde::Deserialize;
-use std::path::Path;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 
 /// JSON structure for a rule file.
 #[derive(Debug, Deserialize)]
@@ -32,6 +38,9 @@ struct JsonRule {
     title: String,
     description: String,
     severity: String,
+    /// Confidence that a match is a true positive (defaults to Medium).
+    #[serde(default)]
+    confidence: Option<String>,
     /// Single pattern (backward compatible).
     pattern: Option<String>,
     /// Multiple patterns (OR semantics: any match triggers a finding).
@@ -42,7 +51,17 @@ struct JsonRule {
     file_names: Vec<String>,
     #[serde(default)]
     exclude_patterns: Vec<String>,
+    /// Patterns that exclude a match based on its surrounding line rather
+    /// than the matched text itself.
+    #[serde(default)]
+    exclude_line_patterns: Vec<String>,
     remediation: Option<String>,
+    #[serde(default)]
+    cwe: Vec<String>,
+    #[serde(default)]
+    owasp_llm: Vec<String>,
+    #[serde(default)]
+    attack_technique: Vec<String>,
     #[serde(default = "default_true")]
     enabled: bool,
     // Community metadata fields
@@ -56,6 +75,38 @@ struct JsonRule {
     #[serde(default)]
     tags: Vec<String>,
     test_cases: Option<JsonTestCases>,
+    /// Translated title/description/remediation keyed by language code.
+    #[serde(default)]
+    translations: HashMap<String, RuleTranslation>,
+    /// Require multiple patterns to co-occur instead of a single pattern
+    /// match. When set, `pattern`/`patterns` are ignored.
+    composite: Option<CompositeMatch>,
+    /// Require another pattern to also appear within a nearby line window
+    /// before a `pattern`/`patterns` match is reported.
+    context: Option<RuleContext>,
+    /// Component types this rule applies to (empty = all).
+    #[serde(default)]
+    component_types: Vec<ComponentType>,
+    /// Marks this rule as superseded, typically by a rename.
+    #[serde(default)]
+    deprecated: bool,
+    /// The rule ID that replaces this one, if `deprecated` is set.
+    replaced_by: Option<String>,
+    /// Regex engine options applied to every pattern on this rule.
+    #[serde(default)]
+    flags: Vec<RegexFlag>,
+    /// Upper bound, in bytes, on the compiled size of each regex program.
+    size_limit: Option<usize>,
+    /// Accumulate weighted weak indicators into a single score instead of
+    /// firing on any single pattern match. When set, `pattern`/`patterns`
+    /// are ignored.
+    scoring: Option<RuleScoring>,
+    /// Narrows matching down to a structured content region (e.g. only a
+    /// SKILL.md's YAML frontmatter). Unset means the whole file, as before.
+    target: Option<RuleTarget>,
+    /// Matches a JSONPath-selected value instead of scanning the whole
+    /// file. When set, `pattern`/`patterns` are ignored.
+    json_path: Option<JsonPathMatch>,
 }
 
 fn default_true() -> bool {
@@ -93,12 +144,14 @@ impl JsonRule {
             pats.clone()
         } else if let Some(ref pat) = self.pattern {
             vec![pat.clone()]
-        } else {
+        } else if self.composite.is_none() && self.scoring.is_none() && self.json_path.is_none() {
             tracing::warn!(
                 "Rule {} has neither pattern nor patterns; will never match",
                 self.id
             );
             vec![]
+        } else {
+            vec![]
         };
 
         Rule {
@@ -106,15 +159,35 @@ impl JsonRule {
             title: self.title.clone(),
             description: self.description.clone(),
             severity: parse_severity(&self.severity),
+            confidence: self
+                .confidence
+                .as_deref()
+                .map(parse_confidence)
+                .unwrap_or(Confidence::Medium),
             category: parse_category(category),
             patterns,
             file_extensions: self.file_extensions.clone(),
             file_names: self.file_names.clone(),
             exclude_patterns: self.exclude_patterns.clone(),
+            exclude_line_patterns: self.exclude_line_patterns.clone(),
             remediation: self.remediation.clone(),
+            cwe: self.cwe.clone(),
+            owasp_llm: self.owasp_llm.clone(),
+            attack_technique: self.attack_technique.clone(),
             enabled: self.enabled,
             source,
             metadata,
+            translations: self.translations.clone(),
+            composite: self.composite.clone(),
+            context: self.context.clone(),
+            component_types: self.component_types.clone(),
+            deprecated: self.deprecated,
+            replaced_by: self.replaced_by.clone(),
+            flags: self.flags.clone(),
+            size_limit: self.size_limit,
+            scoring: self.scoring.clone(),
+            target: self.target,
+            json_path: self.json_path.clone(),
         }
     }
 }
@@ -130,6 +203,15 @@ fn parse_severity(s: &str) -> Severity {
     }
 }
 
+fn parse_confidence(s: &str) -> Confidence {
+    match s.to_lowercase().as_str() {
+        "high" => Confidence::High,
+        "medium" => Confidence::Medium,
+        "low" => Confidence::Low,
+        _ => Confidence::Medium,
+    }
+}
+
 fn parse_category(s: &str) -> FindingCategory {
     match s.to_lowercase().as_str() {
         "code execution" => FindingCategory::CodeExecution,
@@ -302,6 +384,8 @@ const EMBEDDED_OFFICIAL: &[&str] = &[
     include_str!("../../rules/official/batch-scripts.json"),
     include_str!("../../rules/official/mcp-configuration.json"),
     include_str!("../../rules/official/persistence.json"),
+    include_str!("../../rules/official/rust-build-scripts.json"),
+    include_str!("../../rules/official/php-execution.json"),
 ];
 
 /// Embedded community rule JSON files (compiled into the binary).
@@ -462,6 +546,444 @@ pub fn test_rules_from_file(
     Ok(test_all_rules(&rules))
 }
 
+/// A single problem found while validating a rules directory, with enough
+/// location detail (file, and line for JSON syntax errors) for a rule
+/// author to fix it without needing to run a scan first.
+#[derive(Debug)]
+pub struct RuleValidationError {
+    pub file: PathBuf,
+    pub line: Option<usize>,
+    pub rule_id: Option<String>,
+    pub message: String,
+}
+
+impl std::fmt::Display for RuleValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.file.display())?;
+        if let Some(line) = self.line {
+            write!(f, ":{}", line)?;
+        }
+        if let Some(ref id) = self.rule_id {
+            write!(f, " [{}]", id)?;
+        }
+        write!(f, " {}", self.message)
+    }
+}
+
+/// Validate every rule JSON file under `dir`: JSON syntax, regex
+/// compilation of `pattern`/`patterns`/`exclude_patterns`, presence of at
+/// least one pattern, known severity/confidence values, and duplicate rule
+/// IDs across the whole tree. Mirrors the `official/`+`community/` layout
+/// `load_rules_from_directory_tree` understands, falling back to a flat
+/// directory of JSON files. Returns every problem found rather than
+/// stopping at the first, so a rule author can fix everything in one pass.
+pub fn validate_rules_directory(dir: &Path) -> Vec<RuleValidationError> {
+    let mut errors = Vec::new();
+    let mut files = Vec::new();
+    collect_rule_files(dir, &mut files);
+
+    let mut seen_ids: HashMap<String, PathBuf> = HashMap::new();
+
+    for path in files {
+        let content = match std::fs::read_to_string(&path) {
+            Ok(c) => c,
+            Err(e) => {
+                errors.push(RuleValidationError {
+                    file: path.clone(),
+                    line: None,
+                    rule_id: None,
+                    message: format!("failed to read file: {}", e),
+                });
+                continue;
+            }
+        };
+
+        let rule_file: RuleFile = match serde_json::from_str(&content) {
+            Ok(rf) => rf,
+            Err(e) => {
+                errors.push(RuleValidationError {
+                    file: path.clone(),
+                    line: Some(e.line()),
+                    rule_id: None,
+                    message: format!("invalid JSON: {}", e),
+                });
+                continue;
+            }
+        };
+
+        for rule in &rule_file.rules {
+            validate_rule(rule, &path, &mut errors);
+
+            if let Some(first_seen_in) = seen_ids.get(&rule.id) {
+                errors.push(RuleValidationError {
+                    file: path.clone(),
+                    line: None,
+                    rule_id: Some(rule.id.clone()),
+                    message: format!(
+                        "duplicate rule ID (already defined in {})",
+                        first_seen_in.display()
+                    ),
+                });
+            } else {
+                seen_ids.insert(rule.id.clone(), path.clone());
+            }
+        }
+    }
+
+    errors
+}
+
+/// Validate a single rule's patterns and enum-like fields.
+fn validate_rule(rule: &JsonRule, path: &Path, errors: &mut Vec<RuleValidationError>) {
+    let patterns: Vec<&str> = rule
+        .patterns
+        .iter()
+        .flatten()
+        .map(|s| s.as_str())
+        .chain(rule.pattern.as_deref())
+        .collect();
+
+    if patterns.is_empty()
+        && rule.composite.is_none()
+        && rule.scoring.is_none()
+        && rule.json_path.is_none()
+    {
+        errors.push(RuleValidationError {
+            file: path.to_path_buf(),
+            line: None,
+            rule_id: Some(rule.id.clone()),
+            message: "has neither `pattern` nor `patterns`; will never match".to_string(),
+        });
+    }
+
+    for pattern in &patterns {
+        if let Err(e) = Regex::new(pattern) {
+            errors.push(RuleValidationError {
+                file: path.to_path_buf(),
+                line: None,
+                rule_id: Some(rule.id.clone()),
+                message: format!("invalid pattern `{}`: {}", pattern, e),
+            });
+        }
+    }
+
+    if let Some(ref composite) = rule.composite {
+        if composite.all_of.is_empty() && composite.any_of.is_empty() {
+            errors.push(RuleValidationError {
+                file: path.to_path_buf(),
+                line: None,
+                rule_id: Some(rule.id.clone()),
+                message: "composite rule has neither `all_of` nor `any_of`; will never match"
+                    .to_string(),
+            });
+        }
+        for pattern in composite
+            .all_of
+            .iter()
+            .chain(&composite.any_of)
+            .chain(&composite.none_of)
+        {
+            if let Err(e) = Regex::new(pattern) {
+                errors.push(RuleValidationError {
+                    file: path.to_path_buf(),
+                    line: None,
+                    rule_id: Some(rule.id.clone()),
+                    message: format!("invalid composite pattern `{}`: {}", pattern, e),
+                });
+            }
+        }
+    }
+
+    if let Some(ref scoring) = rule.scoring {
+        if scoring.indicators.is_empty() {
+            errors.push(RuleValidationError {
+                file: path.to_path_buf(),
+                line: None,
+                rule_id: Some(rule.id.clone()),
+                message: "scoring rule has no `indicators`; will never match".to_string(),
+            });
+        }
+        for indicator in &scoring.indicators {
+            if let Err(e) = Regex::new(&indicator.pattern) {
+                errors.push(RuleValidationError {
+                    file: path.to_path_buf(),
+                    line: None,
+                    rule_id: Some(rule.id.clone()),
+                    message: format!(
+                        "invalid scoring indicator pattern `{}`: {}",
+                        indicator.pattern, e
+                    ),
+                });
+            }
+        }
+    }
+
+    if let Some(ref json_path) = rule.json_path {
+        if json_path
+            .path
+            .trim_start_matches('$')
+            .trim_start_matches('.')
+            .is_empty()
+        {
+            errors.push(RuleValidationError {
+                file: path.to_path_buf(),
+                line: None,
+                rule_id: Some(rule.id.clone()),
+                message: "json_path rule has an empty `path`; will never match".to_string(),
+            });
+        }
+        if let Err(e) = Regex::new(&json_path.pattern) {
+            errors.push(RuleValidationError {
+                file: path.to_path_buf(),
+                line: None,
+                rule_id: Some(rule.id.clone()),
+                message: format!("invalid json_path pattern `{}`: {}", json_path.pattern, e),
+            });
+        }
+    }
+
+    if let Some(ref context) = rule.context {
+        if let Err(e) = Regex::new(&context.pattern) {
+            errors.push(RuleValidationError {
+                file: path.to_path_buf(),
+                line: None,
+                rule_id: Some(rule.id.clone()),
+                message: format!("invalid context pattern `{}`: {}", context.pattern, e),
+            });
+        }
+    }
+
+    for pattern in &rule.exclude_patterns {
+        if let Err(e) = Regex::new(pattern) {
+            errors.push(RuleValidationError {
+                file: path.to_path_buf(),
+                line: None,
+                rule_id: Some(rule.id.clone()),
+                message: format!("invalid exclude_pattern `{}`: {}", pattern, e),
+            });
+        }
+    }
+
+    for pattern in &rule.exclude_line_patterns {
+        if let Err(e) = Regex::new(pattern) {
+            errors.push(RuleValidationError {
+                file: path.to_path_buf(),
+                line: None,
+                rule_id: Some(rule.id.clone()),
+                message: format!("invalid exclude_line_pattern `{}`: {}", pattern, e),
+            });
+        }
+    }
+
+    if !matches!(
+        rule.severity.to_lowercase().as_str(),
+        "critical" | "high" | "medium" | "low" | "info"
+    ) {
+        errors.push(RuleValidationError {
+            file: path.to_path_buf(),
+            line: None,
+            rule_id: Some(rule.id.clone()),
+            message: format!("unknown severity `{}`", rule.severity),
+        });
+    }
+
+    if let Some(ref confidence) = rule.confidence {
+        if !matches!(
+            confidence.to_lowercase().as_str(),
+            "high" | "medium" | "low"
+        ) {
+            errors.push(RuleValidationError {
+                file: path.to_path_buf(),
+                line: None,
+                rule_id: Some(rule.id.clone()),
+                message: format!("unknown confidence `{}`", confidence),
+            });
+        }
+    }
+}
+
+/// An actionable style/quality warning from `vexscan rules lint`, distinct
+/// from [`RuleValidationError`]: lint warnings flag rules that compile and
+/// run fine but are likely mistakes (redundant patterns, regexes that can
+/// blow up on pathological input), rather than fatal problems.
+#[derive(Debug)]
+pub struct RuleLintWarning {
+    pub file: PathBuf,
+    pub rule_id: Option<String>,
+    pub message: String,
+}
+
+impl std::fmt::Display for RuleLintWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.file.display())?;
+        if let Some(ref id) = self.rule_id {
+            write!(f, " [{}]", id)?;
+        }
+        write!(f, " {}", self.message)
+    }
+}
+
+/// Lint every rule JSON file under `dir` for likely-mistake smells that
+/// still pass [`validate_rules_directory`]: duplicate IDs, a pattern that's
+/// a literal subset of another pattern on the same rule (so the narrower
+/// one can never contribute a match the broader one wouldn't already
+/// catch), an unbounded `.*`/`.+` combined with the `dot_matches_newline`
+/// flag (which can span the entire file instead of a single line), and
+/// classic catastrophic-backtracking shapes like `(a+)+`. These are
+/// heuristics, not a full regex analysis, so a clean report doesn't
+/// guarantee a rule is well-behaved — it just catches the common mistakes.
+pub fn lint_rules_directory(dir: &Path) -> Vec<RuleLintWarning> {
+    let mut warnings = Vec::new();
+    let mut files = Vec::new();
+    collect_rule_files(dir, &mut files);
+
+    let mut seen_ids: HashMap<String, PathBuf> = HashMap::new();
+
+    for path in files {
+        let content = match std::fs::read_to_string(&path) {
+            Ok(c) => c,
+            Err(_) => continue,
+        };
+        let rule_file: RuleFile = match serde_json::from_str(&content) {
+            Ok(rf) => rf,
+            Err(_) => continue,
+        };
+
+        for rule in &rule_file.rules {
+            if let Some(first_seen_in) = seen_ids.get(&rule.id) {
+                warnings.push(RuleLintWarning {
+                    file: path.clone(),
+                    rule_id: Some(rule.id.clone()),
+                    message: format!(
+                        "duplicate rule ID (already defined in {})",
+                        first_seen_in.display()
+                    ),
+                });
+            } else {
+                seen_ids.insert(rule.id.clone(), path.clone());
+            }
+
+            let patterns: Vec<&str> = rule
+                .patterns
+                .iter()
+                .flatten()
+                .map(|s| s.as_str())
+                .chain(rule.pattern.as_deref())
+                .collect();
+
+            for (i, pattern) in patterns.iter().enumerate() {
+                if pattern_looks_catastrophic(pattern) {
+                    warnings.push(RuleLintWarning {
+                        file: path.clone(),
+                        rule_id: Some(rule.id.clone()),
+                        message: format!(
+                            "pattern `{}` has a quantified group inside another quantifier \
+                             (e.g. `(a+)+`), which can cause catastrophic backtracking on \
+                             crafted input",
+                            pattern
+                        ),
+                    });
+                }
+                if pattern_spans_lines_unboundedly(pattern, &rule.flags) {
+                    warnings.push(RuleLintWarning {
+                        file: path.clone(),
+                        rule_id: Some(rule.id.clone()),
+                        message: format!(
+                            "pattern `{}` combines `.*`/`.+` with the `dot_matches_newline` \
+                             flag, so it can match unbounded content across the whole file; \
+                             consider `context` or narrowing the pattern instead",
+                            pattern
+                        ),
+                    });
+                }
+                for (j, other) in patterns.iter().enumerate() {
+                    if i != j && pattern_is_literal_subset(pattern, other) {
+                        warnings.push(RuleLintWarning {
+                            file: path.clone(),
+                            rule_id: Some(rule.id.clone()),
+                            message: format!(
+                                "pattern `{}` is a literal subset of `{}` on the same rule; \
+                                 the narrower pattern can never match anything the broader \
+                                 one wouldn't already catch",
+                                pattern, other
+                            ),
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    warnings
+}
+
+/// Heuristic "literal subset" check for two regex patterns on the same
+/// rule: true if `narrow`'s source text is fully contained in `broad`'s and
+/// shorter than it. This only catches the common case of one pattern being
+/// a more specific copy-paste of another already-broad one (e.g. `eval\(`
+/// next to `eval\(.*\)`) — it doesn't understand alternation, anchors, or
+/// quantifiers, so it can both miss real redundancy and flag coincidental
+/// substrings.
+fn pattern_is_literal_subset(narrow: &str, broad: &str) -> bool {
+    narrow.len() < broad.len() && broad.contains(narrow)
+}
+
+/// Heuristic ReDoS smell: a quantified group immediately followed by
+/// another quantifier, e.g. `(a+)+`, `(\d*)*`, `([a-z]+)*` — the classic
+/// catastrophic-backtracking shape. Doesn't attempt real NFA analysis, just
+/// flags the common textual pattern so a rule author finds out at lint
+/// time instead of when a scan hangs on pathological input.
+fn pattern_looks_catastrophic(pattern: &str) -> bool {
+    static CATASTROPHIC_RE: std::sync::LazyLock<Regex> = std::sync::LazyLock::new(|| {
+        Regex::new(r"\([^()]*[+*][^()]*\)[+*]").expect("catastrophic regex smell pattern")
+    });
+    CATASTROPHIC_RE.is_match(pattern)
+}
+
+/// True if `pattern` combines an unbounded `.*`/`.+` with a flag that makes
+/// `.` match newlines too, meaning the match can span the entire file
+/// instead of stopping at a line boundary.
+fn pattern_spans_lines_unboundedly(pattern: &str, flags: &[RegexFlag]) -> bool {
+    flags.contains(&RegexFlag::DotMatchesNewline)
+        && (pattern.contains(".*") || pattern.contains(".+"))
+}
+
+/// Collect every rule JSON file to validate under `dir`, recursing into
+/// `official/`/`community/` subdirectories if present (matching
+/// `load_rules_from_directory_tree`), or treating `dir` itself as a flat
+/// rules directory otherwise.
+fn collect_rule_files(dir: &Path, out: &mut Vec<PathBuf>) {
+    let official = dir.join("official");
+    let community = dir.join("community");
+    if official.is_dir() || community.is_dir() {
+        for sub in [official, community] {
+            if sub.is_dir() {
+                collect_json_files(&sub, out);
+            }
+        }
+    } else {
+        collect_json_files(dir, out);
+    }
+}
+
+/// Collect the JSON rule files directly inside `dir` (non-recursive),
+/// skipping the schema file.
+fn collect_json_files(dir: &Path, out: &mut Vec<PathBuf>) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let is_schema = path
+            .file_name()
+            .map(|n| n == "rule-schema.json")
+            .unwrap_or(false);
+        if path.extension().map(|e| e == "json").unwrap_or(false) && !is_schema {
+            out.push(path);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -475,9 +997,158 @@ mod tests {
         assert!(matches!(parse_severity("info"), Severity::Info));
     }
 
+    #[test]
+    fn test_parse_confidence() {
+        assert!(matches!(parse_confidence("high"), Confidence::High));
+        assert!(matches!(parse_confidence("Medium"), Confidence::Medium));
+        assert!(matches!(parse_confidence("LOW"), Confidence::Low));
+        assert!(matches!(parse_confidence("bogus"), Confidence::Medium));
+    }
+
     #[test]
     fn test_load_json_rules() {
         let rules = load_builtin_json_rules();
         assert!(!rules.is_empty(), "Should load at least some JSON rules");
     }
+
+    #[test]
+    fn test_validate_rules_directory_clean() {
+        let tmp = tempfile::tempdir().unwrap();
+        std::fs::write(
+            tmp.path().join("test.json"),
+            r#"{"category": "Test", "rules": [{"id": "T-001", "title": "t", "description": "d", "severity": "high", "pattern": "foo"}]}"#,
+        )
+        .unwrap();
+
+        let errors = validate_rules_directory(tmp.path());
+        assert!(errors.is_empty(), "expected no errors, got {:?}", errors);
+    }
+
+    #[test]
+    fn test_validate_rules_directory_bad_regex_and_missing_pattern() {
+        let tmp = tempfile::tempdir().unwrap();
+        std::fs::write(
+            tmp.path().join("test.json"),
+            r#"{"category": "Test", "rules": [
+                {"id": "T-001", "title": "t", "description": "d", "severity": "high", "pattern": "("},
+                {"id": "T-002", "title": "t", "description": "d", "severity": "high"}
+            ]}"#,
+        )
+        .unwrap();
+
+        let errors = validate_rules_directory(tmp.path());
+        assert_eq!(errors.len(), 2);
+        assert!(errors[0].message.contains("invalid pattern"));
+        assert!(errors[1]
+            .message
+            .contains("neither `pattern` nor `patterns`"));
+    }
+
+    #[test]
+    fn test_validate_rules_directory_duplicate_id() {
+        let tmp = tempfile::tempdir().unwrap();
+        std::fs::write(
+            tmp.path().join("a.json"),
+            r#"{"category": "Test", "rules": [{"id": "T-001", "title": "t", "description": "d", "severity": "high", "pattern": "foo"}]}"#,
+        )
+        .unwrap();
+        std::fs::write(
+            tmp.path().join("b.json"),
+            r#"{"category": "Test", "rules": [{"id": "T-001", "title": "t", "description": "d", "severity": "high", "pattern": "bar"}]}"#,
+        )
+        .unwrap();
+
+        let errors = validate_rules_directory(tmp.path());
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].message.contains("duplicate rule ID"));
+    }
+
+    #[test]
+    fn test_validate_rules_directory_invalid_json_reports_line() {
+        let tmp = tempfile::tempdir().unwrap();
+        std::fs::write(tmp.path().join("bad.json"), "{ not valid json").unwrap();
+
+        let errors = validate_rules_directory(tmp.path());
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].line.is_some());
+        assert!(errors[0].message.contains("invalid JSON"));
+    }
+
+    #[test]
+    fn test_lint_rules_directory_clean() {
+        let tmp = tempfile::tempdir().unwrap();
+        std::fs::write(
+            tmp.path().join("test.json"),
+            r#"{"category": "Test", "rules": [{"id": "T-001", "title": "t", "description": "d", "severity": "high", "pattern": "foo"}]}"#,
+        )
+        .unwrap();
+
+        let warnings = lint_rules_directory(tmp.path());
+        assert!(
+            warnings.is_empty(),
+            "expected no warnings, got {:?}",
+            warnings
+        );
+    }
+
+    #[test]
+    fn test_lint_rules_directory_duplicate_id() {
+        let tmp = tempfile::tempdir().unwrap();
+        std::fs::write(
+            tmp.path().join("a.json"),
+            r#"{"category": "Test", "rules": [{"id": "T-001", "title": "t", "description": "d", "severity": "high", "pattern": "foo"}]}"#,
+        )
+        .unwrap();
+        std::fs::write(
+            tmp.path().join("b.json"),
+            r#"{"category": "Test", "rules": [{"id": "T-001", "title": "t", "description": "d", "severity": "high", "pattern": "bar"}]}"#,
+        )
+        .unwrap();
+
+        let warnings = lint_rules_directory(tmp.path());
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].message.contains("duplicate rule ID"));
+    }
+
+    #[test]
+    fn test_lint_rules_directory_literal_subset_pattern() {
+        let tmp = tempfile::tempdir().unwrap();
+        std::fs::write(
+            tmp.path().join("test.json"),
+            r#"{"category": "Test", "rules": [{"id": "T-001", "title": "t", "description": "d", "severity": "high", "patterns": ["eval\\(", "eval\\(.*\\)"]}]}"#,
+        )
+        .unwrap();
+
+        let warnings = lint_rules_directory(tmp.path());
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].message.contains("literal subset"));
+    }
+
+    #[test]
+    fn test_lint_rules_directory_catastrophic_pattern() {
+        let tmp = tempfile::tempdir().unwrap();
+        std::fs::write(
+            tmp.path().join("test.json"),
+            r#"{"category": "Test", "rules": [{"id": "T-001", "title": "t", "description": "d", "severity": "high", "pattern": "(a+)+"}]}"#,
+        )
+        .unwrap();
+
+        let warnings = lint_rules_directory(tmp.path());
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].message.contains("catastrophic backtracking"));
+    }
+
+    #[test]
+    fn test_lint_rules_directory_unbounded_dot_across_lines() {
+        let tmp = tempfile::tempdir().unwrap();
+        std::fs::write(
+            tmp.path().join("test.json"),
+            r#"{"category": "Test", "rules": [{"id": "T-001", "title": "t", "description": "d", "severity": "high", "pattern": "start.*end", "flags": ["dot_matches_newline"]}]}"#,
+        )
+        .unwrap();
+
+        let warnings = lint_rules_directory(tmp.path());
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].message.contains("dot_matches_newline"));
+    }
 }
@@ -51,6 +51,9 @@ pub struct ScopeMap {
     include_globs: Option<GlobSet>,
     /// Conventional dev-only patterns (tests, examples, CI).
     dev_only_globs: GlobSet,
+    /// Resolved npm/Yarn/pnpm workspace members, each with their own
+    /// `files` whitelist, for monorepo scans. Empty outside a workspace.
+    workspace_members: Vec<npm::WorkspaceMember>,
 }
 
 impl ScopeMap {
@@ -80,7 +83,37 @@ impl ScopeMap {
             }
         }
 
-        // Layer 2: Manifest whitelist (e.g., npm `files`)
+        // Layer 2: Workspace member whitelist takes precedence over the
+        // root manifest when the file lives inside a workspace package —
+        // each package publishes according to its own `files` field.
+        if let Some(member) = self
+            .workspace_members
+            .iter()
+            .find(|m| relative.starts_with(&m.dir))
+        {
+            return match member.include_globs {
+                Some(ref include) => {
+                    let member_relative = relative.strip_prefix(&member.dir).unwrap_or(&relative);
+                    if include.is_match(member_relative) {
+                        InstallScope::Installed
+                    } else {
+                        InstallScope::DevOnly
+                    }
+                }
+                // No `files` field on this member — fall through to
+                // conventional patterns, same as a non-workspace npm
+                // package with no `files` field.
+                None => {
+                    if self.dev_only_globs.is_match(&relative) {
+                        InstallScope::DevOnly
+                    } else {
+                        InstallScope::Installed
+                    }
+                }
+            };
+        }
+
+        // Layer 2b: Root manifest whitelist (e.g., npm `files`)
         if let Some(ref include) = self.include_globs {
             return if include.is_match(&relative) {
                 InstallScope::Installed
@@ -181,7 +214,13 @@ pub fn detect_scope(scan_root: &Path) -> ScopeMap {
         _ => None,
     };
 
-    let manifest_based = include_globs.is_some();
+    let workspace_members = match project_type {
+        ProjectType::Npm => npm::detect_workspace_members(scan_root),
+        _ => Vec::new(),
+    };
+
+    let manifest_based =
+        include_globs.is_some() || workspace_members.iter().any(|m| m.include_globs.is_some());
 
     ScopeMap {
         project_type,
@@ -189,6 +228,7 @@ pub fn detect_scope(scan_root: &Path) -> ScopeMap {
         always_in_scope,
         include_globs,
         dev_only_globs,
+        workspace_members,
     }
 }
 
@@ -412,6 +452,62 @@ mod tests {
         assert!(!is_scope_cap_exempt("PKG-001", false));
     }
 
+    #[test]
+    fn test_workspace_member_applies_own_files_whitelist() {
+        let tmp = TempDir::new().unwrap();
+        let root = tmp.path();
+        fs::write(
+            root.join("package.json"),
+            r#"{"name": "monorepo", "private": true, "workspaces": ["packages/*"]}"#,
+        )
+        .unwrap();
+        fs::create_dir_all(root.join("packages/foo")).unwrap();
+        fs::write(
+            root.join("packages/foo/package.json"),
+            r#"{"name": "foo", "files": ["dist"]}"#,
+        )
+        .unwrap();
+
+        let scope = detect_scope(root);
+        assert!(scope.manifest_based);
+
+        // Published per foo's own `files` field
+        assert_eq!(
+            scope.classify(&root.join("packages/foo/dist/index.js"), root),
+            InstallScope::Installed
+        );
+        // Not in foo's `files` field, even though it'd match a bare npm
+        // project's default rules
+        assert_eq!(
+            scope.classify(&root.join("packages/foo/src/index.ts"), root),
+            InstallScope::DevOnly
+        );
+    }
+
+    #[test]
+    fn test_workspace_member_without_files_field_uses_conventional_rules() {
+        let tmp = TempDir::new().unwrap();
+        let root = tmp.path();
+        fs::write(
+            root.join("package.json"),
+            r#"{"name": "monorepo", "private": true, "workspaces": ["packages/*"]}"#,
+        )
+        .unwrap();
+        fs::create_dir_all(root.join("packages/bar")).unwrap();
+        fs::write(root.join("packages/bar/package.json"), r#"{"name": "bar"}"#).unwrap();
+
+        let scope = detect_scope(root);
+
+        assert_eq!(
+            scope.classify(&root.join("packages/bar/src/index.js"), root),
+            InstallScope::Installed
+        );
+        assert_eq!(
+            scope.classify(&root.join("packages/bar/tests/unit.test.js"), root),
+            InstallScope::DevOnly
+        );
+    }
+
     #[test]
     fn test_scope_cap_exempt_with_manifest() {
         // With manifest whitelist, nothing is exempt — manifest is authoritative
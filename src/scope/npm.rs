@@ -1,15 +1,26 @@
 //! npm package.json scope detection.
 //!
 //! Parses the `files` field from package.json to determine which files
-//! are actually published to npm (and thus installed by users).
+//! are actually published to npm (and thus installed by users). Also
+//! resolves npm/Yarn/pnpm workspace members so monorepo scans can apply
+//! each package's own `files` whitelist instead of only the root's.
 
 use globset::{Glob, GlobSet, GlobSetBuilder};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
-/// Parse package.json at the scan root and build a whitelist GlobSet
-/// from the `files` field. Returns `None` if no package.json or no `files` field.
-pub fn detect_npm_files_whitelist(scan_root: &Path) -> Option<GlobSet> {
-    let pkg_path = scan_root.join("package.json");
+/// A resolved workspace member: its directory relative to the scan root,
+/// and its own `files` whitelist (if its package.json declares one).
+pub struct WorkspaceMember {
+    pub dir: PathBuf,
+    pub include_globs: Option<GlobSet>,
+}
+
+/// Parse package.json at `pkg_dir` (a directory containing a package.json,
+/// either the scan root or a workspace member) and build a whitelist
+/// GlobSet from the `files` field. Returns `None` if no package.json or no
+/// `files` field.
+pub fn detect_npm_files_whitelist(pkg_dir: &Path) -> Option<GlobSet> {
+    let pkg_path = pkg_dir.join("package.json");
     let content = std::fs::read_to_string(&pkg_path).ok()?;
     let pkg: serde_json::Value = serde_json::from_str(&content).ok()?;
 
@@ -99,6 +110,131 @@ pub fn is_npm_project(scan_root: &Path) -> bool {
     scan_root.join("package.json").exists()
 }
 
+/// Read the root package.json's `workspaces` field (npm/Yarn), supporting
+/// both the plain array form and Yarn's `{ "packages": [...] }` object form.
+fn workspace_patterns_from_package_json(scan_root: &Path) -> Vec<String> {
+    let content = match std::fs::read_to_string(scan_root.join("package.json")) {
+        Ok(c) => c,
+        Err(_) => return Vec::new(),
+    };
+    let pkg: serde_json::Value = match serde_json::from_str(&content) {
+        Ok(v) => v,
+        Err(_) => return Vec::new(),
+    };
+
+    match pkg.get("workspaces") {
+        Some(serde_json::Value::Array(arr)) => arr
+            .iter()
+            .filter_map(|v| v.as_str())
+            .map(String::from)
+            .collect(),
+        Some(serde_json::Value::Object(map)) => map
+            .get("packages")
+            .and_then(|v| v.as_array())
+            .map(|arr| {
+                arr.iter()
+                    .filter_map(|v| v.as_str())
+                    .map(String::from)
+                    .collect()
+            })
+            .unwrap_or_default(),
+        _ => Vec::new(),
+    }
+}
+
+/// Read pnpm-workspace.yaml's `packages:` list. Hand-rolled since this repo
+/// has no YAML dependency and the format we care about is a flat list of
+/// quoted or bare glob strings under a single top-level key.
+fn workspace_patterns_from_pnpm_yaml(scan_root: &Path) -> Vec<String> {
+    let content = match std::fs::read_to_string(scan_root.join("pnpm-workspace.yaml")) {
+        Ok(c) => c,
+        Err(_) => return Vec::new(),
+    };
+
+    let mut patterns = Vec::new();
+    let mut in_packages = false;
+    for line in content.lines() {
+        let trimmed = line.trim_start();
+        if !line.starts_with(' ') && !line.starts_with('-') {
+            in_packages = trimmed.trim_end().trim_end_matches(':') == "packages";
+            continue;
+        }
+        if in_packages {
+            if let Some(item) = trimmed.strip_prefix("- ") {
+                let item = item.trim().trim_matches(['"', '\'']);
+                if !item.is_empty() {
+                    patterns.push(item.to_string());
+                }
+            }
+        }
+    }
+    patterns
+}
+
+/// Expand workspace glob patterns (e.g. `packages/*`, `apps/**`) against the
+/// scan root's directory tree, returning the directories that match and
+/// contain their own package.json.
+fn expand_workspace_patterns(scan_root: &Path, patterns: &[String]) -> Vec<PathBuf> {
+    if patterns.is_empty() {
+        return Vec::new();
+    }
+
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns {
+        // Workspace patterns are directory globs (e.g. "packages/*"); a glob
+        // without a wildcard is a single explicit member directory.
+        if let Ok(glob) = Glob::new(pattern.trim_end_matches('/')) {
+            builder.add(glob);
+        }
+    }
+    let globs = match builder.build() {
+        Ok(g) => g,
+        Err(_) => return Vec::new(),
+    };
+
+    let mut members = Vec::new();
+    for entry in walkdir::WalkDir::new(scan_root)
+        .follow_links(true)
+        .into_iter()
+        .filter_entry(|e| {
+            let name = e.file_name().to_str().unwrap_or("");
+            !matches!(name, "node_modules" | ".git" | "dist" | "build")
+        })
+        .filter_map(|e| e.ok())
+    {
+        if !entry.path().is_dir() {
+            continue;
+        }
+        let relative = match entry.path().strip_prefix(scan_root) {
+            Ok(r) if !r.as_os_str().is_empty() => r,
+            _ => continue,
+        };
+        if globs.is_match(relative) && entry.path().join("package.json").exists() {
+            members.push(relative.to_path_buf());
+        }
+    }
+    members
+}
+
+/// Resolve npm/Yarn/pnpm workspace members for a monorepo scan root: each
+/// member directory paired with its own `files` whitelist (if declared),
+/// so `ScopeMap` can apply per-package publish-scope filtering instead of
+/// only the root package's.
+pub fn detect_workspace_members(scan_root: &Path) -> Vec<WorkspaceMember> {
+    let mut patterns = workspace_patterns_from_package_json(scan_root);
+    if patterns.is_empty() {
+        patterns = workspace_patterns_from_pnpm_yaml(scan_root);
+    }
+
+    expand_workspace_patterns(scan_root, &patterns)
+        .into_iter()
+        .map(|dir| {
+            let include_globs = detect_npm_files_whitelist(&scan_root.join(&dir));
+            WorkspaceMember { dir, include_globs }
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -155,4 +291,79 @@ mod tests {
         );
         assert!(detect_npm_files_whitelist(tmp.path()).is_none());
     }
+
+    #[test]
+    fn test_npm_workspaces_array_form() {
+        let tmp = TempDir::new().unwrap();
+        create_package_json(
+            tmp.path(),
+            r#"{"name": "root", "private": true, "workspaces": ["packages/*"]}"#,
+        );
+        fs::create_dir_all(tmp.path().join("packages/foo")).unwrap();
+        create_package_json(
+            &tmp.path().join("packages/foo"),
+            r#"{"name": "foo", "files": ["dist"]}"#,
+        );
+        fs::create_dir_all(tmp.path().join("packages/bar")).unwrap();
+        create_package_json(&tmp.path().join("packages/bar"), r#"{"name": "bar"}"#);
+
+        let members = detect_workspace_members(tmp.path());
+        let mut dirs: Vec<_> = members.iter().map(|m| m.dir.clone()).collect();
+        dirs.sort();
+        assert_eq!(
+            dirs,
+            vec![PathBuf::from("packages/bar"), PathBuf::from("packages/foo")]
+        );
+
+        let foo = members
+            .iter()
+            .find(|m| m.dir == PathBuf::from("packages/foo"))
+            .unwrap();
+        assert!(foo.include_globs.is_some());
+
+        let bar = members
+            .iter()
+            .find(|m| m.dir == PathBuf::from("packages/bar"))
+            .unwrap();
+        assert!(bar.include_globs.is_none());
+    }
+
+    #[test]
+    fn test_yarn_workspaces_object_form() {
+        let tmp = TempDir::new().unwrap();
+        create_package_json(
+            tmp.path(),
+            r#"{"name": "root", "workspaces": {"packages": ["apps/*"]}}"#,
+        );
+        fs::create_dir_all(tmp.path().join("apps/web")).unwrap();
+        create_package_json(&tmp.path().join("apps/web"), r#"{"name": "web"}"#);
+
+        let members = detect_workspace_members(tmp.path());
+        assert_eq!(members.len(), 1);
+        assert_eq!(members[0].dir, PathBuf::from("apps/web"));
+    }
+
+    #[test]
+    fn test_pnpm_workspace_yaml() {
+        let tmp = TempDir::new().unwrap();
+        create_package_json(tmp.path(), r#"{"name": "root"}"#);
+        fs::write(
+            tmp.path().join("pnpm-workspace.yaml"),
+            "packages:\n  - \"packages/*\"\n",
+        )
+        .unwrap();
+        fs::create_dir_all(tmp.path().join("packages/pkg-a")).unwrap();
+        create_package_json(&tmp.path().join("packages/pkg-a"), r#"{"name": "pkg-a"}"#);
+
+        let members = detect_workspace_members(tmp.path());
+        assert_eq!(members.len(), 1);
+        assert_eq!(members[0].dir, PathBuf::from("packages/pkg-a"));
+    }
+
+    #[test]
+    fn test_no_workspaces_declared() {
+        let tmp = TempDir::new().unwrap();
+        create_package_json(tmp.path(), r#"{"name": "test", "version": "1.0.0"}"#);
+        assert!(detect_workspace_members(tmp.path()).is_empty());
+    }
 }
@@ -0,0 +1,225 @@
+//! Risk scoring for scan results.
+//!
+//! Findings are weighted by severity and confidence into a 0-100 risk
+//! score, and additionally by the type of file the finding lives in — the
+//! same pattern in a hook or MCP server config is more dangerous than the
+//! same pattern in a doc comment, since hooks and MCP configs run
+//! automatically while docs don't. Per-component scores additionally
+//! weight by the kind of component the finding was found in — a skill or
+//! MCP server is directly reachable by agent tool calls, so the same
+//! finding there is riskier than one buried in a transitive npm/crate
+//! dependency.
+
+use crate::adapters::ComponentType;
+use crate::components::ComponentKind;
+use crate::types::{Confidence, Finding, Severity};
+use std::collections::HashMap;
+
+fn severity_weight(severity: Severity) -> f64 {
+    match severity {
+        Severity::Critical => 40.0,
+        Severity::High => 15.0,
+        Severity::Medium => 5.0,
+        Severity::Low => 2.0,
+        Severity::Info => 0.0,
+    }
+}
+
+fn confidence_weight(confidence: Confidence) -> f64 {
+    match confidence {
+        Confidence::High => 1.0,
+        Confidence::Medium => 0.75,
+        Confidence::Low => 0.4,
+    }
+}
+
+/// Exposure multiplier for a component kind.
+fn component_weight(kind: ComponentKind) -> f64 {
+    match kind {
+        ComponentKind::Skill => 1.2,
+        ComponentKind::McpServer => 1.2,
+        ComponentKind::Plugin => 1.1,
+        ComponentKind::NpmPackage => 0.8,
+        ComponentKind::RustCrate => 0.8,
+    }
+}
+
+/// Default exposure multiplier for a `ComponentType`, applied when the
+/// table in `ComponentTypeWeights` has no override for it. Hooks and MCP
+/// server configs execute automatically and are the highest-value targets
+/// for an attacker; plugin code and config files run less predictably;
+/// prompts, memory, and documentation are read by a model rather than
+/// executed, so the same pattern there is lower-severity in practice.
+fn default_component_type_weight(component_type: ComponentType) -> f64 {
+    match component_type {
+        ComponentType::Hook => 1.3,
+        ComponentType::McpServer => 1.3,
+        ComponentType::Plugin => 1.1,
+        ComponentType::Config => 1.0,
+        ComponentType::Prompt => 0.9,
+        ComponentType::Memory => 0.9,
+        ComponentType::Other => 1.0,
+    }
+}
+
+/// Configurable table of exposure multipliers keyed by `ComponentType`,
+/// used to weight findings by how dangerous their kind of file is (see
+/// `Config::component_type_weights` for the TOML-facing form). Types with
+/// no entry fall back to `default_component_type_weight`.
+#[derive(Debug, Clone, Default)]
+pub struct ComponentTypeWeights(HashMap<ComponentType, f64>);
+
+impl ComponentTypeWeights {
+    /// Build a weighting table from user-supplied overrides, keyed by the
+    /// `ComponentType` `Display` name (e.g. "hook", "mcp-server").
+    /// Unrecognized keys are ignored.
+    pub fn from_overrides(overrides: &HashMap<String, f64>) -> Self {
+        let mut table = HashMap::new();
+        for component_type in [
+            ComponentType::Plugin,
+            ComponentType::Config,
+            ComponentType::Hook,
+            ComponentType::Prompt,
+            ComponentType::McpServer,
+            ComponentType::Memory,
+            ComponentType::Other,
+        ] {
+            if let Some(weight) = overrides.get(&component_type.to_string()) {
+                table.insert(component_type, *weight);
+            }
+        }
+        Self(table)
+    }
+
+    /// Weight for a given (optional) component type. `None` (no adapter
+    /// classification available) is treated as neutral.
+    pub fn weight(&self, component_type: Option<ComponentType>) -> f64 {
+        match component_type {
+            Some(component_type) => self
+                .0
+                .get(&component_type)
+                .copied()
+                .unwrap_or_else(|| default_component_type_weight(component_type)),
+            None => 1.0,
+        }
+    }
+}
+
+/// Compute a 0-100 risk score from a set of findings, weighted by severity,
+/// confidence, and the exposure of the component type each finding was
+/// found in.
+pub fn compute_risk_score<'a>(
+    findings: impl Iterator<Item = (&'a Finding, Option<ComponentType>)>,
+    weights: &ComponentTypeWeights,
+) -> u8 {
+    let score: f64 = findings
+        .map(|(f, component_type)| {
+            severity_weight(f.severity)
+                * confidence_weight(f.confidence)
+                * weights.weight(component_type)
+        })
+        .sum();
+    score.round().min(100.0) as u8
+}
+
+/// Compute a 0-100 risk score for a single component, additionally weighted
+/// by how exposed that component's kind is to agent tool calls.
+pub fn compute_component_risk_score<'a>(
+    findings: impl Iterator<Item = &'a Finding>,
+    kind: ComponentKind,
+) -> u8 {
+    let weight = component_weight(kind);
+    let score: f64 = findings
+        .map(|f| severity_weight(f.severity) * confidence_weight(f.confidence) * weight)
+        .sum();
+    score.round().min(100.0) as u8
+}
+
+/// Letter grade for a 0-100 risk score, for at-a-glance reporting.
+pub fn grade(score: u8) -> char {
+    match score {
+        0 => 'A',
+        1..=25 => 'B',
+        26..=50 => 'C',
+        51..=75 => 'D',
+        _ => 'F',
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{FindingCategory, Location};
+    use std::path::PathBuf;
+
+    fn finding(severity: Severity, confidence: Confidence) -> Finding {
+        Finding::new(
+            "TEST-001",
+            "Test finding",
+            "A test finding",
+            severity,
+            FindingCategory::CodeExecution,
+            Location::new(PathBuf::from("test.js"), 1, 1),
+            "eval(x)",
+        )
+        .with_confidence(confidence)
+    }
+
+    #[test]
+    fn test_no_findings_scores_zero() {
+        let weights = ComponentTypeWeights::default();
+        assert_eq!(compute_risk_score(std::iter::empty(), &weights), 0);
+    }
+
+    #[test]
+    fn test_low_confidence_weighs_less_than_high() {
+        let weights = ComponentTypeWeights::default();
+        let low = finding(Severity::High, Confidence::Low);
+        let high = finding(Severity::High, Confidence::High);
+        assert!(
+            compute_risk_score(std::iter::once((&low, None)), &weights)
+                < compute_risk_score(std::iter::once((&high, None)), &weights)
+        );
+    }
+
+    #[test]
+    fn test_component_weight_amplifies_exposed_kinds() {
+        let f = finding(Severity::High, Confidence::High);
+        let skill_score = compute_component_risk_score(std::iter::once(&f), ComponentKind::Skill);
+        let crate_score =
+            compute_component_risk_score(std::iter::once(&f), ComponentKind::RustCrate);
+        assert!(skill_score > crate_score);
+    }
+
+    #[test]
+    fn test_component_type_weight_amplifies_hooks_over_docs() {
+        let weights = ComponentTypeWeights::default();
+        let f = finding(Severity::High, Confidence::High);
+        let hook_score =
+            compute_risk_score(std::iter::once((&f, Some(ComponentType::Hook))), &weights);
+        let prompt_score =
+            compute_risk_score(std::iter::once((&f, Some(ComponentType::Prompt))), &weights);
+        assert!(hook_score > prompt_score);
+    }
+
+    #[test]
+    fn test_component_type_weight_overrides_are_applied() {
+        let mut overrides = HashMap::new();
+        overrides.insert("prompt".to_string(), 5.0);
+        let weights = ComponentTypeWeights::from_overrides(&overrides);
+        assert_eq!(weights.weight(Some(ComponentType::Prompt)), 5.0);
+        assert_eq!(
+            weights.weight(Some(ComponentType::Hook)),
+            default_component_type_weight(ComponentType::Hook)
+        );
+    }
+
+    #[test]
+    fn test_grade_boundaries() {
+        assert_eq!(grade(0), 'A');
+        assert_eq!(grade(10), 'B');
+        assert_eq!(grade(30), 'C');
+        assert_eq!(grade(60), 'D');
+        assert_eq!(grade(90), 'F');
+    }
+}
@@ -0,0 +1,228 @@
+//! Long-running HTTP server exposing the scanner over a small REST API, so
+//! other tooling (IDE extensions, agent gateways) can query vexscan without
+//! spawning a process per request.
+//!
+//! Routes:
+//! - `GET /health` — liveness check
+//! - `GET /rules` — list built-in and configured external detection rules
+//! - `POST /scan` — scan a path on disk, or a content string (written to a
+//!   temporary file first, since scanning is filesystem-driven)
+
+use crate::config::Config;
+use crate::rules::loader::load_builtin_json_rules;
+use crate::types::ScanReport;
+use crate::{ScanConfig, Scanner};
+use anyhow::Result;
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Json};
+use axum::routing::{get, post};
+use axum::Router;
+use serde::Deserialize;
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::sync::Arc;
+use tracing::info;
+
+struct AppState {
+    base_config: Config,
+}
+
+/// Body of a `POST /scan` request. Exactly one of `path`/`content` must be set.
+#[derive(Debug, Deserialize)]
+struct ScanRequest {
+    /// Path to scan on the server's filesystem.
+    path: Option<PathBuf>,
+    /// Raw content to scan instead of a path, written to a temp file first.
+    content: Option<String>,
+    /// Filename to use for `content`, so extension-based rules still apply.
+    #[serde(default = "default_filename")]
+    filename: String,
+    /// Enable AST-based analysis for obfuscation detection.
+    #[serde(default)]
+    ast: bool,
+    /// Enable dependency scanning (package.json analysis).
+    #[serde(default)]
+    deps: bool,
+}
+
+fn default_filename() -> String {
+    "snippet.txt".to_string()
+}
+
+/// Start the HTTP server and run until the process is terminated.
+pub async fn serve(addr: SocketAddr, base_config: Config) -> Result<()> {
+    let state = Arc::new(AppState { base_config });
+
+    let app = Router::new()
+        .route("/health", get(health))
+        .route("/rules", get(list_rules))
+        .route("/scan", post(scan))
+        .with_state(state);
+
+    info!("vexscan serve listening on http://{}", addr);
+    let listener = tokio::net::TcpListener::bind(addr).await?;
+    axum::serve(listener, app).await?;
+    Ok(())
+}
+
+async fn health() -> impl IntoResponse {
+    Json(serde_json::json!({
+        "status": "ok",
+        "version": env!("CARGO_PKG_VERSION"),
+    }))
+}
+
+/// Loaded fresh from disk on every request (built-ins plus any
+/// `extra_rules_dirs`), so edits to a custom rule file show up immediately
+/// without restarting the server — same as `POST /scan`.
+async fn list_rules(State(state): State<Arc<AppState>>) -> impl IntoResponse {
+    let mut rules = load_builtin_json_rules();
+    for dir in state.base_config.resolved_extra_rules_dirs() {
+        if dir.is_dir() {
+            if let Ok(external) = crate::rules::loader::load_rules_from_directory_with_source(
+                &dir,
+                Some(crate::rules::RuleSource::External),
+            ) {
+                rules.extend(external);
+            }
+        }
+    }
+    Json(rules)
+}
+
+async fn scan(
+    State(state): State<Arc<AppState>>,
+    Json(req): Json<ScanRequest>,
+) -> Result<Json<ScanReport>, (StatusCode, String)> {
+    if req.path.is_none() && req.content.is_none() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "request must include \"path\" or \"content\"".to_string(),
+        ));
+    }
+
+    let base_config = state.base_config.clone();
+    // `Scanner::scan_path` builds a `Box<dyn PlatformAdapter>` it holds
+    // across an `.await`, which makes its future `!Send` — incompatible with
+    // axum's handler requirement. Run it on a blocking thread with its own
+    // single-threaded runtime instead of awaiting it directly here.
+    let report = tokio::task::spawn_blocking(move || run_scan(base_config, req))
+        .await
+        .map_err(internal_error)?
+        .map_err(internal_error)?;
+    Ok(Json(report))
+}
+
+fn run_scan(base_config: Config, req: ScanRequest) -> Result<ScanReport> {
+    let (path, _temp_dir) = match (req.path, req.content) {
+        (Some(path), _) => (path, None),
+        (None, Some(content)) => {
+            let dir = tempfile::tempdir()?;
+            let file_path = dir.path().join(&req.filename);
+            std::fs::write(&file_path, content)?;
+            (file_path, Some(dir))
+        }
+        (None, None) => unreachable!("validated by caller"),
+    };
+
+    let config = ScanConfig {
+        enable_ast: req.ast,
+        enable_deps: req.deps,
+        extra_rules_dirs: base_config.resolved_extra_rules_dirs(),
+        filter_config: base_config,
+        ..Default::default()
+    };
+
+    let scanner = Scanner::with_config(config)?;
+    let rt = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()?;
+    rt.block_on(scanner.scan_path(&path))
+}
+
+fn internal_error(e: impl std::fmt::Display) -> (StatusCode, String) {
+    (StatusCode::INTERNAL_SERVER_ERROR, e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Spin up the real router on an ephemeral port and return its base URL,
+    /// mirroring the mock-server pattern used to test the AI request
+    /// pipeline in `Scanner::scan`'s tests — here it's the router under test
+    /// rather than a mocked dependency.
+    async fn spawn_test_server() -> String {
+        let state = Arc::new(AppState {
+            base_config: Config::default(),
+        });
+        let app = Router::new()
+            .route("/health", get(health))
+            .route("/rules", get(list_rules))
+            .route("/scan", post(scan))
+            .with_state(state);
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+        base_url
+    }
+
+    #[tokio::test]
+    async fn test_health_reports_ok_status() {
+        let base_url = spawn_test_server().await;
+        let resp = reqwest::get(format!("{base_url}/health")).await.unwrap();
+        assert_eq!(resp.status(), reqwest::StatusCode::OK);
+        let body: serde_json::Value = resp.json().await.unwrap();
+        assert_eq!(body["status"], "ok");
+        assert_eq!(body["version"], env!("CARGO_PKG_VERSION"));
+    }
+
+    #[tokio::test]
+    async fn test_list_rules_returns_builtin_rules() {
+        let base_url = spawn_test_server().await;
+        let resp = reqwest::get(format!("{base_url}/rules")).await.unwrap();
+        assert_eq!(resp.status(), reqwest::StatusCode::OK);
+        let rules: serde_json::Value = resp.json().await.unwrap();
+        assert!(
+            rules.as_array().is_some_and(|r| !r.is_empty()),
+            "expected the embedded built-in rules to be returned"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_scan_missing_path_and_content_returns_bad_request() {
+        let base_url = spawn_test_server().await;
+        let client = reqwest::Client::new();
+        let resp = client
+            .post(format!("{base_url}/scan"))
+            .json(&serde_json::json!({}))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), reqwest::StatusCode::BAD_REQUEST);
+        let body = resp.text().await.unwrap();
+        assert!(body.contains("path") && body.contains("content"));
+    }
+
+    #[tokio::test]
+    async fn test_scan_with_content_returns_report() {
+        let base_url = spawn_test_server().await;
+        let client = reqwest::Client::new();
+        let resp = client
+            .post(format!("{base_url}/scan"))
+            .json(&serde_json::json!({
+                "content": "eval(userInput)",
+                "filename": "snippet.js",
+            }))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), reqwest::StatusCode::OK);
+        let report: ScanReport = resp.json().await.unwrap();
+        assert_eq!(report.results.len(), 1);
+    }
+}
@@ -1,9 +1,10 @@
 //! Core type definitions for the Vexscan security scanner.
 
+use crate::adapters::ComponentType;
 use crate::components::DetectedComponent;
 use crate::scope::InstallScope;
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// Severity level for security findings.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
@@ -28,6 +29,54 @@ impl std::fmt::Display for Severity {
     }
 }
 
+impl Severity {
+    /// One level up, saturating at `Critical`.
+    pub fn escalate(self) -> Self {
+        match self {
+            Severity::Info => Severity::Low,
+            Severity::Low => Severity::Medium,
+            Severity::Medium => Severity::High,
+            Severity::High => Severity::Critical,
+            Severity::Critical => Severity::Critical,
+        }
+    }
+}
+
+/// Confidence that a finding is a true positive, independent of severity.
+/// A Critical/Low-confidence finding is a strong hit that's easy to spoof
+/// away (or an educated guess); a Low/High-confidence finding is a
+/// low-impact issue we're nonetheless sure about.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Confidence {
+    Low,
+    Medium,
+    High,
+}
+
+impl std::fmt::Display for Confidence {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Confidence::Low => write!(f, "low"),
+            Confidence::Medium => write!(f, "medium"),
+            Confidence::High => write!(f, "high"),
+        }
+    }
+}
+
+impl std::str::FromStr for Confidence {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "low" => Ok(Confidence::Low),
+            "medium" | "med" => Ok(Confidence::Medium),
+            "high" => Ok(Confidence::High),
+            _ => Err(format!("Unknown confidence level: {}", s)),
+        }
+    }
+}
+
 /// Category of security finding.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -121,6 +170,9 @@ pub struct Finding {
     pub description: String,
     /// Severity level.
     pub severity: Severity,
+    /// Confidence that this is a true positive (defaults to Medium).
+    #[serde(default = "default_confidence")]
+    pub confidence: Confidence,
     /// Category of the finding.
     pub category: FindingCategory,
     /// Location in the source file.
@@ -129,9 +181,86 @@ pub struct Finding {
     pub snippet: String,
     /// Suggested remediation (optional).
     pub remediation: Option<String>,
+    /// CWE IDs this finding maps to (e.g. "CWE-78").
+    #[serde(default)]
+    pub cwe: Vec<String>,
+    /// OWASP Top 10 for LLM Applications categories this finding maps to
+    /// (e.g. "LLM01:2025").
+    #[serde(default)]
+    pub owasp_llm: Vec<String>,
+    /// MITRE ATT&CK/ATLAS technique IDs this finding maps to (e.g. "T1059",
+    /// "AML.T0051").
+    #[serde(default)]
+    pub attack_technique: Vec<String>,
     /// Additional metadata.
     #[serde(default)]
     pub metadata: std::collections::HashMap<String, String>,
+    /// A safe, mechanical fix for this finding, if one exists.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fix: Option<FixSuggestion>,
+}
+
+/// How a finding came to be suppressed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SuppressionMechanism {
+    /// A `vexscan-ignore:` comment on the flagged line or the line above it.
+    InlineComment,
+    /// A previously-accepted finding recorded in a baseline file.
+    Baseline,
+    /// A `[[suppressions]]` entry in the scanner's config file.
+    Allowlist,
+}
+
+impl std::fmt::Display for SuppressionMechanism {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            SuppressionMechanism::InlineComment => "inline comment",
+            SuppressionMechanism::Baseline => "baseline",
+            SuppressionMechanism::Allowlist => "config allowlist",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// Provenance for a suppressed finding, kept so accepted risk stays
+/// auditable instead of silently vanishing from scan results.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Suppression {
+    /// How this finding came to be suppressed.
+    pub mechanism: SuppressionMechanism,
+    /// Why it was accepted as risk, if given.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    /// Who suppressed it, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub by: Option<String>,
+    /// When it was suppressed, if known (inline comments are evaluated
+    /// fresh on every scan and carry no fixed timestamp).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// A finding that was suppressed rather than dropped, paired with the
+/// provenance of who/when/why it was accepted as risk.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SuppressedFinding {
+    pub finding: Finding,
+    pub suppression: Suppression,
+}
+
+/// A structured, mechanical fix for a finding, expressed as a replacement
+/// for the lines spanned by `Finding::location`. Only attached to findings
+/// where a fix can be generated with no risk of changing behavior other
+/// than removing the flagged content (e.g. stripping a hidden comment,
+/// dropping a superfluous import alias).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FixSuggestion {
+    /// Human-readable summary of what the fix does.
+    pub description: String,
+    /// Replacement text for the finding's line span. An empty string
+    /// deletes the line(s) entirely.
+    pub replacement: String,
 }
 
 impl Finding {
@@ -149,11 +278,16 @@ impl Finding {
             title: title.into(),
             description: description.into(),
             severity,
+            confidence: Confidence::Medium,
             category,
             location,
             snippet: snippet.into(),
             remediation: None,
+            cwe: Vec::new(),
+            owasp_llm: Vec::new(),
+            attack_technique: Vec::new(),
             metadata: std::collections::HashMap::new(),
+            fix: None,
         }
     }
 
@@ -162,10 +296,49 @@ impl Finding {
         self
     }
 
+    pub fn with_confidence(mut self, confidence: Confidence) -> Self {
+        self.confidence = confidence;
+        self
+    }
+
+    pub fn with_cwe(mut self, cwe: Vec<String>) -> Self {
+        self.cwe = cwe;
+        self
+    }
+
+    pub fn with_owasp_llm(mut self, owasp_llm: Vec<String>) -> Self {
+        self.owasp_llm = owasp_llm;
+        self
+    }
+
+    pub fn with_attack_technique(mut self, attack_technique: Vec<String>) -> Self {
+        self.attack_technique = attack_technique;
+        self
+    }
+
     pub fn with_metadata(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
         self.metadata.insert(key.into(), value.into());
         self
     }
+
+    pub fn with_fix(mut self, fix: FixSuggestion) -> Self {
+        self.fix = Some(fix);
+        self
+    }
+
+    /// Stable identity hash for this finding, independent of scan order.
+    /// Used as the final tiebreaker when sorting findings that share a
+    /// path, line, and rule ID (e.g. two matches of the same rule on one
+    /// line), so report output is deterministic across runs.
+    pub fn fingerprint(&self) -> u64 {
+        use std::hash::{Hash, Hasher};
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        self.rule_id.hash(&mut hasher);
+        self.snippet.hash(&mut hasher);
+        self.location.start_line.hash(&mut hasher);
+        self.location.start_column.hash(&mut hasher);
+        hasher.finish()
+    }
 }
 
 /// Result of scanning a single file or component.
@@ -182,9 +355,18 @@ pub struct ScanResult {
     /// Installation scope classification.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub install_scope: Option<InstallScope>,
+    /// Adapter-level component type (plugin, hook, MCP config, etc.) of the
+    /// file this result came from. Used to weight findings by how exposed
+    /// that kind of file is to agent tool calls (see `scoring::compute_risk_score`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub component_type: Option<ComponentType>,
     /// Index into ScanReport.components (None = ungrouped).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub component_idx: Option<usize>,
+    /// Findings suppressed by an inline comment, baseline, or config
+    /// allowlist entry, kept for audit instead of being dropped.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub suppressed: Vec<SuppressedFinding>,
 }
 
 impl ScanResult {
@@ -195,7 +377,9 @@ impl ScanResult {
             scan_time_ms: 0,
             content_hash: None,
             install_scope: None,
+            component_type: None,
             component_idx: None,
+            suppressed: Vec::new(),
         }
     }
 
@@ -215,6 +399,97 @@ impl ScanResult {
     }
 }
 
+/// Per-phase profiling data for a scan, collected when
+/// `ScanConfig.collect_stats` is set (the `--stats` CLI flag). Helps users
+/// tune configuration (e.g. disabling AST/AI) on huge trees.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ScanStats {
+    /// Time spent discovering files/components before analysis started.
+    pub discovery_ms: u64,
+    /// Time spent in regex rule matching and recursive decoding.
+    pub static_ms: u64,
+    /// Time spent in AST-based analysis.
+    pub ast_ms: u64,
+    /// Time spent in AI-backed analysis.
+    pub ai_ms: u64,
+    /// Time spent in dependency (package.json) analysis.
+    pub deps_ms: u64,
+    /// Number of files actually analyzed (post-filtering).
+    pub files_scanned: usize,
+    /// Total bytes of content read and analyzed.
+    pub bytes_scanned: u64,
+    /// Number of detection rules active for this scan.
+    pub rules_active: usize,
+    /// The slowest files by total per-file analysis time, descending,
+    /// capped at 10 entries.
+    pub slowest_files: Vec<SlowFile>,
+}
+
+/// One entry in `ScanStats.slowest_files`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SlowFile {
+    pub path: PathBuf,
+    pub time_ms: u64,
+}
+
+/// Why a file was left out of a scan due to a configured resource limit
+/// (`ScanConfig::max_file_size`, `max_total_files`, `max_scan_duration`) or
+/// an embedder-requested cancellation (`Scanner::abort_handle`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LimitReason {
+    /// File content exceeded `ScanConfig::max_file_size`.
+    FileTooLarge,
+    /// File was beyond `ScanConfig::max_total_files` discovered files.
+    TotalFileLimit,
+    /// `ScanConfig::max_scan_duration` elapsed before this file was reached.
+    DurationExceeded,
+    /// The scan was cancelled via `Scanner::abort_handle` before this file
+    /// was reached.
+    Cancelled,
+    /// `ScanConfig::max_ai_cost_usd` was reached before this file's AI
+    /// analysis was submitted; the file's static/AST findings are unaffected.
+    AiBudgetExceeded,
+}
+
+impl std::fmt::Display for LimitReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LimitReason::FileTooLarge => write!(f, "file too large"),
+            LimitReason::TotalFileLimit => write!(f, "total file limit reached"),
+            LimitReason::DurationExceeded => write!(f, "scan duration limit exceeded"),
+            LimitReason::Cancelled => write!(f, "scan cancelled"),
+            LimitReason::AiBudgetExceeded => write!(f, "AI cost budget exceeded"),
+        }
+    }
+}
+
+/// One file that was skipped because of a resource limit, and why.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkippedFile {
+    pub path: PathBuf,
+    pub reason: LimitReason,
+}
+
+/// One file whose findings were cut off by `ScanConfig::max_findings_per_file`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TruncatedFindings {
+    pub path: PathBuf,
+    /// Number of findings dropped beyond the configured cap.
+    pub dropped: usize,
+}
+
+/// Resource-limit bookkeeping for a scan: which files were skipped (and
+/// why), and which files had findings truncated. Present only when at least
+/// one of `ScanConfig`'s resource limits was configured for the scan.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LimitsReport {
+    /// Files not scanned because of a configured limit.
+    pub skipped_files: Vec<SkippedFile>,
+    /// Files whose findings were cut off at `max_findings_per_file`.
+    pub truncated_findings: Vec<TruncatedFindings>,
+}
+
 /// Aggregated report from scanning multiple files/components.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ScanReport {
@@ -249,9 +524,23 @@ pub struct ScanReport {
     /// Computed risk score (0-100).
     #[serde(default)]
     pub risk_score: u8,
+    /// Overall letter grade derived from `risk_score` (A-F).
+    #[serde(default = "default_grade")]
+    pub grade: char,
     /// Detected AI components within the scan target.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub components: Vec<DetectedComponent>,
+    /// Per-component risk scores (0-100), same order/index as `components`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub component_risk_scores: Vec<u8>,
+    /// Per-phase profiling data, present only when `ScanConfig.collect_stats`
+    /// was set (the `--stats` CLI flag).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stats: Option<ScanStats>,
+    /// Files skipped or truncated by a configured resource limit, present
+    /// only when at least one `ScanConfig` resource limit was set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limits: Option<LimitsReport>,
 }
 
 impl ScanReport {
@@ -269,7 +558,11 @@ impl ScanReport {
             ast_enabled: false,
             deps_enabled: false,
             risk_score: 0,
+            grade: default_grade(),
             components: Vec::new(),
+            component_risk_scores: Vec::new(),
+            stats: None,
+            limits: None,
         }
     }
 
@@ -277,18 +570,101 @@ impl ScanReport {
         self.results.iter().map(|r| r.findings.len()).sum()
     }
 
+    /// Put results and their findings into a stable, scan-order-independent
+    /// order: results by path, findings within a result by
+    /// (line, column, rule ID, fingerprint). Call this once after a scan
+    /// completes so report output and diffs aren't dominated by whatever
+    /// order parallel scanning happened to produce.
+    pub fn sort_deterministic(&mut self) {
+        self.results.sort_by(|a, b| a.path.cmp(&b.path));
+        for result in &mut self.results {
+            result.findings.sort_by(|a, b| {
+                a.location
+                    .start_line
+                    .cmp(&b.location.start_line)
+                    .then_with(|| a.location.start_column.cmp(&b.location.start_column))
+                    .then_with(|| a.rule_id.cmp(&b.rule_id))
+                    .then_with(|| a.fingerprint().cmp(&b.fingerprint()))
+            });
+        }
+    }
+
+    /// All suppressed findings across every scanned file, paired with the
+    /// path they were found in.
+    pub fn suppressed_findings(&self) -> Vec<(&Path, &SuppressedFinding)> {
+        self.results
+            .iter()
+            .flat_map(|r| r.suppressed.iter().map(move |s| (r.path.as_path(), s)))
+            .collect()
+    }
+
+    pub fn total_suppressed(&self) -> usize {
+        self.results.iter().map(|r| r.suppressed.len()).sum()
+    }
+
     pub fn max_severity(&self) -> Option<Severity> {
         self.results.iter().filter_map(|r| r.max_severity()).max()
     }
 
-    /// Compute a 0-100 risk score from finding severities.
+    /// Highest severity across all findings, escalated by one level for
+    /// findings in component types with an above-neutral weight (hooks,
+    /// MCP server configs, ...). Used for fail-on decisions so that a
+    /// medium-severity pattern in a hook can block a build the same way a
+    /// high-severity one would, matching `compute_risk_score_weighted`.
+    pub fn max_severity_weighted(
+        &self,
+        weights: &crate::scoring::ComponentTypeWeights,
+    ) -> Option<Severity> {
+        self.results
+            .iter()
+            .flat_map(|r| r.findings.iter().map(move |f| (f, r.component_type)))
+            .map(|(f, component_type)| {
+                if weights.weight(component_type) > 1.0 {
+                    f.severity.escalate()
+                } else {
+                    f.severity
+                }
+            })
+            .max()
+    }
+
+    /// Compute a 0-100 risk score from finding severity and confidence,
+    /// additionally weighted by the component type each finding was found
+    /// in (see `scoring::compute_risk_score`).
     pub fn compute_risk_score(&self) -> u8 {
-        let counts = self.findings_count_by_severity();
-        let score: usize = counts.get(&Severity::Critical).unwrap_or(&0) * 40
-            + counts.get(&Severity::High).unwrap_or(&0) * 15
-            + counts.get(&Severity::Medium).unwrap_or(&0) * 5
-            + counts.get(&Severity::Low).unwrap_or(&0) * 2;
-        score.min(100) as u8
+        self.compute_risk_score_weighted(&crate::scoring::ComponentTypeWeights::default())
+    }
+
+    /// Same as `compute_risk_score`, but with an explicit component-type
+    /// weighting table (e.g. loaded from `Config::component_type_weights`).
+    pub fn compute_risk_score_weighted(
+        &self,
+        weights: &crate::scoring::ComponentTypeWeights,
+    ) -> u8 {
+        crate::scoring::compute_risk_score(
+            self.results
+                .iter()
+                .flat_map(|r| r.findings.iter().map(move |f| (f, r.component_type))),
+            weights,
+        )
+    }
+
+    /// Compute per-component risk scores, weighted by each component's
+    /// exposure to agent tool calls (see `scoring::compute_component_risk_score`).
+    /// Returned in the same order as `self.components`.
+    pub fn compute_component_risk_scores(&self) -> Vec<u8> {
+        self.components
+            .iter()
+            .enumerate()
+            .map(|(idx, comp)| {
+                let findings = self
+                    .results
+                    .iter()
+                    .filter(|r| r.component_idx == Some(idx))
+                    .flat_map(|r| r.findings.iter());
+                crate::scoring::compute_component_risk_score(findings, comp.kind)
+            })
+            .collect()
     }
 
     /// Human-readable risk label for a given score.
@@ -311,6 +687,57 @@ impl ScanReport {
         }
         counts
     }
+
+    /// Group findings by CWE ID, for compliance-oriented reporting.
+    /// A finding with multiple CWE IDs is counted under each.
+    pub fn findings_count_by_cwe(&self) -> std::collections::HashMap<String, usize> {
+        let mut counts = std::collections::HashMap::new();
+        for result in &self.results {
+            for finding in &result.findings {
+                for cwe in &finding.cwe {
+                    *counts.entry(cwe.clone()).or_insert(0) += 1;
+                }
+            }
+        }
+        counts
+    }
+
+    /// Group findings by OWASP LLM Top 10 category, for compliance-oriented
+    /// reporting. A finding with multiple categories is counted under each.
+    pub fn findings_count_by_owasp_llm(&self) -> std::collections::HashMap<String, usize> {
+        let mut counts = std::collections::HashMap::new();
+        for result in &self.results {
+            for finding in &result.findings {
+                for category in &finding.owasp_llm {
+                    *counts.entry(category.clone()).or_insert(0) += 1;
+                }
+            }
+        }
+        counts
+    }
+
+    /// Group findings by MITRE ATT&CK/ATLAS technique ID, forming a
+    /// coverage matrix of which techniques this scan's findings touch.
+    /// A finding with multiple technique IDs is counted under each.
+    pub fn findings_count_by_attack_technique(&self) -> std::collections::HashMap<String, usize> {
+        let mut counts = std::collections::HashMap::new();
+        for result in &self.results {
+            for finding in &result.findings {
+                for technique in &finding.attack_technique {
+                    *counts.entry(technique.clone()).or_insert(0) += 1;
+                }
+            }
+        }
+        counts
+    }
+}
+
+fn default_confidence() -> Confidence {
+    Confidence::Medium
+}
+
+fn default_grade() -> char {
+    'A'
 }
 
 /// Truncate a string to a maximum number of characters (UTF-8 safe).
@@ -362,3 +789,95 @@ impl std::str::FromStr for Platform {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn finding_at(rule_id: &str, path: &str, line: usize) -> Finding {
+        Finding::new(
+            rule_id,
+            "Test finding",
+            "A test finding",
+            Severity::Medium,
+            FindingCategory::CodeExecution,
+            Location::new(PathBuf::from(path), line, line),
+            "eval(x)",
+        )
+    }
+
+    #[test]
+    fn test_sort_deterministic_orders_results_by_path() {
+        let mut report = ScanReport::new(PathBuf::from("."));
+        let mut b = ScanResult::new(PathBuf::from("b.js"));
+        b.findings.push(finding_at("RULE-1", "b.js", 1));
+        let mut a = ScanResult::new(PathBuf::from("a.js"));
+        a.findings.push(finding_at("RULE-1", "a.js", 1));
+        report.results.push(b);
+        report.results.push(a);
+
+        report.sort_deterministic();
+
+        assert_eq!(report.results[0].path, PathBuf::from("a.js"));
+        assert_eq!(report.results[1].path, PathBuf::from("b.js"));
+    }
+
+    #[test]
+    fn test_sort_deterministic_orders_findings_by_line_then_rule_id() {
+        let mut report = ScanReport::new(PathBuf::from("."));
+        let mut result = ScanResult::new(PathBuf::from("a.js"));
+        result.findings.push(finding_at("RULE-2", "a.js", 5));
+        result.findings.push(finding_at("RULE-1", "a.js", 5));
+        result.findings.push(finding_at("RULE-1", "a.js", 1));
+        report.results.push(result);
+
+        report.sort_deterministic();
+
+        let findings = &report.results[0].findings;
+        assert_eq!(findings[0].location.start_line, 1);
+        assert_eq!(findings[1].location.start_line, 5);
+        assert_eq!(findings[1].rule_id, "RULE-1");
+        assert_eq!(findings[2].rule_id, "RULE-2");
+    }
+
+    #[test]
+    fn test_fingerprint_stable_across_calls() {
+        let f = finding_at("RULE-1", "a.js", 1);
+        assert_eq!(f.fingerprint(), f.fingerprint());
+    }
+
+    #[test]
+    fn test_fingerprint_differs_for_different_snippets() {
+        let f1 = finding_at("RULE-1", "a.js", 1);
+        let f2 = finding_at("RULE-1", "a.js", 1).with_remediation("fix it");
+        // Remediation doesn't affect fingerprint, but snippet does.
+        assert_eq!(f1.fingerprint(), f2.fingerprint());
+        let f3 = Finding::new(
+            "RULE-1",
+            "Test finding",
+            "A test finding",
+            Severity::Medium,
+            FindingCategory::CodeExecution,
+            Location::new(PathBuf::from("a.js"), 1, 1),
+            "different snippet",
+        );
+        assert_ne!(f1.fingerprint(), f3.fingerprint());
+    }
+
+    #[test]
+    fn test_findings_count_by_attack_technique_counts_multi_mapped_findings() {
+        let mut report = ScanReport::new(PathBuf::from("."));
+        let mut result = ScanResult::new(PathBuf::from("a.sh"));
+        let f1 = finding_at("RULE-1", "a.sh", 1)
+            .with_attack_technique(vec!["T1059".to_string(), "T1041".to_string()]);
+        let f2 = finding_at("RULE-2", "a.sh", 2).with_attack_technique(vec!["T1059".to_string()]);
+        result.findings.push(f1);
+        result.findings.push(f2);
+        report.results.push(result);
+
+        let counts = report.findings_count_by_attack_technique();
+
+        assert_eq!(counts.get("T1059"), Some(&2));
+        assert_eq!(counts.get("T1041"), Some(&1));
+    }
+}